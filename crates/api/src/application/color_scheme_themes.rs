@@ -0,0 +1,47 @@
+use crate::{shell::ColorScheme, theming::Theme};
+
+/// Holds a light and a dark theme. The theme of a window is selected by the light / dark preference of the
+/// system and switched if the preference is changed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColorSchemeThemes {
+    light: Theme,
+    dark: Theme,
+}
+
+impl ColorSchemeThemes {
+    /// Creates the themes from a light and a dark theme.
+    pub fn new(light: Theme, dark: Theme) -> Self {
+        ColorSchemeThemes { light, dark }
+    }
+
+    /// Returns the theme for the given color scheme or `None` if the color scheme is unknown.
+    pub fn select(&self, color_scheme: ColorScheme) -> Option<&Theme> {
+        match color_scheme {
+            ColorScheme::Light => Some(&self.light),
+            ColorScheme::Dark => Some(&self.dark),
+            ColorScheme::Unknown => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme_default::{theme_default_dark, theme_default_light};
+
+    #[test]
+    fn test_select() {
+        let themes = ColorSchemeThemes::new(theme_default_light(), theme_default_dark());
+        assert_ne!(theme_default_light(), theme_default_dark());
+
+        assert_eq!(
+            themes.select(ColorScheme::Dark),
+            Some(&theme_default_dark())
+        );
+        assert_eq!(
+            themes.select(ColorScheme::Light),
+            Some(&theme_default_light())
+        );
+        assert_eq!(themes.select(ColorScheme::Unknown), None);
+    }
+}
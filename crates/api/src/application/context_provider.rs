@@ -7,7 +7,7 @@ use std::{
 
 use dces::prelude::*;
 
-use super::WindowAdapter;
+use super::{ColorSchemeThemes, WindowAdapter};
 
 use crate::{
     event::*,
@@ -27,6 +27,8 @@ pub struct ContextProvider {
     pub handler_map: Rc<RefCell<EventHandlerMap>>,
     pub states: Rc<RefCell<BTreeMap<Entity, Box<dyn State>>>>,
     pub event_adapter: EventAdapter,
+    pub event_filter: EventFilter,
+    pub color_scheme_themes: Option<ColorSchemeThemes>,
     pub message_adapter: MessageAdapter,
     pub mouse_position: Rc<Cell<Point>>,
     pub window_sender: mpsc::Sender<WindowRequest>,
@@ -45,6 +47,8 @@ impl ContextProvider {
         shell_sender: mpsc::Sender<ShellRequest<WindowAdapter>>,
         application_name: impl Into<String>,
         localization: Option<Rc<RefCell<Box<dyn Localization>>>>,
+        event_filter: EventFilter,
+        color_scheme_themes: Option<ColorSchemeThemes>,
     ) -> Self {
         ContextProvider {
             render_objects: Rc::new(RefCell::new(BTreeMap::new())),
@@ -52,6 +56,8 @@ impl ContextProvider {
             handler_map: Rc::new(RefCell::new(EventHandlerMap::new())),
             states: Rc::new(RefCell::new(BTreeMap::new())),
             event_adapter: EventAdapter::new(window_sender.clone()),
+            event_filter,
+            color_scheme_themes,
             message_adapter: MessageAdapter::new(window_sender.clone()),
             mouse_position: Rc::new(Cell::new(Point::new(0.0, 0.0))),
             window_sender,
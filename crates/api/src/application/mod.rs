@@ -5,16 +5,20 @@ use std::sync::mpsc;
 use dces::prelude::Entity;
 
 use crate::{
+    event::{EventBox, EventFilter, FilterAction},
     localization::Localization,
-    shell::{Shell, ShellRequest},
+    shell::{system_color_scheme, ColorScheme, Shell, ShellRequest},
     theming::Theme,
+    utils::motion,
     widget_base::BuildContext,
 };
 
+pub use self::color_scheme_themes::*;
 pub use self::context_provider::*;
 pub use self::overlay::*;
 pub use self::window_adapter::*;
 
+mod color_scheme_themes;
 mod context_provider;
 mod overlay;
 mod window_adapter;
@@ -27,6 +31,8 @@ pub struct Application {
     name: Box<str>,
     theme: Theme,
     localization: Option<Rc<RefCell<Box<dyn Localization>>>>,
+    event_filter: EventFilter,
+    color_scheme_themes: Option<ColorSchemeThemes>,
 }
 
 impl Default for Application {
@@ -47,6 +53,34 @@ impl Application {
         self
     }
 
+    /// Sets a light and a dark theme. The theme is selected by the light / dark preference of the system and is
+    /// switched if the preference is changed. If the preference is unknown the light theme is used.
+    pub fn color_scheme_themes(mut self, light: Theme, dark: Theme) -> Self {
+        let color_scheme_themes = ColorSchemeThemes::new(light, dark);
+
+        if let Some(theme) = color_scheme_themes
+            .select(Application::system_color_scheme())
+            .or_else(|| color_scheme_themes.select(ColorScheme::Light))
+        {
+            self.theme = theme.clone();
+        }
+
+        self.color_scheme_themes = Some(color_scheme_themes);
+        self
+    }
+
+    /// Returns the light / dark preference of the system. `ColorScheme::Unknown` is returned if the platform
+    /// does not provide it.
+    pub fn system_color_scheme() -> ColorScheme {
+        system_color_scheme()
+    }
+
+    /// Enables or disables reduced motion. If it is enabled animations are skipped, see `utils::motion`.
+    pub fn reduced_motion(self, reduced_motion: bool) -> Self {
+        motion::set_reduced_motion(reduced_motion);
+        self
+    }
+
     pub fn localization<L>(mut self, localization: L) -> Self
     where
         L: Localization + 'static,
@@ -55,6 +89,27 @@ impl Application {
         self
     }
 
+    /// Adds a global event filter. Each event of all windows passes the filters in the order they are added before
+    /// it is dispatched to the widgets. A filter can pass, consume or replace the event.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// Application::new()
+    ///     // blocks all mouse input e.g. while loading
+    ///     .add_event_filter(|event| {
+    ///         if event.is_type::<MouseDownEvent>() || event.is_type::<MouseUpEvent>() {
+    ///             return FilterAction::Consume;
+    ///         }
+    ///
+    ///         FilterAction::Pass
+    ///     });
+    /// ```
+    pub fn add_event_filter<F: Fn(&EventBox) -> FilterAction + 'static>(self, filter: F) -> Self {
+        self.event_filter.add(filter);
+        self
+    }
+
     /// Create a new application with the given name.
     pub fn from_name(name: impl Into<Box<str>>) -> Self {
         let (sender, receiver) = mpsc::channel();
@@ -65,6 +120,8 @@ impl Application {
             shell: Shell::new(receiver),
             theme: crate::theme_default::theme_default(),
             localization: None,
+            event_filter: EventFilter::new(),
+            color_scheme_themes: None,
         }
     }
 
@@ -76,6 +133,8 @@ impl Application {
             self.request_sender.clone(),
             create_fn,
             self.localization.clone(),
+            self.event_filter.clone(),
+            self.color_scheme_themes.clone(),
         );
 
         self.shell
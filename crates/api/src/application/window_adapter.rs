@@ -41,6 +41,11 @@ impl WindowAdapter {
 }
 
 impl WindowAdapter {
+    /// Returns the entity component manager of the window, it contains the widget tree and the widget properties.
+    pub fn entity_component_manager(&mut self) -> &mut EntityComponentManager<Tree> {
+        self.world.entity_component_manager()
+    }
+
     fn root(&mut self) -> Entity {
         self.world
             .entity_component_manager()
@@ -108,6 +113,7 @@ impl shell::WindowAdapter for WindowAdapter {
                     MouseUpEvent {
                         position: event.position,
                         button: event.button,
+                        modifiers: event.modifiers,
                     },
                 );
                 self.ctx.event_adapter.push_event(
@@ -115,6 +121,7 @@ impl shell::WindowAdapter for WindowAdapter {
                     GlobalMouseUpEvent {
                         position: event.position,
                         button: event.button,
+                        modifiers: event.modifiers,
                     },
                 );
             }
@@ -123,11 +130,24 @@ impl shell::WindowAdapter for WindowAdapter {
                 MouseDownEvent {
                     position: event.position,
                     button: event.button,
+                    modifiers: event.modifiers,
                 },
             ),
         }
     }
 
+    fn double_click(&mut self, event: shell::MouseEvent) {
+        let root = self.root();
+        self.ctx.event_adapter.push_event(
+            root,
+            DoubleClickEvent {
+                position: event.position,
+                button: event.button,
+                modifiers: event.modifiers,
+            },
+        );
+    }
+
     fn mouse_position(&self) -> Point {
         self.ctx.mouse_position.get()
     }
@@ -159,7 +179,7 @@ impl shell::WindowAdapter for WindowAdapter {
 
         self.ctx
             .event_adapter
-            .push_event_direct(root, SystemEvent::Quit);
+            .push_event_direct(root, WindowEvent::CloseRequested);
     }
 
     fn active(&mut self, active: bool) {
@@ -170,6 +190,14 @@ impl shell::WindowAdapter for WindowAdapter {
             .push_event_direct(root, WindowEvent::ActiveChanged(active));
     }
 
+    fn color_scheme_changed(&mut self, color_scheme: shell::ColorScheme) {
+        let root = self.root();
+
+        self.ctx
+            .event_adapter
+            .push_event_direct(root, WindowEvent::ColorSchemeChanged(color_scheme));
+    }
+
     fn run(&mut self, render_context: &mut render::RenderContext2D) {
         self.world.run_with_context(render_context);
     }
@@ -208,6 +236,8 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
     request_sender: mpsc::Sender<ShellRequest<WindowAdapter>>,
     create_fn: F,
     localization: Option<Rc<RefCell<Box<dyn Localization>>>>,
+    event_filter: EventFilter,
+    color_scheme_themes: Option<ColorSchemeThemes>,
 ) -> (WindowAdapter, WindowSettings, mpsc::Receiver<WindowRequest>) {
     let app_name = app_name.into();
     let mut world: World<Tree, render::RenderContext2D> = World::from_entity_store(Tree::default());
@@ -216,8 +246,14 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
 
     let registry = Rc::new(RefCell::new(Registry::new()));
 
-    let context_provider =
-        ContextProvider::new(sender, request_sender, app_name.clone(), localization);
+    let context_provider = ContextProvider::new(
+        sender,
+        request_sender,
+        app_name.clone(),
+        localization,
+        event_filter,
+        color_scheme_themes,
+    );
 
     if app_name.is_empty() {
         registry.borrow_mut().register(
@@ -307,6 +343,10 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
             .unwrap(),
         position: (position.x(), position.y()),
         size: (constraint.width(), constraint.height()),
+        min_size: Some(constraint.min_size())
+            .filter(|(width, height)| *width > 0.0 || *height > 0.0),
+        max_size: Some(constraint.max_size())
+            .filter(|(width, height)| *width < f64::MAX || *height < f64::MAX),
         fonts,
     };
 
@@ -317,6 +357,10 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
         .entity_component_manager()
         .component_store_mut()
         .register("theme", window, theme);
+    world
+        .entity_component_manager()
+        .component_store_mut()
+        .register("transition_widgets", window, Vec::<Entity>::new());
     world
         .entity_component_manager()
         .component_store_mut()
@@ -356,10 +400,15 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
         .build();
 
     world
-        .create_system(RenderSystem::new(context_provider.clone()))
+        .create_system(TransitionSystem::new(context_provider.clone()))
         .with_priority(3)
         .build();
 
+    world
+        .create_system(RenderSystem::new(context_provider.clone()))
+        .with_priority(4)
+        .build();
+
     (
         WindowAdapter::new(world, context_provider, registry),
         settings,
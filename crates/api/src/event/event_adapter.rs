@@ -59,6 +59,17 @@ impl EventAdapter {
         self.redraw();
     }
 
+    /// Pushes an event that moves from the given entity (widget) to its descendants. Parents receive the event
+    /// before their children, it stops at the first widget that handles it.
+    pub fn push_event_top_down<E: Event + Send>(&self, entity: Entity, event: E) {
+        self.event_queue
+            .lock()
+            .expect("EventAdapter::push_event_top_down: Cannot lock event queue")
+            .register_event_with_strategy(event, EventStrategy::TopDown, entity);
+
+        self.redraw();
+    }
+
     fn redraw(&self) {
         if let Some(window_sender) = &self.window_sender {
             window_sender.send(WindowRequest::Redraw).unwrap();
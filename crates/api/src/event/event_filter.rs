@@ -0,0 +1,157 @@
+use std::{cell::RefCell, rc::Rc};
+
+use super::EventBox;
+
+/// Describes what happens with an event after it is checked by an event filter.
+pub enum FilterAction {
+    /// The event is passed unchanged to the next filter and the widgets.
+    Pass,
+
+    /// The event is consumed. It does not reach the next filters and the widgets.
+    Consume,
+
+    /// The event is replaced by the given event.
+    Replace(EventBox),
+}
+
+/// Defines the signature of an event filter function.
+pub type EventFilterFunction = dyn Fn(&EventBox) -> FilterAction + 'static;
+
+/// The `EventFilter` holds the global event filters of an application. The filters are called in the order they
+/// are added with each event before it is dispatched to the widgets.
+#[derive(Clone, Default)]
+pub struct EventFilter {
+    filters: Rc<RefCell<Vec<Rc<EventFilterFunction>>>>,
+}
+
+impl EventFilter {
+    /// Creates a new event filter without filter functions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a filter function. It is called after all filter functions that are already added.
+    pub fn add<F: Fn(&EventBox) -> FilterAction + 'static>(&self, filter: F) {
+        self.filters.borrow_mut().push(Rc::new(filter));
+    }
+
+    /// Passes the event through all filter functions. Returns `None` if the event is consumed.
+    pub fn filter(&self, event: EventBox) -> Option<EventBox> {
+        let mut event = event;
+
+        for filter in self.filters.borrow().iter() {
+            match filter(&event) {
+                FilterAction::Pass => {}
+                FilterAction::Consume => return None,
+                FilterAction::Replace(replacement) => event = replacement,
+            }
+        }
+
+        Some(event)
+    }
+
+    /// Returns the number of filter functions.
+    pub fn len(&self) -> usize {
+        self.filters.borrow().len()
+    }
+
+    /// Returns `true` if there are no filter functions.
+    pub fn is_empty(&self) -> bool {
+        self.filters.borrow().is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dces::entity::Entity;
+
+    use crate::{
+        prelude::*,
+        shell::{Modifiers, MouseButton},
+        utils::Point,
+    };
+
+    fn click_events(entity: Entity) -> Vec<EventBox> {
+        vec![
+            EventBox::new(
+                MouseDownEvent {
+                    button: MouseButton::Left,
+                    position: Point::new(5.0, 5.0),
+                    modifiers: Modifiers::NONE,
+                },
+                EventStrategy::BottomUp,
+                entity,
+            ),
+            EventBox::new(
+                MouseUpEvent {
+                    button: MouseButton::Left,
+                    position: Point::new(5.0, 5.0),
+                    modifiers: Modifiers::NONE,
+                },
+                EventStrategy::BottomUp,
+                entity,
+            ),
+            EventBox::new(
+                WindowEvent::ActiveChanged(true),
+                EventStrategy::Direct,
+                entity,
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_consume_mouse_events() {
+        let entity = Entity::from(1);
+        let event_filter = EventFilter::new();
+        assert!(event_filter.is_empty());
+
+        event_filter.add(|event| {
+            if event.is_type::<MouseDownEvent>() || event.is_type::<MouseUpEvent>() {
+                return FilterAction::Consume;
+            }
+
+            FilterAction::Pass
+        });
+        assert_eq!(event_filter.len(), 1);
+
+        // without mouse down and up the click behavior of a button never raises its click handler
+        let events: Vec<EventBox> = click_events(entity)
+            .into_iter()
+            .filter_map(|event| event_filter.filter(event))
+            .collect();
+
+        assert_eq!(events.len(), 1);
+        assert!(events[0].is_type::<WindowEvent>());
+    }
+
+    #[test]
+    fn test_replace_event() {
+        let entity = Entity::from(1);
+        let event_filter = EventFilter::new();
+
+        // swaps the mouse buttons
+        event_filter.add(|event| {
+            if let Ok(mouse_down) = event.downcast_ref::<MouseDownEvent>() {
+                if mouse_down.button == MouseButton::Left {
+                    return FilterAction::Replace(EventBox::new(
+                        MouseDownEvent {
+                            button: MouseButton::Right,
+                            position: mouse_down.position,
+                            modifiers: mouse_down.modifiers,
+                        },
+                        event.strategy.clone(),
+                        event.source,
+                    ));
+                }
+            }
+
+            FilterAction::Pass
+        });
+
+        let event = event_filter.filter(click_events(entity).remove(0)).unwrap();
+        assert_eq!(
+            event.downcast_ref::<MouseDownEvent>().unwrap().button,
+            MouseButton::Right
+        );
+    }
+}
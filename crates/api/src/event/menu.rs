@@ -0,0 +1,45 @@
+use std::rc::Rc;
+
+use dces::prelude::*;
+
+use super::*;
+
+use crate::{proc_macros::*, widget_base::*};
+
+/// This event occurs when an item of a menu is selected. It contains the action name of the item.
+#[derive(Clone, Event)]
+pub struct MenuActionEvent(pub Entity, pub String);
+
+pub type MenuActionHandlerFn = dyn Fn(&mut StatesContext, Entity, String) + 'static;
+
+#[derive(IntoHandler)]
+pub struct MenuActionEventHandler {
+    pub handler: Rc<MenuActionHandlerFn>,
+}
+
+impl EventHandler for MenuActionEventHandler {
+    fn handle_event(&self, states: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<MenuActionEvent>() {
+            (self.handler)(states, event.0, event.1.clone());
+            return true;
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<MenuActionEvent>()
+    }
+}
+
+pub trait MenuActionHandler: Sized + Widget {
+    /// Inserts a handler that is called with the action name if an item of a menu is selected.
+    fn on_menu_action<H: Fn(&mut StatesContext, Entity, String) + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(MenuActionEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
@@ -9,32 +9,39 @@ use crate::widget_base::StatesContext;
 pub use self::drop::*;
 pub use self::editable::*;
 pub use self::event_adapter::*;
+pub use self::event_filter::*;
 pub use self::event_handler::*;
 pub use self::event_queue::*;
 pub use self::focus::*;
 pub use self::key::*;
+pub use self::menu::*;
 pub use self::mouse::*;
 pub use self::system::*;
 pub use self::text_input::*;
+pub use self::tree_node::*;
 pub use self::window::*;
 
 mod drop;
 mod editable;
 mod event_adapter;
+mod event_filter;
 mod event_handler;
 mod event_queue;
 mod focus;
 mod key;
+mod menu;
 mod mouse;
 mod system;
 mod text_input;
+mod tree_node;
 mod window;
 
 /// Defines the strategy of an event how it moves through the tree.
 #[derive(Debug, Clone, PartialEq)]
 pub enum EventStrategy {
-    // /// From root to leaf.
-    // TopDown,
+    /// From root to leaf.
+    TopDown,
+
     /// From leaf to root.
     BottomUp,
 
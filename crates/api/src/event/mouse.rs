@@ -3,11 +3,13 @@ use std::rc::Rc;
 use crate::{
     prelude::*,
     proc_macros::{Event, IntoHandler},
-    shell::MouseButton,
+    shell::{Modifiers, MouseButton},
     utils::*,
 };
 
-/// Checks if the given point is inside of a widget.
+/// Checks if the given point is inside of a widget. Disabled widgets and widgets that are not hit testable are
+/// never hit. If the widget or one of its ancestors has a `render_transform`, the inverse of the transform is
+/// applied to the point before it is tested.
 pub fn check_mouse_condition(mouse_position: Point, widget: &WidgetContainer<'_>) -> bool {
     let enabled = widget.get::<bool>("enabled");
 
@@ -17,13 +19,37 @@ pub fn check_mouse_condition(mouse_position: Point, widget: &WidgetContainer<'_>
 
     let bounds = widget.get::<Rectangle>("bounds");
     let position = widget.get::<Point>("position");
+    let hit_testable = widget.try_clone::<bool>("hit_testable").unwrap_or(true);
 
-    let mut rect = Rectangle::new((0.0, 0.0), (bounds.width(), bounds.height()));
+    match untransformed_position(mouse_position, widget.global_render_transform()) {
+        Some(mouse_position) => hit_test(mouse_position, *position, bounds.size(), hit_testable),
+        None => false,
+    }
+}
+
+/// Maps the given (window) mouse position into the local space of the widget, `(0, 0)` is the top left corner of
+/// the widget. The render transforms of the widget and its ancestors are considered. Returns `None` if the widget
+/// is rendered under a transform that cannot be inverted, e.g. a scale of zero.
+pub fn local_position(mouse_position: Point, widget: &WidgetContainer<'_>) -> Option<Point> {
+    let position = widget.get::<Point>("position");
+
+    untransformed_position(mouse_position, widget.global_render_transform())
+        .map(|mouse_position| mouse_position - *position)
+}
 
-    rect.set_x(position.x());
-    rect.set_y(position.y());
+// applies the inverse of the render transform to the given (window) mouse position
+fn untransformed_position(mouse_position: Point, transform: Option<Transform>) -> Option<Point> {
+    match transform {
+        Some(transform) => transform
+            .inverse()
+            .map(|inverse| inverse.apply(mouse_position)),
+        None => Some(mouse_position),
+    }
+}
 
-    rect.contains(mouse_position)
+// checks if the given point is inside of the area at the given (absolute) position with the given size
+fn hit_test(mouse_position: Point, position: Point, size: Size, hit_testable: bool) -> bool {
+    hit_testable && Rectangle::new(position, size).contains(mouse_position)
 }
 
 /// `MouseMoveEvent` indicates if the mouse position is changed on the window.
@@ -62,6 +88,9 @@ pub struct Mouse {
 
     /// Indicates position of the mouse on the window.
     pub position: Point,
+
+    /// Indicates the modifier keys that are pressed during the event.
+    pub modifiers: Modifiers,
 }
 
 /// `MouseUpEvent` occurs when a mouse button is released.
@@ -72,6 +101,9 @@ pub struct MouseUpEvent {
 
     /// Indicates position of the mouse on the window.
     pub position: Point,
+
+    /// Indicates the modifier keys that are pressed during the event.
+    pub modifiers: Modifiers,
 }
 
 /// `ClickEvent` occurs when a user clicked on an element.
@@ -81,6 +113,19 @@ pub struct ClickEvent {
     pub position: Point,
 }
 
+/// `DoubleClickEvent` occurs when a user double clicked on an element.
+#[derive(Event)]
+pub struct DoubleClickEvent {
+    /// Indicates the mouse button that is double clicked.
+    pub button: MouseButton,
+
+    /// Indicates the x and y position of the double click event.
+    pub position: Point,
+
+    /// Indicates the modifier keys that are pressed during the event.
+    pub modifiers: Modifiers,
+}
+
 /// `MouseDownEvent` occurs when a mouse button is pressed.
 #[derive(Event)]
 pub struct MouseDownEvent {
@@ -89,6 +134,9 @@ pub struct MouseDownEvent {
 
     /// Indicates position of the mouse on the window.
     pub position: Point,
+
+    /// Indicates the modifier keys that are pressed during the event.
+    pub modifiers: Modifiers,
 }
 
 /// `GlobalMouseUpEvent` occurs when a mouse button is released.
@@ -101,6 +149,9 @@ pub struct GlobalMouseUpEvent {
 
     /// Indicates position of the mouse on the window.
     pub position: Point,
+
+    /// Indicates the modifier keys that are pressed during the event.
+    pub modifiers: Modifiers,
 }
 
 /// Defines the mouse handler function.
@@ -137,6 +188,34 @@ impl EventHandler for ClickEventHandler {
     }
 }
 
+/// Used to handle double click events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct DoubleClickEventHandler {
+    handler: Rc<MouseHandlerFunction>,
+}
+
+impl EventHandler for DoubleClickEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<DoubleClickEvent>()
+            .ok()
+            .map_or(false, |event| {
+                (self.handler)(
+                    state_context,
+                    Mouse {
+                        button: event.button,
+                        position: event.position,
+                        modifiers: event.modifiers,
+                    },
+                )
+            })
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<DoubleClickEvent>()
+    }
+}
+
 /// Used to handle mouse down events. Could be attached to a widget.
 #[derive(IntoHandler)]
 pub struct MouseDownEventHandler {
@@ -154,6 +233,7 @@ impl EventHandler for MouseDownEventHandler {
                     Mouse {
                         button: event.button,
                         position: event.position,
+                        modifiers: event.modifiers,
                     },
                 )
             })
@@ -181,6 +261,7 @@ impl EventHandler for GlobalMouseUpEventHandler {
                     Mouse {
                         button: event.button,
                         position: event.position,
+                        modifiers: event.modifiers,
                     },
                 );
                 false
@@ -206,6 +287,7 @@ impl EventHandler for MouseUpEventHandler {
                 Mouse {
                     button: event.button,
                     position: event.position,
+                    modifiers: event.modifiers,
                 },
             );
         }
@@ -304,6 +386,16 @@ pub trait MouseHandler: Sized + Widget {
         })
     }
 
+    /// Inserts a double click handler.
+    fn on_double_click<H: Fn(&mut StatesContext, Mouse) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(DoubleClickEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
     /// Insert a mouse down handler.
     fn on_mouse_down<H: Fn(&mut StatesContext, Mouse) -> bool + 'static>(self, handler: H) -> Self {
         self.insert_handler(MouseDownEventHandler {
@@ -0,0 +1,84 @@
+use std::rc::Rc;
+
+use dces::prelude::*;
+
+use super::*;
+
+use crate::{proc_macros::*, widget_base::*};
+
+/// This event occurs when a node of a tree is expanded or collapsed. It contains the path of the node, that is the
+/// list of child indices from the top level to the node, and the new expanded state.
+#[derive(Clone, Event)]
+pub struct TreeNodeExpandedEvent(pub Entity, pub Vec<usize>, pub bool);
+
+pub type TreeNodeExpandedHandlerFn = dyn Fn(&mut StatesContext, Entity, Vec<usize>, bool) + 'static;
+
+#[derive(IntoHandler)]
+pub struct TreeNodeExpandedEventHandler {
+    pub handler: Rc<TreeNodeExpandedHandlerFn>,
+}
+
+impl EventHandler for TreeNodeExpandedEventHandler {
+    fn handle_event(&self, states: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<TreeNodeExpandedEvent>() {
+            (self.handler)(states, event.0, event.1.clone(), event.2);
+            return true;
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<TreeNodeExpandedEvent>()
+    }
+}
+
+pub trait TreeNodeExpandedHandler: Sized + Widget {
+    /// Inserts a handler that is called if a node is expanded or collapsed.
+    fn on_node_expanded<H: Fn(&mut StatesContext, Entity, Vec<usize>, bool) + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(TreeNodeExpandedEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
+
+/// This event occurs when a node of a tree is selected. It contains the path of the selected node.
+#[derive(Clone, Event)]
+pub struct TreeNodeSelectedEvent(pub Entity, pub Vec<usize>);
+
+pub type TreeNodeSelectedHandlerFn = dyn Fn(&mut StatesContext, Entity, Vec<usize>) + 'static;
+
+#[derive(IntoHandler)]
+pub struct TreeNodeSelectedEventHandler {
+    pub handler: Rc<TreeNodeSelectedHandlerFn>,
+}
+
+impl EventHandler for TreeNodeSelectedEventHandler {
+    fn handle_event(&self, states: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<TreeNodeSelectedEvent>() {
+            (self.handler)(states, event.0, event.1.clone());
+            return true;
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<TreeNodeSelectedEvent>()
+    }
+}
+
+pub trait TreeNodeSelectedHandler: Sized + Widget {
+    /// Inserts a handler that is called if a node is selected.
+    fn on_node_selected<H: Fn(&mut StatesContext, Entity, Vec<usize>) + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(TreeNodeSelectedEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
@@ -0,0 +1,273 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
+};
+
+use dces::prelude::*;
+
+use crate::{
+    prelude::*, proc_macros::IntoLayout, render::RenderContext2D, theming::*, tree::Tree,
+    utils::prelude::*,
+};
+
+use super::{component, component_try_mut, try_component, Layout};
+
+/// Places its children at the absolute positions that are defined by their `left`, `top`, `right` and `bottom`
+/// attached properties. A child with `left` and `right` (or `top` and `bottom`) is stretched between both
+/// positions, a child without any of them is aligned inside of the whole area.
+#[derive(Default, IntoLayout)]
+pub struct CanvasLayout {
+    desired_size: RefCell<DirtySize>,
+    children_sizes: RefCell<BTreeMap<Entity, (f64, f64)>>,
+    old_alignment: Cell<(Alignment, Alignment)>,
+}
+
+impl CanvasLayout {
+    pub fn new() -> Self {
+        CanvasLayout::default()
+    }
+}
+
+impl Layout for CanvasLayout {
+    fn measure(
+        &self,
+        render_context_2_d: &mut RenderContext2D,
+        entity: Entity,
+        ecm: &mut EntityComponentManager<Tree>,
+        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        theme: &Theme,
+    ) -> DirtySize {
+        if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
+            self.desired_size.borrow_mut().set_size(0.0, 0.0);
+            return *self.desired_size.borrow();
+        }
+
+        let halign: Alignment = component(ecm, entity, "h_align");
+        let valign: Alignment = component(ecm, entity, "v_align");
+        let (old_valign, old_halign) = self.old_alignment.get();
+
+        if halign != old_halign || valign != old_valign {
+            self.desired_size.borrow_mut().set_dirty(true);
+        }
+
+        self.old_alignment.set((valign, halign));
+
+        let mut desired_size: (f64, f64) = (0.0, 0.0);
+
+        for index in 0..ecm.entity_store().children[&entity].len() {
+            let child = ecm.entity_store().children[&entity][index];
+
+            if let Some(child_layout) = layouts.get(&child) {
+                let child_desired_size =
+                    child_layout.measure(render_context_2_d, child, ecm, layouts, theme);
+
+                let dirty = child_desired_size.dirty() || self.desired_size.borrow().dirty();
+                self.desired_size.borrow_mut().set_dirty(dirty);
+
+                let child_margin = {
+                    if child_desired_size.width() > 0.0 && child_desired_size.height() > 0.0 {
+                        component(ecm, child, "margin")
+                    } else {
+                        Thickness::default()
+                    }
+                };
+
+                let size = (
+                    child_desired_size.width() + child_margin.left() + child_margin.right(),
+                    child_desired_size.height() + child_margin.top() + child_margin.bottom(),
+                );
+
+                let (left, top, right, bottom) = offsets(ecm, child);
+
+                desired_size.0 = desired_size
+                    .0
+                    .max(left.unwrap_or_default() + size.0 + right.unwrap_or_default());
+                desired_size.1 = desired_size
+                    .1
+                    .max(top.unwrap_or_default() + size.1 + bottom.unwrap_or_default());
+
+                self.children_sizes.borrow_mut().insert(child, size);
+            }
+        }
+
+        let size = component::<Constraint>(ecm, entity, "constraint").perform(desired_size);
+        self.desired_size.borrow_mut().set_size(size.0, size.1);
+
+        *self.desired_size.borrow()
+    }
+
+    fn arrange(
+        &self,
+        render_context_2_d: &mut RenderContext2D,
+        parent_size: (f64, f64),
+        entity: Entity,
+        ecm: &mut EntityComponentManager<Tree>,
+        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        theme: &Theme,
+    ) -> (f64, f64) {
+        if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
+            self.desired_size.borrow_mut().set_size(0.0, 0.0);
+            return (0.0, 0.0);
+        }
+
+        if !self.desired_size.borrow().dirty() {
+            return self.desired_size.borrow().size();
+        }
+
+        let halign: Alignment = component(ecm, entity, "h_align");
+        let valign: Alignment = component(ecm, entity, "v_align");
+        let margin: Thickness = component(ecm, entity, "margin");
+        let constraint: Constraint = component(ecm, entity, "constraint");
+
+        let size = constraint.perform((
+            halign.align_measure(
+                parent_size.0,
+                self.desired_size.borrow().width(),
+                margin.left(),
+                margin.right(),
+            ),
+            valign.align_measure(
+                parent_size.1,
+                self.desired_size.borrow().height(),
+                margin.top(),
+                margin.bottom(),
+            ),
+        ));
+
+        if let Some(bounds) = component_try_mut::<Rectangle>(ecm, entity, "bounds") {
+            bounds.set_width(size.0);
+            bounds.set_height(size.1);
+        }
+
+        for index in 0..ecm.entity_store().children[&entity].len() {
+            let child = ecm.entity_store().children[&entity][index];
+
+            let desired_size = self
+                .children_sizes
+                .borrow()
+                .get(&child)
+                .cloned()
+                .unwrap_or_default();
+            let (left, top, right, bottom) = offsets(ecm, child);
+
+            let (x, width) =
+                canvas_area(left, right, size.0, desired_size.0).unwrap_or((0.0, size.0));
+            let (y, height) =
+                canvas_area(top, bottom, size.1, desired_size.1).unwrap_or((0.0, size.1));
+
+            let mut child_desired_size = (0.0, 0.0);
+            if let Some(child_layout) = layouts.get(&child) {
+                child_desired_size = child_layout.arrange(
+                    render_context_2_d,
+                    (width, height),
+                    child,
+                    ecm,
+                    layouts,
+                    theme,
+                );
+            }
+
+            let child_margin = {
+                if child_desired_size.0 > 0.0 && child_desired_size.1 > 0.0 {
+                    component(ecm, child, "margin")
+                } else {
+                    Thickness::default()
+                }
+            };
+
+            let child_halign: Alignment = component(ecm, child, "h_align");
+            let child_valign: Alignment = component(ecm, child, "v_align");
+
+            if let Some(child_bounds) = component_try_mut::<Rectangle>(ecm, child, "bounds") {
+                child_bounds.set_x(
+                    x + child_halign.align_position(
+                        width,
+                        child_bounds.width(),
+                        child_margin.left(),
+                        child_margin.right(),
+                    ),
+                );
+                child_bounds.set_y(
+                    y + child_valign.align_position(
+                        height,
+                        child_bounds.height(),
+                        child_margin.top(),
+                        child_margin.bottom(),
+                    ),
+                );
+            }
+
+            mark_as_dirty("bounds", child, ecm);
+        }
+
+        mark_as_dirty("bounds", entity, ecm);
+
+        self.desired_size.borrow_mut().set_dirty(false);
+        size
+    }
+}
+
+// reads the left, top, right and bottom attached properties of the given child
+fn offsets(
+    ecm: &mut EntityComponentManager<Tree>,
+    child: Entity,
+) -> (Option<f64>, Option<f64>, Option<f64>, Option<f64>) {
+    (
+        try_component(ecm, child, "left"),
+        try_component(ecm, child, "top"),
+        try_component(ecm, child, "right"),
+        try_component(ecm, child, "bottom"),
+    )
+}
+
+// returns the position and the size of the area a child is arranged in along one axis, `None` if the child does
+// not define a position on this axis. The desired size includes the margin of the child.
+fn canvas_area(
+    start: Option<f64>,
+    end: Option<f64>,
+    available: f64,
+    desired: f64,
+) -> Option<(f64, f64)> {
+    match (start, end) {
+        (Some(start), Some(end)) => Some((start, (available - start - end).max(0.0))),
+        (Some(start), None) => Some((start, desired)),
+        (None, Some(end)) => Some((available - end - desired, desired)),
+        (None, None) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canvas_area() {
+        // a child at (30, 40) keeps its desired size
+        assert_eq!(
+            canvas_area(Some(30.0), None, 400.0, 50.0),
+            Some((30.0, 50.0))
+        );
+        assert_eq!(
+            canvas_area(Some(40.0), None, 300.0, 20.0),
+            Some((40.0, 20.0))
+        );
+
+        // right and bottom are measured from the end of the canvas
+        assert_eq!(
+            canvas_area(None, Some(30.0), 400.0, 50.0),
+            Some((320.0, 50.0))
+        );
+
+        // the child is stretched between start and end
+        assert_eq!(
+            canvas_area(Some(30.0), Some(20.0), 400.0, 50.0),
+            Some((30.0, 350.0))
+        );
+        assert_eq!(
+            canvas_area(Some(300.0), Some(200.0), 400.0, 50.0),
+            Some((300.0, 0.0))
+        );
+
+        assert_eq!(canvas_area(None, None, 400.0, 50.0), None);
+    }
+}
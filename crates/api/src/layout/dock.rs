@@ -0,0 +1,334 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
+};
+
+use dces::prelude::*;
+
+use crate::{
+    prelude::*, proc_macros::IntoLayout, render::RenderContext2D, theming::*, tree::Tree,
+    utils::prelude::*,
+};
+
+use super::{component, component_try_mut, mirror_children, try_component, Layout};
+
+/// Docks its children to the edges of the remaining area in the order of the children, the last child fills
+/// the area that is left in the center. The edge of a child is defined by its `dock` attached property.
+#[derive(Default, IntoLayout)]
+pub struct DockLayout {
+    desired_size: RefCell<DirtySize>,
+    children_sizes: RefCell<BTreeMap<Entity, (f64, f64)>>,
+    old_alignment: Cell<(Alignment, Alignment)>,
+}
+
+impl DockLayout {
+    pub fn new() -> Self {
+        DockLayout::default()
+    }
+}
+
+impl Layout for DockLayout {
+    fn measure(
+        &self,
+        render_context_2_d: &mut RenderContext2D,
+        entity: Entity,
+        ecm: &mut EntityComponentManager<Tree>,
+        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        theme: &Theme,
+    ) -> DirtySize {
+        if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
+            self.desired_size.borrow_mut().set_size(0.0, 0.0);
+            return *self.desired_size.borrow();
+        }
+
+        let halign: Alignment = component(ecm, entity, "h_align");
+        let valign: Alignment = component(ecm, entity, "v_align");
+        let (old_valign, old_halign) = self.old_alignment.get();
+
+        if halign != old_halign || valign != old_valign {
+            self.desired_size.borrow_mut().set_dirty(true);
+        }
+
+        self.old_alignment.set((valign, halign));
+
+        let mut docks = vec![];
+        let mut sizes = vec![];
+
+        for index in 0..ecm.entity_store().children[&entity].len() {
+            let child = ecm.entity_store().children[&entity][index];
+
+            if let Some(child_layout) = layouts.get(&child) {
+                let child_desired_size =
+                    child_layout.measure(render_context_2_d, child, ecm, layouts, theme);
+
+                let dirty = child_desired_size.dirty() || self.desired_size.borrow().dirty();
+                self.desired_size.borrow_mut().set_dirty(dirty);
+
+                let child_margin = {
+                    if child_desired_size.width() > 0.0 && child_desired_size.height() > 0.0 {
+                        component(ecm, child, "margin")
+                    } else {
+                        Thickness::default()
+                    }
+                };
+
+                let size = (
+                    child_desired_size.width() + child_margin.left() + child_margin.right(),
+                    child_desired_size.height() + child_margin.top() + child_margin.bottom(),
+                );
+
+                docks.push(try_component::<Dock>(ecm, child, "dock").unwrap_or_default());
+                sizes.push(size);
+                self.children_sizes.borrow_mut().insert(child, size);
+            }
+        }
+
+        let desired_size = dock_desired_size(&docks, &sizes);
+
+        let size = component::<Constraint>(ecm, entity, "constraint").perform(desired_size);
+        self.desired_size.borrow_mut().set_size(size.0, size.1);
+
+        *self.desired_size.borrow()
+    }
+
+    fn arrange(
+        &self,
+        render_context_2_d: &mut RenderContext2D,
+        parent_size: (f64, f64),
+        entity: Entity,
+        ecm: &mut EntityComponentManager<Tree>,
+        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        theme: &Theme,
+    ) -> (f64, f64) {
+        if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
+            self.desired_size.borrow_mut().set_size(0.0, 0.0);
+            return (0.0, 0.0);
+        }
+
+        if !self.desired_size.borrow().dirty() {
+            return self.desired_size.borrow().size();
+        }
+
+        let halign: Alignment = component(ecm, entity, "h_align");
+        let valign: Alignment = component(ecm, entity, "v_align");
+        let margin: Thickness = component(ecm, entity, "margin");
+        let constraint: Constraint = component(ecm, entity, "constraint");
+
+        let size = constraint.perform((
+            halign.align_measure(
+                parent_size.0,
+                self.desired_size.borrow().width(),
+                margin.left(),
+                margin.right(),
+            ),
+            valign.align_measure(
+                parent_size.1,
+                self.desired_size.borrow().height(),
+                margin.top(),
+                margin.bottom(),
+            ),
+        ));
+
+        if let Some(bounds) = component_try_mut::<Rectangle>(ecm, entity, "bounds") {
+            bounds.set_width(size.0);
+            bounds.set_height(size.1);
+        }
+
+        let children = ecm.entity_store().children[&entity].clone();
+        let docks: Vec<Dock> = children
+            .iter()
+            .map(|child| try_component::<Dock>(ecm, *child, "dock").unwrap_or_default())
+            .collect();
+        let sizes: Vec<(f64, f64)> = children
+            .iter()
+            .map(|child| {
+                self.children_sizes
+                    .borrow()
+                    .get(child)
+                    .cloned()
+                    .unwrap_or_default()
+            })
+            .collect();
+
+        let rects = dock_rects(&docks, &sizes, size);
+
+        for (child, rect) in children.into_iter().zip(rects) {
+            let mut child_desired_size = (0.0, 0.0);
+            if let Some(child_layout) = layouts.get(&child) {
+                child_desired_size = child_layout.arrange(
+                    render_context_2_d,
+                    (rect.width(), rect.height()),
+                    child,
+                    ecm,
+                    layouts,
+                    theme,
+                );
+            }
+
+            let child_margin = {
+                if child_desired_size.0 > 0.0 && child_desired_size.1 > 0.0 {
+                    component(ecm, child, "margin")
+                } else {
+                    Thickness::default()
+                }
+            };
+
+            let child_halign: Alignment = component(ecm, child, "h_align");
+            let child_valign: Alignment = component(ecm, child, "v_align");
+
+            if let Some(child_bounds) = component_try_mut::<Rectangle>(ecm, child, "bounds") {
+                child_bounds.set_x(
+                    rect.x()
+                        + child_halign.align_position(
+                            rect.width(),
+                            child_bounds.width(),
+                            child_margin.left(),
+                            child_margin.right(),
+                        ),
+                );
+                child_bounds.set_y(
+                    rect.y()
+                        + child_valign.align_position(
+                            rect.height(),
+                            child_bounds.height(),
+                            child_margin.top(),
+                            child_margin.bottom(),
+                        ),
+                );
+            }
+
+            mark_as_dirty("bounds", child, ecm);
+        }
+
+        mirror_children(ecm, entity, size.0);
+
+        mark_as_dirty("bounds", entity, ecm);
+
+        self.desired_size.borrow_mut().set_dirty(false);
+        size
+    }
+}
+
+// calculates the size that is needed to dock the children with the given sizes (including margin)
+fn dock_desired_size(docks: &[Dock], sizes: &[(f64, f64)]) -> (f64, f64) {
+    let mut desired_size = (0.0_f64, 0.0_f64);
+    let mut used = (0.0, 0.0);
+
+    for (index, (dock, size)) in docks.iter().zip(sizes).enumerate() {
+        if index + 1 == sizes.len() {
+            desired_size.0 = desired_size.0.max(used.0 + size.0);
+            desired_size.1 = desired_size.1.max(used.1 + size.1);
+            continue;
+        }
+
+        match dock {
+            Dock::Left | Dock::Right => {
+                desired_size.1 = desired_size.1.max(used.1 + size.1);
+                used.0 += size.0;
+            }
+            Dock::Top | Dock::Bottom => {
+                desired_size.0 = desired_size.0.max(used.0 + size.0);
+                used.1 += size.1;
+            }
+        }
+    }
+
+    (desired_size.0.max(used.0), desired_size.1.max(used.1))
+}
+
+// calculates the area of each child, each child consumes its size from the remaining area at the edge it is
+// docked to, the last child fills the remaining area
+fn dock_rects(docks: &[Dock], sizes: &[(f64, f64)], available_size: (f64, f64)) -> Vec<Rectangle> {
+    let mut rects = vec![];
+    let (mut x, mut y) = (0.0, 0.0);
+    let (mut width, mut height) = available_size;
+
+    for (index, (dock, size)) in docks.iter().zip(sizes).enumerate() {
+        if index + 1 == sizes.len() {
+            rects.push(Rectangle::new((x, y), (width, height)));
+            continue;
+        }
+
+        let child_width = size.0.min(width);
+        let child_height = size.1.min(height);
+
+        match dock {
+            Dock::Left => {
+                rects.push(Rectangle::new((x, y), (child_width, height)));
+                x += child_width;
+                width -= child_width;
+            }
+            Dock::Top => {
+                rects.push(Rectangle::new((x, y), (width, child_height)));
+                y += child_height;
+                height -= child_height;
+            }
+            Dock::Right => {
+                rects.push(Rectangle::new(
+                    (x + width - child_width, y),
+                    (child_width, height),
+                ));
+                width -= child_width;
+            }
+            Dock::Bottom => {
+                rects.push(Rectangle::new(
+                    (x, y + height - child_height),
+                    (width, child_height),
+                ));
+                height -= child_height;
+            }
+        }
+    }
+
+    rects
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dock_rects() {
+        // toolbar at the top, status bar at the bottom and the content fills the center
+        let docks = [Dock::Top, Dock::Bottom, Dock::Left];
+        let sizes = [(120.0, 32.0), (80.0, 24.0), (50.0, 50.0)];
+
+        let rects = dock_rects(&docks, &sizes, (400.0, 300.0));
+
+        assert_eq!(rects[0], Rectangle::new((0.0, 0.0), (400.0, 32.0)));
+        assert_eq!(rects[1], Rectangle::new((0.0, 276.0), (400.0, 24.0)));
+        assert_eq!(rects[2], Rectangle::new((0.0, 32.0), (400.0, 244.0)));
+
+        // left and right docked children take the remaining height
+        let docks = [Dock::Top, Dock::Left, Dock::Right, Dock::Top];
+        let sizes = [(0.0, 32.0), (100.0, 0.0), (60.0, 0.0), (0.0, 0.0)];
+
+        let rects = dock_rects(&docks, &sizes, (400.0, 300.0));
+
+        assert_eq!(rects[1], Rectangle::new((0.0, 32.0), (100.0, 268.0)));
+        assert_eq!(rects[2], Rectangle::new((340.0, 32.0), (60.0, 268.0)));
+        assert_eq!(rects[3], Rectangle::new((100.0, 32.0), (240.0, 268.0)));
+
+        // a child never takes more than the remaining area
+        let rects = dock_rects(
+            &[Dock::Left, Dock::Left],
+            &[(500.0, 10.0), (10.0, 10.0)],
+            (400.0, 300.0),
+        );
+        assert_eq!(rects[0], Rectangle::new((0.0, 0.0), (400.0, 300.0)));
+        assert_eq!(rects[1], Rectangle::new((400.0, 0.0), (0.0, 300.0)));
+    }
+
+    #[test]
+    fn test_dock_desired_size() {
+        let docks = [Dock::Top, Dock::Bottom, Dock::Left];
+        let sizes = [(120.0, 32.0), (80.0, 24.0), (50.0, 50.0)];
+        assert_eq!(dock_desired_size(&docks, &sizes), (120.0, 106.0));
+
+        let docks = [Dock::Left, Dock::Top, Dock::Left];
+        let sizes = [(100.0, 200.0), (150.0, 20.0), (50.0, 50.0)];
+        assert_eq!(dock_desired_size(&docks, &sizes), (250.0, 200.0));
+
+        assert_eq!(dock_desired_size(&[], &[]), (0.0, 0.0));
+    }
+}
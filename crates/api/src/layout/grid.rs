@@ -10,7 +10,7 @@ use crate::{
     utils::prelude::*,
 };
 
-use super::{component, component_try_mut, Layout};
+use super::{component, component_try_mut, mirror_children, Layout};
 
 /// Orders its children in a grid layout with columns and rows. If no columns and rows are defined
 /// the grid layout could also be used as an alignment layout.
@@ -478,6 +478,8 @@ impl Layout for GridLayout {
             mark_as_dirty("bounds", child, ecm);
         }
 
+        mirror_children(ecm, entity, size.0);
+
         if let Some(bounds) = component_try_mut::<Rectangle>(ecm, entity, "bounds") {
             bounds.set_width(size.0);
             bounds.set_height(size.1);
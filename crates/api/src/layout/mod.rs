@@ -2,21 +2,27 @@ use std::{any::Any, collections::BTreeMap};
 
 use dces::prelude::*;
 
-use crate::{render::RenderContext2D, theming::*, tree::Tree, utils::*};
+use crate::{properties::FlowDirection, render::RenderContext2D, theming::*, tree::Tree, utils::*};
 
 pub use self::absolute::*;
+pub use self::canvas::*;
+pub use self::dock::*;
 pub use self::fixed_size::*;
 pub use self::grid::*;
 pub use self::padding::*;
 pub use self::popup::*;
 pub use self::stack::*;
+pub use self::uniform_grid::*;
 
 mod absolute;
+mod canvas;
+mod dock;
 mod fixed_size;
 mod grid;
 mod padding;
 mod popup;
 mod stack;
+mod uniform_grid;
 
 /// A layout is used to dynamic order the children of a widget.
 pub trait Layout: Any {
@@ -85,3 +91,39 @@ fn component_try_mut<'a, C: Component>(
         .get_mut::<C>(component, entity)
         .ok()
 }
+
+// returns the flow direction of the given widget, a widget without flow direction inherits it from its parent
+fn flow_direction(ecm: &mut EntityComponentManager<Tree>, entity: Entity) -> FlowDirection {
+    let mut current = Some(entity);
+
+    while let Some(entity) = current {
+        match try_component(ecm, entity, "flow_direction") {
+            Some(FlowDirection::Inherit) | None => {}
+            Some(flow_direction) => return flow_direction,
+        }
+
+        current = ecm.entity_store().parent[&entity];
+    }
+
+    FlowDirection::LeftToRight
+}
+
+// mirrors the children of the given widget inside of the given width if its flow direction is right to left
+fn mirror_children(ecm: &mut EntityComponentManager<Tree>, entity: Entity, width: f64) {
+    if flow_direction(ecm, entity) != FlowDirection::RightToLeft {
+        return;
+    }
+
+    for index in 0..ecm.entity_store().children[&entity].len() {
+        let child = ecm.entity_store().children[&entity][index];
+
+        if let Some(child_bounds) = component_try_mut::<Rectangle>(ecm, child, "bounds") {
+            child_bounds.set_x(mirror_x(child_bounds.x(), child_bounds.width(), width));
+        }
+    }
+}
+
+// returns the mirrored horizontal position of a child with the given position and width inside of the given width
+fn mirror_x(x: f64, child_width: f64, width: f64) -> f64 {
+    width - x - child_width
+}
@@ -10,7 +10,7 @@ use crate::{
     utils::prelude::*,
 };
 
-use super::{component, component_try_mut, Layout};
+use super::{component, component_or_default, component_try_mut, mirror_children, Layout};
 
 /// Add padding to the widget.
 #[derive(Default, IntoLayout)]
@@ -151,10 +151,11 @@ impl Layout for PaddingLayout {
 
         mark_as_dirty("bounds", entity, ecm);
 
-        let available_size = (
-            size.0 - padding.left() - padding.right(),
-            size.1 - padding.top() - padding.bottom(),
-        );
+        let max_content_width: f64 = component_or_default(ecm, entity, "max_content_width");
+        let (content_x, content_width) =
+            content_column(size.0 - padding.left() - padding.right(), max_content_width);
+
+        let available_size = (content_width, size.1 - padding.top() - padding.bottom());
 
         for index in 0..ecm.entity_store().children[&entity].len() {
             let child = ecm.entity_store().children[&entity][index];
@@ -182,6 +183,7 @@ impl Layout for PaddingLayout {
             {
                 child_bounds.set_x(
                     padding.left()
+                        + content_x
                         + child_horizontal_alignment.align_position(
                             available_size.0,
                             child_bounds.width(),
@@ -202,8 +204,43 @@ impl Layout for PaddingLayout {
 
             mark_as_dirty("bounds", child, ecm);
         }
+
+        mirror_children(ecm, entity, size.0);
+
         self.old_parent_size.set(parent_size);
         self.desired_size.borrow_mut().set_dirty(false);
         size
     }
 }
+
+// returns the horizontal offset and the width of the content inside of the available width, the content is
+// limited to the max content width and centered, a max content width of `0.0` does not limit the content
+fn content_column(available_width: f64, max_content_width: f64) -> (f64, f64) {
+    if max_content_width <= 0.0 || available_width <= max_content_width {
+        return (0.0, available_width);
+    }
+
+    (
+        (available_width - max_content_width) / 2.0,
+        max_content_width,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_content_column() {
+        // the child is limited to 600 and centered with 200 on each side
+        let (x, width) = content_column(1000.0, 600.0);
+        let child_width = Alignment::Stretch.align_measure(width, 0.0, 0.0, 0.0);
+        assert!((x - 200.0).abs() < f64::EPSILON);
+        assert!((child_width - 600.0).abs() < f64::EPSILON);
+        assert!((1000.0 - x - child_width - 200.0).abs() < f64::EPSILON);
+
+        // a narrower parent is not affected
+        assert_eq!(content_column(400.0, 600.0), (0.0, 400.0));
+        assert_eq!(content_column(1000.0, 0.0), (0.0, 1000.0));
+    }
+}
@@ -10,7 +10,7 @@ use crate::{
     utils::prelude::*,
 };
 
-use super::{component, component_or_default, component_try_mut, Layout};
+use super::{component, component_or_default, component_try_mut, mirror_children, Layout};
 
 /// Stacks visual the children widgets vertical or horizontal.
 #[derive(Default, IntoLayout)]
@@ -58,6 +58,8 @@ impl Layout for StackLayout {
 
         let nchildren = ecm.entity_store().children[&entity].len();
         let spacing: f64 = component_or_default(ecm, entity, "spacing");
+        let collapse_margins: bool = component_or_default(ecm, entity, "collapse_margins");
+        let mut previous_margin = 0.0;
 
         for index in 0..nchildren {
             let child = ecm.entity_store().children[&entity][index];
@@ -74,6 +76,11 @@ impl Layout for StackLayout {
                     }
                 };
 
+                if collapse_margins {
+                    previous_margin =
+                        collapse_margin(&mut child_margin, previous_margin, orientation);
+                }
+
                 if spacing != 0.0 && nchildren > 1 {
                     apply_spacing(&mut child_margin, spacing, orientation, index, nchildren);
                 }
@@ -142,6 +149,8 @@ impl Layout for StackLayout {
         let available_size = size;
         let nchildren = ecm.entity_store().children[&entity].len();
         let spacing: f64 = component_or_default(ecm, entity, "spacing");
+        let collapse_margins: bool = component_or_default(ecm, entity, "collapse_margins");
+        let mut previous_margin = 0.0;
 
         for index in 0..nchildren {
             let child = ecm.entity_store().children[&entity][index];
@@ -173,6 +182,10 @@ impl Layout for StackLayout {
                 }
             };
 
+            if collapse_margins {
+                previous_margin = collapse_margin(&mut child_margin, previous_margin, orientation);
+            }
+
             if spacing != 0.0 && nchildren > 1 {
                 apply_spacing(&mut child_margin, spacing, orientation, index, nchildren);
             }
@@ -205,6 +218,8 @@ impl Layout for StackLayout {
             }
         }
 
+        mirror_children(ecm, entity, size.0);
+
         if let Some(bounds) = component_try_mut::<Rectangle>(ecm, entity, "bounds") {
             bounds.set_width(size.0);
             bounds.set_height(size.1);
@@ -297,6 +312,21 @@ fn apply_spacing(
     }
 }
 
+/// Collapses the start margin of a widget in a stack with the end margin of the previous widget, so that the
+/// larger one of both is the gap between them. Returns the end margin of the widget.
+fn collapse_margin(margins: &mut Thickness, previous_margin: f64, orientation: Orientation) -> f64 {
+    match orientation {
+        Orientation::Vertical => {
+            margins.top = (margins.top - previous_margin).max(0.0);
+            margins.bottom
+        }
+        Orientation::Horizontal => {
+            margins.left = (margins.left - previous_margin).max(0.0);
+            margins.right
+        }
+    }
+}
+
 fn accumulate_desired_size(
     desired_size: &mut (f64, f64),
     desired: DirtySize,
@@ -320,10 +350,10 @@ fn accumulate_desired_size(
 
 #[cfg(test)]
 mod tests {
-    use orbtk_utils::{Orientation, Thickness};
+    use orbtk_utils::{Alignment, Orientation, Rectangle, Thickness};
     use std::iter;
 
-    use super::apply_spacing;
+    use super::{apply_arrangement, apply_spacing, collapse_margin};
 
     const NUM_WIDGETS: usize = 5;
 
@@ -389,4 +419,43 @@ mod tests {
             assert_eq!(input, expected);
         }
     }
+
+    #[test]
+    fn margin_collapsing() {
+        assert!((gap(false) - 16.0).abs() < f64::EPSILON);
+        assert!((gap(true) - 10.0).abs() < f64::EPSILON);
+    }
+
+    // returns the gap between two stacked widgets with a bottom margin of 10 and a top margin of 6
+    fn gap(collapse_margins: bool) -> f64 {
+        let mut size_counter = 0.0;
+        let mut previous_margin = 0.0;
+        let mut bounds = vec![];
+
+        for margin in &[
+            Thickness::new(0.0, 0.0, 0.0, 10.0),
+            Thickness::new(0.0, 6.0, 0.0, 0.0),
+        ] {
+            let mut margin = *margin;
+            let mut child_bounds = Rectangle::new((0.0, 0.0), (100.0, 20.0));
+
+            if collapse_margins {
+                previous_margin =
+                    collapse_margin(&mut margin, previous_margin, Orientation::Vertical);
+            }
+
+            apply_arrangement(
+                &mut child_bounds,
+                &mut size_counter,
+                margin,
+                (Alignment::Stretch, Alignment::Start),
+                Orientation::Vertical,
+                (100.0, 200.0),
+            );
+
+            bounds.push(child_bounds);
+        }
+
+        bounds[1].y() - (bounds[0].y() + bounds[0].height())
+    }
 }
@@ -0,0 +1,279 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
+};
+
+use dces::prelude::*;
+
+use crate::{
+    prelude::*, proc_macros::IntoLayout, render::RenderContext2D, theming::*, tree::Tree,
+    utils::prelude::*,
+};
+
+use super::{component, component_or_default, component_try_mut, mirror_children, Layout};
+
+/// Places its children row by row into cells of equal size. The number of columns is defined by the
+/// `column_count` property, if it is `0` the columns are calculated from the aspect ratio of the available size.
+#[derive(Default, IntoLayout)]
+pub struct UniformGridLayout {
+    desired_size: RefCell<DirtySize>,
+    old_alignment: Cell<(Alignment, Alignment)>,
+}
+
+impl UniformGridLayout {
+    pub fn new() -> Self {
+        UniformGridLayout::default()
+    }
+}
+
+impl Layout for UniformGridLayout {
+    fn measure(
+        &self,
+        render_context_2_d: &mut RenderContext2D,
+        entity: Entity,
+        ecm: &mut EntityComponentManager<Tree>,
+        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        theme: &Theme,
+    ) -> DirtySize {
+        if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
+            self.desired_size.borrow_mut().set_size(0.0, 0.0);
+            return *self.desired_size.borrow();
+        }
+
+        let halign: Alignment = component(ecm, entity, "h_align");
+        let valign: Alignment = component(ecm, entity, "v_align");
+        let (old_valign, old_halign) = self.old_alignment.get();
+
+        if halign != old_halign || valign != old_valign {
+            self.desired_size.borrow_mut().set_dirty(true);
+        }
+
+        self.old_alignment.set((valign, halign));
+
+        let mut cell_size = (0.0_f64, 0.0_f64);
+        let mut count = 0;
+
+        for index in 0..ecm.entity_store().children[&entity].len() {
+            let child = ecm.entity_store().children[&entity][index];
+
+            if let Some(child_layout) = layouts.get(&child) {
+                let child_desired_size =
+                    child_layout.measure(render_context_2_d, child, ecm, layouts, theme);
+
+                let dirty = child_desired_size.dirty() || self.desired_size.borrow().dirty();
+                self.desired_size.borrow_mut().set_dirty(dirty);
+
+                if component::<Visibility>(ecm, child, "visibility") == Visibility::Collapsed {
+                    continue;
+                }
+
+                let child_margin: Thickness = component(ecm, child, "margin");
+
+                cell_size.0 = cell_size
+                    .0
+                    .max(child_desired_size.width() + child_margin.left() + child_margin.right());
+                cell_size.1 = cell_size
+                    .1
+                    .max(child_desired_size.height() + child_margin.top() + child_margin.bottom());
+                count += 1;
+            }
+        }
+
+        let column_count: usize = component_or_default(ecm, entity, "column_count");
+        let (columns, rows) = grid_dimensions(count, column_count, 1.0);
+
+        let size = component::<Constraint>(ecm, entity, "constraint")
+            .perform((cell_size.0 * columns as f64, cell_size.1 * rows as f64));
+        self.desired_size.borrow_mut().set_size(size.0, size.1);
+
+        *self.desired_size.borrow()
+    }
+
+    fn arrange(
+        &self,
+        render_context_2_d: &mut RenderContext2D,
+        parent_size: (f64, f64),
+        entity: Entity,
+        ecm: &mut EntityComponentManager<Tree>,
+        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        theme: &Theme,
+    ) -> (f64, f64) {
+        if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
+            self.desired_size.borrow_mut().set_size(0.0, 0.0);
+            return (0.0, 0.0);
+        }
+
+        if !self.desired_size.borrow().dirty() {
+            return self.desired_size.borrow().size();
+        }
+
+        let halign: Alignment = component(ecm, entity, "h_align");
+        let valign: Alignment = component(ecm, entity, "v_align");
+        let margin: Thickness = component(ecm, entity, "margin");
+        let constraint: Constraint = component(ecm, entity, "constraint");
+
+        let size = constraint.perform((
+            halign.align_measure(
+                parent_size.0,
+                self.desired_size.borrow().width(),
+                margin.left(),
+                margin.right(),
+            ),
+            valign.align_measure(
+                parent_size.1,
+                self.desired_size.borrow().height(),
+                margin.top(),
+                margin.bottom(),
+            ),
+        ));
+
+        if let Some(bounds) = component_try_mut::<Rectangle>(ecm, entity, "bounds") {
+            bounds.set_width(size.0);
+            bounds.set_height(size.1);
+        }
+
+        // collapsed children do not take a cell
+        let children: Vec<Entity> = ecm.entity_store().children[&entity]
+            .clone()
+            .into_iter()
+            .filter(|child| {
+                component::<Visibility>(ecm, *child, "visibility") != Visibility::Collapsed
+            })
+            .collect();
+
+        let column_count: usize = component_or_default(ecm, entity, "column_count");
+        let aspect_ratio = if size.1 > 0.0 { size.0 / size.1 } else { 1.0 };
+        let (columns, _) = grid_dimensions(children.len(), column_count, aspect_ratio);
+        let cells = uniform_cells(children.len(), columns, size);
+
+        for (child, cell) in children.into_iter().zip(cells) {
+            let mut child_desired_size = (0.0, 0.0);
+            if let Some(child_layout) = layouts.get(&child) {
+                child_desired_size = child_layout.arrange(
+                    render_context_2_d,
+                    (cell.width(), cell.height()),
+                    child,
+                    ecm,
+                    layouts,
+                    theme,
+                );
+            }
+
+            let child_margin = {
+                if child_desired_size.0 > 0.0 && child_desired_size.1 > 0.0 {
+                    component(ecm, child, "margin")
+                } else {
+                    Thickness::default()
+                }
+            };
+
+            let child_halign: Alignment = component(ecm, child, "h_align");
+            let child_valign: Alignment = component(ecm, child, "v_align");
+
+            if let Some(child_bounds) = component_try_mut::<Rectangle>(ecm, child, "bounds") {
+                child_bounds.set_x(
+                    cell.x()
+                        + child_halign.align_position(
+                            cell.width(),
+                            child_bounds.width(),
+                            child_margin.left(),
+                            child_margin.right(),
+                        ),
+                );
+                child_bounds.set_y(
+                    cell.y()
+                        + child_valign.align_position(
+                            cell.height(),
+                            child_bounds.height(),
+                            child_margin.top(),
+                            child_margin.bottom(),
+                        ),
+                );
+            }
+
+            mark_as_dirty("bounds", child, ecm);
+        }
+
+        mirror_children(ecm, entity, size.0);
+
+        mark_as_dirty("bounds", entity, ecm);
+
+        self.desired_size.borrow_mut().set_dirty(false);
+        size
+    }
+}
+
+// returns the number of columns and rows that are needed for the given number of children, if the column count
+// is `0` the columns are chosen to get cells that are nearly square for the given aspect ratio (width / height)
+fn grid_dimensions(count: usize, column_count: usize, aspect_ratio: f64) -> (usize, usize) {
+    if count == 0 {
+        return (column_count, 0);
+    }
+
+    let columns = if column_count > 0 {
+        column_count
+    } else {
+        ((count as f64 * aspect_ratio).sqrt().ceil() as usize)
+            .max(1)
+            .min(count)
+    };
+
+    (columns, (count + columns - 1) / columns)
+}
+
+// returns the cells of the given number of children, the cells are filled row by row
+fn uniform_cells(count: usize, columns: usize, size: (f64, f64)) -> Vec<Rectangle> {
+    if count == 0 || columns == 0 {
+        return vec![];
+    }
+
+    let rows = (count + columns - 1) / columns;
+    let cell_size = (size.0 / columns as f64, size.1 / rows as f64);
+
+    (0..count)
+        .map(|index| {
+            Rectangle::new(
+                (
+                    (index % columns) as f64 * cell_size.0,
+                    (index / columns) as f64 * cell_size.1,
+                ),
+                cell_size,
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uniform_cells() {
+        // 7 children in 3 columns take 3 rows
+        assert_eq!(grid_dimensions(7, 3, 1.0), (3, 3));
+
+        let cells = uniform_cells(7, 3, (300.0, 150.0));
+        assert_eq!(cells.len(), 7);
+
+        for cell in &cells {
+            assert_eq!(cell.size(), Size::new(100.0, 50.0));
+        }
+
+        assert_eq!(cells[0].position(), Point::new(0.0, 0.0));
+        assert_eq!(cells[2].position(), Point::new(200.0, 0.0));
+        assert_eq!(cells[3].position(), Point::new(0.0, 50.0));
+        assert_eq!(cells[6].position(), Point::new(0.0, 100.0));
+
+        assert!(uniform_cells(0, 3, (300.0, 150.0)).is_empty());
+    }
+
+    #[test]
+    fn test_grid_dimensions() {
+        // the columns are calculated from the aspect ratio if no column count is set
+        assert_eq!(grid_dimensions(9, 0, 1.0), (3, 3));
+        assert_eq!(grid_dimensions(8, 0, 2.0), (4, 2));
+        assert_eq!(grid_dimensions(2, 0, 10.0), (2, 1));
+        assert_eq!(grid_dimensions(1, 0, 1.0), (1, 1));
+        assert_eq!(grid_dimensions(0, 0, 1.0), (0, 0));
+    }
+}
@@ -25,5 +25,8 @@ pub mod services;
 pub mod systems;
 pub mod widget_base;
 
+#[cfg(test)]
+mod test_support;
+
 #[macro_use]
 pub mod macros;
@@ -67,13 +67,23 @@ macro_rules! into_property_source {
 /// });
 /// ```
 ///
+/// For each property a typed key constant is generated, e.g. `PersonWidget::NAME` is a `PropertyKey<String>`.
+/// It can be used with `value`, `value_ref`, `value_mut` and `set_value` of the `WidgetContainer`, a misspelled
+/// key is a compile error where the string key only fails at runtime.
+///
+/// A property whose type implements [`CollectionProperty`] can name its items after `as` to get builder methods
+/// that change single items, e.g. `selected_indices: SelectedIndices as selected_index` generates
+/// `add_selected_index`, `remove_selected_index` and `clear_selected_indices`, together with the
+/// `selected_indices_add`, `selected_indices_remove` and `selected_indices_clear` setters.
+///
 /// [`Widget`]: ./widget/trait.Widget.html
+/// [`CollectionProperty`]: ./properties/trait.CollectionProperty.html
 /// [`builder`]: https://doc.rust-lang.org/1.0.0/style/ownership/builders.html
 /// [`builder pattern`]: https://en.wikipedia.org/wiki/Builder_pattern
 #[macro_export]
 macro_rules! widget {
     ( $(#[$widget_doc:meta])* $widget:ident $(<$state:ident>)* $(: $( $handler:ident ),*)*
-            $( { $($(#[$prop_doc:meta])* $property:ident: $property_type:tt ),*
+            $( { $($(#[$prop_doc:meta])* $property:ident: $property_type:tt $(as $item:ident)? ),*
                 $( attached_properties: { $($(#[$att_prop_doc:meta])* $att_property:ident: $att_property_type:tt ),* } )*
              } )* ) => {
         $(#[$widget_doc])*
@@ -97,6 +107,7 @@ macro_rules! widget {
             height: Option<f64>,
             name: Option<String>,
             style: Option<String>,
+            inline_style: Option<String>,
             id: Option<String>,
             #[property(Alignment)]
             h_align: Alignment,
@@ -108,8 +119,12 @@ macro_rules! widget {
             enabled: bool,
             #[property(bool)]
             clip: bool,
+            #[property(bool)]
+            hit_testable: bool,
             #[property(f32)]
             opacity: f32,
+            #[property(Transform)]
+            render_transform: Transform,
             #[property(Visibility)]
             visibility: Visibility,
             #[property(Selector)]
@@ -120,7 +135,7 @@ macro_rules! widget {
             _empty: Option<RefCell<i32>>,
              $(
                 $(
-                    #[property($property_type)]
+                    #[property($property_type $(, collection($item))?)]
                     $property: Option<PropertySource<$property_type>>,
                 )*
              )*
@@ -166,6 +181,13 @@ macro_rules! widget {
                 self
             }
 
+            /// Sets the inline style of the widget as RON map or CSS-like list of properties e.g. `background: #ff0000`.
+            /// The properties of the inline style overrides the properties read from the theme.
+            pub fn inline_style(mut self, inline_style: impl Into<String>) -> Self {
+                self.inline_style = Some(inline_style.into());
+                self
+            }
+
             /// Sets or shares the position of the widget. (Be careful the position could be adjusted by layouts).
             pub fn position(self, position: impl IntoPropertySource<Point>) -> Self {
                 self.set_property("position", position)
@@ -222,11 +244,23 @@ macro_rules! widget {
                 self.set_property("clip", clip)
             }
 
+            /// Sets or shares the hit testable property. A widget that is not hit testable is ignored by mouse
+            /// events, e.g. a click on it reaches the widget behind it. Its children are not affected.
+            pub fn hit_testable(self, hit_testable: impl IntoPropertySource<bool>) -> Self {
+                self.set_property("hit_testable", hit_testable)
+            }
+
             /// Sets or shares the opacity property.
             pub fn opacity(self, opacity: impl IntoPropertySource<f32>) -> Self {
                 self.set_property("opacity", opacity)
             }
 
+            /// Sets or shares the render transform property. The widget and its children are drawn under the
+            /// transform, it is applied after the layout and mouse positions are mapped back by its inverse.
+            pub fn render_transform(self, render_transform: impl IntoPropertySource<Transform>) -> Self {
+                self.set_property("render_transform", render_transform)
+            }
+
             /// Inserts a new width.
             pub fn width(mut self, width: impl Into<f64>) -> Self {
                 if !self.width.is_none() {
@@ -387,6 +421,7 @@ macro_rules! widget {
                     enabled: true,
                     opacity: 1.,
                     clip: false,
+                    hit_testable: true,
                     $(
                         $(
                             $property: None,
@@ -457,7 +492,9 @@ macro_rules! widget {
                 ctx.register_property("margin", entity, this.margin);
                 ctx.register_property("enabled", entity, this.enabled);
                 ctx.register_property("clip", entity, this.clip);
+                ctx.register_property("hit_testable", entity, this.hit_testable);
                 ctx.register_property("opacity", entity, this.opacity);
+                ctx.register_property("render_transform", entity, this.render_transform);
                 ctx.register_property("type_id", entity, TypeId::of::<$widget>());
                 ctx.register_property("type_name", entity, std::any::type_name::<$widget>().to_string());
                 ctx.register_property("dirty", entity, false);
@@ -525,6 +562,10 @@ macro_rules! widget {
                     this.selector
                 };
 
+                if let Some(inline_style) = this.inline_style {
+                    selector.set_inline_style(inline_style);
+                }
+
                 // initial set disabled
                 if ctx.get_widget(entity).has::<bool>("enabled") && !*ctx.get_widget(entity).get::<bool>("enabled") {
                     selector.push_state("disabled");
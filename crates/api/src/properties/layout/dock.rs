@@ -0,0 +1,32 @@
+/// The `Dock` defines the edge of a `DockLayout` a child is docked to.
+#[derive(Copy, Debug, Clone, PartialEq)]
+pub enum Dock {
+    /// The child is docked to the left edge and takes the full remaining height.
+    Left,
+
+    /// The child is docked to the top edge and takes the full remaining width.
+    Top,
+
+    /// The child is docked to the right edge and takes the full remaining height.
+    Right,
+
+    /// The child is docked to the bottom edge and takes the full remaining width.
+    Bottom,
+}
+
+impl Default for Dock {
+    fn default() -> Self {
+        Dock::Left
+    }
+}
+
+impl From<&str> for Dock {
+    fn from(s: &str) -> Dock {
+        match s {
+            "Top" | "top" => Dock::Top,
+            "Right" | "right" => Dock::Right,
+            "Bottom" | "bottom" => Dock::Bottom,
+            _ => Dock::Left,
+        }
+    }
+}
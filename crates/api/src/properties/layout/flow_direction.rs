@@ -0,0 +1,28 @@
+/// The `FlowDirection` defines the horizontal direction in which a layout places its children.
+#[derive(Copy, Debug, Clone, PartialEq)]
+pub enum FlowDirection {
+    /// The flow direction of the parent is used, the root widget flows from left to right.
+    Inherit,
+
+    /// Children flow from left to right, `Alignment::Start` is the left edge.
+    LeftToRight,
+
+    /// Children flow from right to left, the layout is mirrored and `Alignment::Start` is the right edge.
+    RightToLeft,
+}
+
+impl Default for FlowDirection {
+    fn default() -> Self {
+        FlowDirection::Inherit
+    }
+}
+
+impl From<&str> for FlowDirection {
+    fn from(s: &str) -> FlowDirection {
+        match s {
+            "LeftToRight" | "left_to_right" | "ltr" => FlowDirection::LeftToRight,
+            "RightToLeft" | "right_to_left" | "rtl" => FlowDirection::RightToLeft,
+            _ => FlowDirection::Inherit,
+        }
+    }
+}
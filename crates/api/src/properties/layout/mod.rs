@@ -1,7 +1,13 @@
 // Layout specific properties.
 
 pub use self::block::*;
+pub use self::dock::*;
+pub use self::flow_direction::*;
+pub use self::scroll_bar_visibility::*;
 pub use self::scroll_viewer_mode::*;
 
 mod block;
+mod dock;
+mod flow_direction;
+mod scroll_bar_visibility;
 mod scroll_viewer_mode;
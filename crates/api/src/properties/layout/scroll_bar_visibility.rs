@@ -0,0 +1,28 @@
+/// The `ScrollBarVisibility` defines when the scroll bars of a scroll viewer are shown.
+#[derive(Copy, Debug, Clone, PartialEq)]
+pub enum ScrollBarVisibility {
+    /// The scroll bars are always shown.
+    Always,
+
+    /// The scroll bars are shown if the content is larger than the scroll viewer.
+    Auto,
+
+    /// The scroll bars are shown over the content while it is scrolled or hovered and fade out afterwards.
+    Overlay,
+}
+
+impl Default for ScrollBarVisibility {
+    fn default() -> Self {
+        ScrollBarVisibility::Auto
+    }
+}
+
+impl From<&str> for ScrollBarVisibility {
+    fn from(s: &str) -> ScrollBarVisibility {
+        match s {
+            "Always" | "always" => ScrollBarVisibility::Always,
+            "Overlay" | "overlay" => ScrollBarVisibility::Overlay,
+            _ => ScrollBarVisibility::Auto,
+        }
+    }
+}
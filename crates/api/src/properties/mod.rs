@@ -49,6 +49,38 @@ pub trait IntoPropertySource<P: Component + Debug> {
     fn into_source(self) -> PropertySource<P>;
 }
 
+/// Used by the `add_*`, `remove_*` and `clear_*` methods that are generated for collection properties (e.g.
+/// `selected_indices`) to change the items of the property without replacing it.
+pub trait CollectionProperty: Component + Debug + Default {
+    /// The type of the items of the collection.
+    type Item;
+
+    /// Adds the given item to the collection.
+    fn add_item(&mut self, item: Self::Item);
+
+    /// Removes the given item from the collection.
+    fn remove_item(&mut self, item: &Self::Item);
+
+    /// Removes all items from the collection.
+    fn clear_items(&mut self);
+}
+
+impl<T: Component + Debug + PartialEq> CollectionProperty for Vec<T> {
+    type Item = T;
+
+    fn add_item(&mut self, item: T) {
+        self.push(item);
+    }
+
+    fn remove_item(&mut self, item: &T) {
+        self.retain(|i| i != item);
+    }
+
+    fn clear_items(&mut self) {
+        self.clear();
+    }
+}
+
 /// Used ot generate attached properties.
 pub struct AttachedProperty<P>
 where
@@ -87,9 +119,11 @@ into_property_source!(PathBuf);
 // Implementation of PropertySource for utils types
 into_property_source!(utils::Alignment: &str);
 into_property_source!(utils::Brush: &str, utils::Color, utils::Value);
+into_property_source!(utils::BoxShadow: &str, String, utils::Value);
 into_property_source!(utils::Orientation: &str);
 into_property_source!(utils::Point: f64, i32, (i32, i32), (f64, f64));
 into_property_source!(utils::Size: f64, i32, (i32, i32), (f64, f64));
+into_property_source!(utils::Transform);
 into_property_source!(
     utils::Rectangle: (i32, i32, i32, i32),
     (f64, f64, f64, f64),
@@ -106,6 +140,7 @@ into_property_source!(
 );
 into_property_source!(utils::SelectionMode: &str);
 into_property_source!(utils::Visibility: &str);
+into_property_source!(Vec<f64>);
 into_property_source!(Vec<String>);
 into_property_source!(utils::Filter: &str, String, Vec<String>, Vec<&str>);
 
@@ -118,8 +153,11 @@ into_property_source!(render::Image: &str, String, (u32, u32, Vec<u32>));
 
 // Implementation of custom property types
 into_property_source!(Blocks: BlocksBuilder, &str, String);
+into_property_source!(Dock: &str);
+into_property_source!(FlowDirection: &str);
 into_property_source!(utils::Constraint: utils::ConstraintBuilder);
 into_property_source!(DefaultRenderPipeline);
+into_property_source!(ScrollBarVisibility: &str);
 into_property_source!(ScrollViewerMode: (&str, &str));
 into_property_source!(SelectedEntities: HashSet<Entity>);
 into_property_source!(SelectedIndices: HashSet<usize>);
@@ -2,6 +2,8 @@ use std::collections::HashSet;
 
 use dces::prelude::*;
 
+use crate::properties::CollectionProperty;
+
 /// `SelectedEntities` describes a list of selected entities.
 #[derive(Clone, Default, Debug, PartialEq)]
 pub struct SelectedEntities(pub HashSet<Entity>);
@@ -11,3 +13,19 @@ impl From<HashSet<Entity>> for SelectedEntities {
         SelectedEntities(i)
     }
 }
+
+impl CollectionProperty for SelectedEntities {
+    type Item = Entity;
+
+    fn add_item(&mut self, item: Entity) {
+        self.0.insert(item);
+    }
+
+    fn remove_item(&mut self, item: &Entity) {
+        self.0.remove(item);
+    }
+
+    fn clear_items(&mut self) {
+        self.0.clear();
+    }
+}
@@ -1,5 +1,7 @@
 use std::collections::HashSet;
 
+use crate::properties::CollectionProperty;
+
 /// `SelectedIndices` describes a list of selected indices.
 #[derive(Clone, Default, Debug, PartialEq)]
 pub struct SelectedIndices(pub HashSet<usize>);
@@ -9,3 +11,19 @@ impl From<HashSet<usize>> for SelectedIndices {
         SelectedIndices(i)
     }
 }
+
+impl CollectionProperty for SelectedIndices {
+    type Item = usize;
+
+    fn add_item(&mut self, item: usize) {
+        self.0.insert(item);
+    }
+
+    fn remove_item(&mut self, item: &usize) {
+        self.0.remove(item);
+    }
+
+    fn clear_items(&mut self) {
+        self.0.clear();
+    }
+}
@@ -39,8 +39,11 @@ impl RenderObject for CursorRenderObject {
 
         let border_width = border_width.right();
 
+        let alpha = ctx.render_context_2_d().alpha();
+
         // background
-        ctx.render_context_2_d().set_alpha(background_opacity);
+        ctx.render_context_2_d()
+            .set_alpha(alpha * background_opacity);
         ctx.render_context_2_d().set_fill_style(background);
         ctx.render_context_2_d().fill_rect(
             global_position.x() + bounds.x() + offset + selection_x - border_width / 2.,
@@ -48,7 +51,7 @@ impl RenderObject for CursorRenderObject {
             selection_width,
             bounds.height(),
         );
-        ctx.render_context_2_d().set_alpha(1.);
+        ctx.render_context_2_d().set_alpha(alpha);
 
         // border
         ctx.render_context_2_d().set_fill_style(border_brush);
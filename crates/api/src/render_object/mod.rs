@@ -55,13 +55,32 @@ pub trait RenderObject: Any {
             return;
         }
 
+        // the opacity of a widget is applied to the whole widget including its children
+        let parent_alpha = render_context.alpha();
+
         render_context.begin_path();
         render_context.set_alpha(
-            *ecm.component_store()
-                .get::<f32>("opacity", entity)
-                .unwrap_or(&1.0),
+            parent_alpha
+                * *ecm
+                    .component_store()
+                    .get::<f32>("opacity", entity)
+                    .unwrap_or(&1.0),
         );
 
+        // the render transform of a widget is applied to the whole widget including its children
+        let transformed = ecm
+            .component_store()
+            .get::<Transform>("render_transform", entity)
+            .map(|transform| *transform != Transform::identity())
+            .unwrap_or(false);
+
+        if transformed {
+            set_transform(
+                render_context,
+                global_render_transform(entity, ecm).unwrap_or_default(),
+            );
+        }
+
         // Could be unwrap because every widget has the clip property
         let clip = *ecm.component_store().get::<bool>("clip", entity).unwrap();
         if clip {
@@ -116,6 +135,8 @@ pub trait RenderObject: Any {
             render_context.restore();
         }
 
+        render_context.set_alpha(parent_alpha);
+
         // render debug border for each widget
         if debug {
             if let Ok(bounds) = ecm.component_store().get::<Rectangle>("bounds", entity) {
@@ -130,6 +151,12 @@ pub trait RenderObject: Any {
                 render_context.close_path();
             }
         }
+
+        if transformed {
+            let parent_transform = ecm.entity_store().parent[&entity]
+                .and_then(|parent| global_render_transform(parent, ecm));
+            set_transform(render_context, parent_transform.unwrap_or_default());
+        }
     }
 
     fn render_self(&self, _: &mut Context, _: &Point) {}
@@ -161,3 +188,117 @@ pub trait RenderObject: Any {
         }
     }
 }
+
+// sets the given transform as the transform of the render context
+fn set_transform(render_context: &mut RenderContext2D, transform: Transform) {
+    render_context.set_transform(
+        transform.h_scaling,
+        transform.h_skewing,
+        transform.v_skewing,
+        transform.v_scaling,
+        transform.h_moving,
+        transform.v_moving,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TestContext;
+
+    /// Registers the properties the render objects read on a new widget with the given bounds and background.
+    pub fn widget(
+        ecm: &mut EntityComponentManager<Tree>,
+        bounds: Rectangle,
+        background: impl Into<Brush>,
+    ) -> Entity {
+        let entity = ecm.create_entity().build();
+        let store = ecm.component_store_mut();
+        store.register("visibility", entity, Visibility::Visible);
+        store.register("opacity", entity, 1.0_f32);
+        store.register("clip", entity, false);
+        store.register("bounds", entity, bounds);
+        store.register("background", entity, background.into());
+
+        entity
+    }
+
+    /// Renders the widget with the given render object into an offscreen render context.
+    pub fn render(
+        render_object: &dyn RenderObject,
+        test_context: &mut TestContext,
+        entity: Entity,
+        width: f64,
+        height: f64,
+    ) -> RenderContext2D {
+        let mut render_context = RenderContext2D::new_headless(width, height);
+
+        render_object.render(
+            &mut render_context,
+            entity,
+            &mut test_context.ecm,
+            &test_context.provider,
+            &test_context.theme,
+            &mut BTreeMap::new(),
+            false,
+        );
+
+        render_context
+    }
+
+    #[test]
+    fn test_opacity() {
+        let mut test_context = TestContext::new();
+        let entity = widget(
+            &mut test_context.ecm,
+            Rectangle::new((0.0, 0.0), (10.0, 10.0)),
+            "#ffffff",
+        );
+        test_context
+            .ecm
+            .component_store_mut()
+            .register("opacity", entity, 0.5_f32);
+
+        let render_context = render(
+            &RectangleRenderObject,
+            &mut test_context,
+            entity,
+            10.0,
+            10.0,
+        );
+
+        // the background is drawn at half alpha
+        let alpha = render_context.get_image_data(5.0, 5.0, 1.0, 1.0)[3];
+        assert!((127..=128).contains(&alpha), "alpha is {}", alpha);
+
+        // the alpha of the render context is restored for the following widgets
+        assert!((render_context.alpha() - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_render_transform() {
+        let mut test_context = TestContext::new();
+        let entity = widget(
+            &mut test_context.ecm,
+            Rectangle::new((0.0, 0.0), (5.0, 5.0)),
+            "#ffffff",
+        );
+        test_context.ecm.component_store_mut().register(
+            "render_transform",
+            entity,
+            Transform::scale(2.0, 2.0),
+        );
+
+        let render_context = render(
+            &RectangleRenderObject,
+            &mut test_context,
+            entity,
+            20.0,
+            20.0,
+        );
+
+        // the background is drawn with twice its size
+        assert_eq!(render_context.get_image_data(8.0, 8.0, 1.0, 1.0)[3], 255);
+        assert_eq!(render_context.get_image_data(12.0, 12.0, 1.0, 1.0)[3], 0);
+    }
+}
@@ -5,9 +5,12 @@ use crate::{
     render::RenderContext2D,
     render_object::*,
     utils,
-    utils::{Brush, Point, Rectangle, Thickness},
+    utils::{BoxShadow, Brush, Color, Point, Rectangle, Thickness},
 };
 
+// Maximum count of layers used to approximate the blur of a box shadow.
+const MAX_SHADOW_LAYERS: f64 = 16.0;
+
 #[derive(Debug, IntoRenderObject)]
 pub struct RectangleRenderObject;
 
@@ -98,6 +101,50 @@ impl RectangleRenderObject {
         }
     }
 
+    // Renders the shadow of the rectangle. The blur is approximated by layers of translucent
+    // rectangles, which grow from the inner to the outer edge of the blur.
+    fn render_box_shadow(
+        &self,
+        render_context_2_d: &mut RenderContext2D,
+        rect: Rectangle,
+        radius: f64,
+        box_shadow: BoxShadow,
+    ) {
+        let layers = box_shadow.blur.ceil().max(1.0).min(MAX_SHADOW_LAYERS);
+        let color = box_shadow.color;
+
+        render_context_2_d.set_fill_style(Color::rgba(
+            color.r(),
+            color.g(),
+            color.b(),
+            (color.a() as f64 / layers).ceil() as u8,
+        ));
+
+        for layer in 0..layers as usize {
+            let extent = box_shadow.spread - box_shadow.blur / 2.0
+                + box_shadow.blur * (layers - layer as f64) / layers;
+            let x = rect.x() + box_shadow.offset.x() - extent;
+            let y = rect.y() + box_shadow.offset.y() - extent;
+            let width = rect.width() + 2.0 * extent;
+            let height = rect.height() + 2.0 * extent;
+
+            if width <= 0.0 || height <= 0.0 {
+                continue;
+            }
+
+            render_context_2_d.begin_path();
+
+            if radius > 0.0 {
+                let radius = (radius + extent).max(0.0).min(width.min(height) / 2.0);
+                self.render_rounded_rect_path(render_context_2_d, x, y, width, height, radius);
+            } else {
+                render_context_2_d.rect(x, y, width, height);
+            }
+
+            render_context_2_d.fill();
+        }
+    }
+
     // Renders rectangle with border and radius.
     fn render_rounded_bordered_rect_path(
         &self,
@@ -132,7 +179,15 @@ impl RectangleRenderObject {
 
 impl RenderObject for RectangleRenderObject {
     fn render_self(&self, ctx: &mut Context, global_position: &Point) {
-        let (bounds, background, border_radius, border_thickness, border_brush) = {
+        let (
+            bounds,
+            background,
+            border_radius,
+            border_thickness,
+            border_brush,
+            box_shadow,
+            backdrop_blur,
+        ) = {
             let widget = ctx.widget();
             (
                 widget.clone::<Rectangle>("bounds"),
@@ -140,9 +195,30 @@ impl RenderObject for RectangleRenderObject {
                 widget.clone_or_default::<f64>("border_radius"),
                 widget.clone_or_default::<Thickness>("border_width"),
                 widget.clone_or_default::<Brush>("border_brush"),
+                widget.clone_or_default::<BoxShadow>("box_shadow"),
+                widget.clone_or_default::<f64>("backdrop_blur"),
             )
         };
 
+        if bounds.width() > 0.0 && bounds.height() > 0.0 && backdrop_blur > 0.0 {
+            ctx.render_context_2_d().blur_region(
+                global_position.x() + bounds.x(),
+                global_position.y() + bounds.y(),
+                bounds.width(),
+                bounds.height(),
+                backdrop_blur,
+            );
+        }
+
+        if bounds.width() > 0.0 && bounds.height() > 0.0 && !box_shadow.is_transparent() {
+            self.render_box_shadow(
+                ctx.render_context_2_d(),
+                Rectangle::new(*global_position + bounds.position(), bounds.size()),
+                border_radius,
+                box_shadow,
+            );
+        }
+
         if (bounds.width() == 0.0
             || bounds.height() == 0.0
             || (background.is_transparent() && border_brush.is_transparent()))
@@ -228,3 +304,72 @@ impl RenderObject for RectangleRenderObject {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use dces::prelude::*;
+
+    use super::*;
+    use crate::{
+        render_object::tests::{render, widget},
+        tree::Tree,
+    };
+
+    // renders a widget at (10, 10) with a size of 20 x 20 and the given box shadow into a context of 50 x 50
+    fn render_box_shadow(box_shadow: BoxShadow) -> Vec<u8> {
+        let mut ecm = EntityComponentManager::new(Tree::default());
+        let entity = widget(
+            &mut ecm,
+            Rectangle::new((10.0, 10.0), (20.0, 20.0)),
+            "#ffffff",
+        );
+        ecm.component_store_mut()
+            .register("box_shadow", entity, box_shadow);
+
+        render(&RectangleRenderObject, &mut ecm, entity, 50.0, 50.0)
+            .get_image_data(0.0, 0.0, 50.0, 50.0)
+    }
+
+    fn alpha(image_data: &[u8], x: usize, y: usize) -> u8 {
+        image_data[(y * 50 + x) * 4 + 3]
+    }
+
+    #[test]
+    fn test_box_shadow_offset() {
+        let image_data = render_box_shadow(BoxShadow::new((5.0, 5.0), 0.0, 0.0, "#000000"));
+
+        // the shadow is drawn outside of the bounds in the direction of the offset
+        assert_eq!(alpha(&image_data, 32, 32), 255);
+        assert_eq!(alpha(&image_data, 20, 33), 255);
+        assert_eq!(alpha(&image_data, 33, 20), 255);
+
+        // and ends at the offset bounds
+        assert_eq!(alpha(&image_data, 38, 38), 255);
+        assert_eq!(alpha(&image_data, 41, 41), 0);
+
+        // nothing is drawn on the opposite sides of the bounds
+        assert_eq!(alpha(&image_data, 8, 8), 0);
+        assert_eq!(alpha(&image_data, 20, 8), 0);
+        assert_eq!(alpha(&image_data, 32, 12), 0);
+    }
+
+    #[test]
+    fn test_transparent_box_shadow() {
+        let image_data = render_box_shadow(BoxShadow::new(
+            (5.0, 5.0),
+            4.0,
+            2.0,
+            Color::rgba(0, 0, 0, 0),
+        ));
+
+        for y in 0..50 {
+            for x in 0..50 {
+                if (10..30).contains(&x) && (10..30).contains(&y) {
+                    continue;
+                }
+
+                assert_eq!(alpha(&image_data, x, y), 0, "pixel ({}, {})", x, y);
+            }
+        }
+    }
+}
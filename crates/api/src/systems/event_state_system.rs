@@ -143,6 +143,43 @@ impl EventStateSystem {
         false
     }
 
+    fn process_top_down_event(
+        &self,
+        event: &EventBox,
+        ecm: &mut EntityComponentManager<Tree>,
+    ) -> bool {
+        let route = top_down_route(ecm.entity_store(), event.source, &|node| {
+            ecm.component_store()
+                .get::<bool>("enabled", node)
+                .map(|enabled| *enabled)
+                .unwrap_or(true)
+        });
+
+        let message_adapter = self.context_provider.message_adapter.clone();
+        let mut update = false;
+
+        propagate(&route, |node| {
+            if let Some(handlers) = self.context_provider.handler_map.borrow().get(&node) {
+                update = true;
+
+                return handlers.iter().any(|handler| {
+                    handler.handle_event(
+                        &mut StatesContext::new(
+                            &mut *self.context_provider.states.borrow_mut(),
+                            ecm,
+                            &message_adapter,
+                        ),
+                        event,
+                    )
+                });
+            }
+
+            false
+        });
+
+        update
+    }
+
     fn process_bottom_up_event(
         &self,
         mouse_position: Point,
@@ -248,10 +285,20 @@ impl EventStateSystem {
                     }
                     unknown_event = false;
                 }
-                // click handling
-                if let Ok(event) = event.downcast_ref::<ClickEvent>() {
+                // click and double click handling
+                let click_position = event
+                    .downcast_ref::<ClickEvent>()
+                    .map(|event| event.position)
+                    .or_else(|_| {
+                        event
+                            .downcast_ref::<DoubleClickEvent>()
+                            .map(|event| event.position)
+                    })
+                    .ok();
+
+                if let Some(position) = click_position {
                     if check_mouse_condition(
-                        event.position,
+                        position,
                         &WidgetContainer::new(
                             current_node,
                             ecm,
@@ -262,7 +309,7 @@ impl EventStateSystem {
                         let mut add = true;
                         if let Some(op) = clipped_parent.get(0) {
                             if !check_mouse_condition(
-                                event.position,
+                                position,
                                 &WidgetContainer::new(
                                     *op,
                                     ecm,
@@ -476,15 +523,20 @@ impl System<Tree, RenderContext2D> for EventStateSystem {
                         }
                     }
 
+                    let event = match self.context_provider.event_filter.filter(event) {
+                        Some(event) => event,
+                        None => continue,
+                    };
+
                     match event.strategy {
                         EventStrategy::Direct => {
                             if event.strategy == EventStrategy::Direct {
                                 update = self.process_direct(&event, ecm) || update;
                             }
                         }
-                        // EventStrategy::TopDown => {
-                        //     self.process_top_down_event(&event, ecm);
-                        // }
+                        EventStrategy::TopDown => {
+                            update = self.process_top_down_event(&event, ecm) || update;
+                        }
                         EventStrategy::BottomUp => {
                             let should_update =
                                 self.process_bottom_up_event(mouse_position, &event, ecm);
@@ -630,3 +682,203 @@ impl System<Tree, RenderContext2D> for EventStateSystem {
         }
     }
 }
+
+// --- Helpers --
+
+// returns the given node and its descendants, parents before their children. Disabled widgets and their
+// descendants are skipped.
+fn top_down_route(tree: &Tree, node: Entity, is_enabled: &dyn Fn(Entity) -> bool) -> Vec<Entity> {
+    let mut route = vec![];
+    push_route(tree, node, is_enabled, &mut route);
+    route
+}
+
+fn push_route(
+    tree: &Tree,
+    node: Entity,
+    is_enabled: &dyn Fn(Entity) -> bool,
+    route: &mut Vec<Entity>,
+) {
+    if !is_enabled(node) {
+        return;
+    }
+
+    route.push(node);
+
+    if let Some(children) = tree.children.get(&node) {
+        for child in children {
+            push_route(tree, *child, is_enabled, route);
+        }
+    }
+}
+
+// calls the handle function with the nodes of the route until the event is handled, returns `true` if it is handled
+fn propagate(route: &[Entity], mut handle: impl FnMut(Entity) -> bool) -> bool {
+    route.iter().any(|node| handle(*node))
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TestContext;
+
+    // root (0) -> container (1) -> button (2)
+    fn tree() -> Tree {
+        let mut tree = Tree::new();
+
+        for entity in 0..3 {
+            tree.register_node(entity);
+        }
+
+        tree.set_root(0);
+        tree.append_child(0, 1).unwrap();
+        tree.append_child(1, 2).unwrap();
+        tree
+    }
+
+    fn invocations(route: &[Entity], handled_by: Option<Entity>) -> Vec<Entity> {
+        let mut calls = vec![];
+
+        propagate(route, |node| {
+            calls.push(node);
+            Some(node) == handled_by
+        });
+
+        calls
+    }
+
+    #[test]
+    fn test_top_down() {
+        let tree = tree();
+        let top_down = top_down_route(&tree, Entity(0), &|_| true);
+        let bottom_up: Vec<Entity> = top_down.iter().rev().cloned().collect();
+
+        assert_eq!(
+            invocations(&top_down, None),
+            vec![Entity(0), Entity(1), Entity(2)]
+        );
+        assert_eq!(
+            invocations(&bottom_up, None),
+            vec![Entity(2), Entity(1), Entity(0)]
+        );
+
+        // the container stops the propagation
+        assert_eq!(
+            invocations(&top_down, Some(Entity(1))),
+            vec![Entity(0), Entity(1)]
+        );
+        assert_eq!(
+            invocations(&bottom_up, Some(Entity(1))),
+            vec![Entity(2), Entity(1)]
+        );
+
+        // starts at the source, disabled widgets and their children are skipped
+        assert_eq!(
+            top_down_route(&tree, Entity(1), &|_| true),
+            vec![Entity(1), Entity(2)]
+        );
+        assert_eq!(
+            top_down_route(&tree, Entity(0), &|node| node != Entity(1)),
+            vec![Entity(0)]
+        );
+    }
+
+    struct TestEvent;
+
+    impl Event for TestEvent {}
+
+    // records the widget it is registered on, the event is handled by the widget that equals `handled_by`
+    struct RecordHandler {
+        widget: Entity,
+        handled_by: Option<Entity>,
+        calls: Rc<RefCell<Vec<Entity>>>,
+    }
+
+    impl EventHandler for RecordHandler {
+        fn handle_event(&self, _: &mut StatesContext, _: &EventBox) -> bool {
+            self.calls.borrow_mut().push(self.widget);
+            Some(self.widget) == self.handled_by
+        }
+
+        fn handles_event(&self, event: &EventBox) -> bool {
+            event.downcast_ref::<TestEvent>().is_ok()
+        }
+    }
+
+    // builds the widgets root -> container -> button with a handler on each of them, pushes an event from the
+    // root with the given function and runs the event state system. Returns the widgets from the root to the
+    // button and the widgets whose handlers were called in that order.
+    fn dispatch(
+        push: impl Fn(&EventAdapter, Entity),
+        handled_by: Option<usize>,
+    ) -> (Vec<Entity>, Vec<Entity>) {
+        let mut test_context = TestContext::new();
+        let calls = Rc::new(RefCell::new(vec![]));
+
+        let widgets = test_context.build(|ctx| {
+            let button = Overlay::new().build(ctx);
+            let container = Overlay::new().child(button).build(ctx);
+            let root = Overlay::new().child(container).build(ctx);
+            let widgets = vec![root, container, button];
+
+            for widget in &widgets {
+                ctx.register_handler(
+                    *widget,
+                    Rc::new(RecordHandler {
+                        widget: *widget,
+                        handled_by: handled_by.map(|index| widgets[index]),
+                        calls: calls.clone(),
+                    }),
+                );
+            }
+
+            widgets
+        });
+
+        let root = widgets[0];
+        let theme = test_context.theme.clone();
+        let ecm = &mut test_context.ecm;
+        ecm.entity_store_mut().set_root(root);
+        ecm.component_store_mut().register("theme", root, theme);
+        ecm.component_store_mut()
+            .register("dirty_widgets", root, Vec::<Entity>::new());
+
+        push(&test_context.provider.event_adapter, root);
+
+        EventStateSystem::new(
+            test_context.provider.clone(),
+            Rc::new(RefCell::new(Registry::new())),
+            RefCell::new(vec![]),
+        )
+        .run_with_context(
+            &mut test_context.ecm,
+            &mut RenderContext2D::new(100.0, 100.0),
+        );
+
+        let calls = calls.borrow().clone();
+        (widgets, calls)
+    }
+
+    #[test]
+    fn test_dispatch_order() {
+        let top_down = |adapter: &EventAdapter, root| adapter.push_event_top_down(root, TestEvent);
+        let bottom_up = |adapter: &EventAdapter, root| adapter.push_event(root, TestEvent);
+
+        // parents are called before their children
+        let (widgets, calls) = dispatch(top_down, None);
+        assert_eq!(calls, widgets);
+
+        // children are called before their parents
+        let (widgets, calls) = dispatch(bottom_up, None);
+        assert_eq!(calls, widgets.into_iter().rev().collect::<Vec<_>>());
+
+        // the container stops the propagation
+        let (widgets, calls) = dispatch(top_down, Some(1));
+        assert_eq!(calls, vec![widgets[0], widgets[1]]);
+
+        let (widgets, calls) = dispatch(bottom_up, Some(1));
+        assert_eq!(calls, vec![widgets[2], widgets[1]]);
+    }
+}
@@ -75,7 +75,7 @@ impl System<Tree, RenderContext2D> for InitSystem {
 }
 
 pub fn print_tree(entity: Entity, depth: usize, ecm: &mut EntityComponentManager<Tree>) {
-    let name = ecm.component_store().get::<String>("name", entity).unwrap();
+    let name = name_of(ecm, entity).unwrap();
 
     let selector = if let Ok(selector) = ecm.component_store().get::<Selector>("selector", entity) {
         selector.clone()
@@ -7,6 +7,7 @@ pub use self::init_system::*;
 pub use self::layout_system::*;
 pub use self::post_layout_state_system::*;
 pub use self::render_system::*;
+pub use self::transition_system::*;
 
 mod cleanup_system;
 mod event_state_system;
@@ -14,3 +15,4 @@ mod init_system;
 mod layout_system;
 mod post_layout_state_system;
 mod render_system;
+mod transition_system;
@@ -0,0 +1,116 @@
+use dces::prelude::*;
+
+use crate::{
+    prelude::*,
+    render::RenderContext2D,
+    shell::WindowRequest,
+    theming::{TransitionValue, Transitions},
+    tree::Tree,
+    utils::Brush,
+};
+
+/// Returns the current time in milliseconds. It is used as time base of transitions and animations.
+///
+/// The time is monotonic and counted from the first call on the current thread, it is only comparable with other
+/// values of the same thread.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn now() -> f64 {
+    use std::time::Instant;
+
+    thread_local! {
+        static START: Instant = Instant::now();
+    }
+
+    START.with(|start| start.elapsed().as_secs_f64() * 1000.0)
+}
+
+/// Returns the current time in milliseconds. It is used as time base of transitions and animations.
+#[cfg(target_arch = "wasm32")]
+pub fn now() -> f64 {
+    stdweb::web::Date::now()
+}
+
+/// The `TransitionSystem` animates the styled properties of widgets after a state change, if the style defines
+/// transitions for them. Only the widgets in the `transition_widgets` list of the window are visited, a widget is
+/// removed from the list after its last transition is finished.
+#[derive(Constructor)]
+pub struct TransitionSystem {
+    context_provider: ContextProvider,
+}
+
+impl System<Tree, RenderContext2D> for TransitionSystem {
+    fn run_with_context(
+        &self,
+        ecm: &mut EntityComponentManager<Tree>,
+        _render_context: &mut RenderContext2D,
+    ) {
+        let root = ecm.entity_store().root();
+
+        let entities = match ecm
+            .component_store()
+            .get::<Vec<Entity>>("transition_widgets", root)
+        {
+            Ok(entities) if !entities.is_empty() => entities.clone(),
+            _ => return,
+        };
+
+        let time = now();
+        let mut running = vec![];
+
+        for entity in entities {
+            let values = match ecm
+                .component_store_mut()
+                .get_mut::<Transitions>("style_transitions", entity)
+            {
+                Ok(transitions) => {
+                    let values = transitions.tick(time);
+
+                    if !transitions.is_empty() {
+                        running.push(entity);
+                    }
+
+                    values
+                }
+                // the widget is removed
+                Err(_) => continue,
+            };
+
+            for (key, value) in values {
+                match value {
+                    TransitionValue::Brush(brush) => {
+                        if let Ok(property) =
+                            ecm.component_store_mut().get_mut::<Brush>(&key, entity)
+                        {
+                            *property = brush;
+                        }
+                    }
+                    TransitionValue::Number(number) => {
+                        if let Ok(property) = ecm.component_store_mut().get_mut::<f64>(&key, entity)
+                        {
+                            *property = number;
+                        }
+                    }
+                }
+
+                mark_as_dirty(&key, entity, ecm);
+            }
+        }
+
+        let running_transitions = !running.is_empty();
+
+        if let Ok(transition_widgets) = ecm
+            .component_store_mut()
+            .get_mut::<Vec<Entity>>("transition_widgets", root)
+        {
+            *transition_widgets = running;
+        }
+
+        // keeps the update loop running until all transitions are finished
+        if running_transitions {
+            self.context_provider
+                .window_sender
+                .send(WindowRequest::Redraw)
+                .expect("TransitionSystem: Cannot send redraw request.");
+        }
+    }
+}
@@ -0,0 +1,75 @@
+//! Fixtures shared by the unit tests of this crate.
+
+use std::sync::mpsc;
+
+use dces::prelude::*;
+
+use crate::{
+    application::{ContextProvider, WindowAdapter},
+    prelude::*,
+    render::RenderContext2D,
+    shell::{ShellRequest, WindowRequest},
+    theming::Theme,
+    tree::Tree,
+};
+
+/// Owns everything that is needed to build widgets and to create a `Context` for them outside of a window.
+pub struct TestContext {
+    pub ecm: EntityComponentManager<Tree>,
+    pub provider: ContextProvider,
+    pub theme: Theme,
+    render_context: RenderContext2D,
+    // keeps the channels of the provider open, sending requests would fail otherwise
+    _window_receiver: mpsc::Receiver<WindowRequest>,
+    _shell_receiver: mpsc::Receiver<ShellRequest<WindowAdapter>>,
+}
+
+impl TestContext {
+    /// Creates an empty test context with the default theme.
+    pub fn new() -> Self {
+        let (window_sender, window_receiver) = mpsc::channel();
+        let (shell_sender, shell_receiver) = mpsc::channel();
+
+        TestContext {
+            ecm: EntityComponentManager::new(Tree::default()),
+            provider: ContextProvider::new(
+                window_sender,
+                shell_sender,
+                "test",
+                None,
+                EventFilter::new(),
+                None,
+            ),
+            theme: Theme::default(),
+            render_context: RenderContext2D::new(100.0, 100.0),
+            _window_receiver: window_receiver,
+            _shell_receiver: shell_receiver,
+        }
+    }
+
+    /// Calls the given function with a `BuildContext` that registers the built widgets in this context.
+    pub fn build<R>(&mut self, build: impl FnOnce(&mut BuildContext) -> R) -> R {
+        let mut states = self.provider.states.borrow_mut();
+        let mut ctx = BuildContext::new(
+            &mut self.ecm,
+            &self.provider.render_objects,
+            &self.provider.layouts,
+            &self.provider.handler_map,
+            &mut *states,
+            &self.theme,
+            self.provider.event_adapter.clone(),
+        );
+
+        build(&mut ctx)
+    }
+
+    /// Returns a `Context` of the given widget.
+    pub fn context(&mut self, entity: Entity) -> Context<'_> {
+        Context::new(
+            (entity, &mut self.ecm),
+            &self.theme,
+            &self.provider,
+            &mut self.render_context,
+        )
+    }
+}
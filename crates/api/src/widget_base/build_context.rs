@@ -31,6 +31,11 @@ impl<'a> BuildContext<'a> {
         self.ecm.create_entity().build()
     }
 
+    /// Returns the name of the given widget followed by its id if it has one, e.g. `Switch#dark_mode`.
+    pub fn name_of(&self, entity: Entity) -> Option<String> {
+        super::name_of(&*self.ecm, entity)
+    }
+
     /// Update theme by state.
     pub fn update_theme_by_state(&mut self, entity: Entity) {
         self.get_widget(entity).update(true);
@@ -159,3 +164,23 @@ pub fn register_property<P: Component>(
 ) {
     ctx.register_property(key, entity, property);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TestContext;
+
+    #[test]
+    fn test_name_of() {
+        TestContext::new().build(|ctx| {
+            let overlay = Overlay::new().build(ctx);
+            assert_eq!(ctx.name_of(overlay), Some("Overlay".to_string()));
+
+            let overlay = Overlay::new().id("popups").build(ctx);
+            assert_eq!(ctx.name_of(overlay), Some("Overlay#popups".to_string()));
+
+            let entity = ctx.create_entity();
+            assert_eq!(ctx.name_of(entity), None);
+        });
+    }
+}
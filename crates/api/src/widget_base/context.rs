@@ -1,4 +1,7 @@
-use std::{collections::BTreeMap, sync::mpsc};
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::mpsc,
+};
 
 use dces::prelude::*;
 
@@ -8,9 +11,10 @@ use crate::{
     application::{create_window, ContextProvider},
     prelude::*,
     render::RenderContext2D,
-    shell::{ShellRequest, WindowRequest},
+    shell::{ColorScheme, ShellRequest, WindowRequest},
     theming::prelude::*,
     tree::Tree,
+    utils::Color,
 };
 
 use super::WidgetContainer;
@@ -307,6 +311,22 @@ impl<'a> Context<'a> {
         }
     }
 
+    /// Moves a child of the given parent to the given index, the other children keep their order. An index behind
+    /// the last child moves it to the end. Only the parent is marked as dirty, if the given entity is not a child
+    /// of the parent nothing will happen.
+    pub fn move_child(&mut self, parent: Entity, child: Entity, index: usize) {
+        let children = match self.ecm.entity_store().children.get_mut(&parent) {
+            Some(children) => children,
+            None => return,
+        };
+
+        if let Some(current_index) = children.iter().position(|&c| c == child) {
+            children.remove(current_index);
+            children.insert(index.min(children.len()), child);
+            mark_as_dirty_self(parent, self.ecm);
+        }
+    }
+
     /// Returns a mutable reference of the children that should be removed.
     pub fn remove_widget_list(&mut self) -> &mut Vec<Entity> {
         &mut self.remove_widget_list
@@ -404,6 +424,53 @@ impl<'a> Context<'a> {
         None
     }
 
+    /// Returns the name of the given widget followed by its id if it has one, e.g. `Switch#dark_mode`.
+    pub fn name_of(&self, entity: Entity) -> Option<String> {
+        super::name_of(&*self.ecm, entity)
+    }
+
+    /// Returns an iterator over the children of the given widget in the order they were appended.
+    pub fn children_of(&mut self, entity: Entity) -> impl Iterator<Item = Entity> + '_ {
+        self.ecm
+            .entity_store()
+            .children
+            .get(&entity)
+            .into_iter()
+            .flatten()
+            .copied()
+    }
+
+    /// Returns an iterator over all descendants of the given widget, depth first in the order of the tree.
+    pub fn descendants(&mut self, entity: Entity) -> impl Iterator<Item = Entity> + '_ {
+        let children = &self.ecm.entity_store().children;
+        let mut stack: Vec<Entity> = children
+            .get(&entity)
+            .map(|c| c.iter().rev().copied().collect())
+            .unwrap_or_default();
+
+        std::iter::from_fn(move || {
+            let entity = stack.pop()?;
+
+            if let Some(c) = children.get(&entity) {
+                stack.extend(c.iter().rev());
+            }
+
+            Some(entity)
+        })
+    }
+
+    /// Returns the first descendant of the given widget with the given name, see `name` of the widget builder.
+    pub fn find_child_by_name(&mut self, entity: Entity, name: &str) -> Option<Entity> {
+        let descendants: Vec<Entity> = self.descendants(entity).collect();
+
+        descendants.into_iter().find(|descendant| {
+            self.ecm
+                .component_store()
+                .get::<String>("name", *descendant)
+                .map_or(false, |n| n == name)
+        })
+    }
+
     /// Returns the entity of the parent.
     pub fn entity_of_parent(&mut self) -> Option<Entity> {
         self.ecm.entity_store().parent[&self.entity]
@@ -428,6 +495,8 @@ impl<'a> Context<'a> {
             self.provider.shell_sender.clone(),
             create_fn,
             self.provider.localization.clone(),
+            self.provider.event_filter.clone(),
+            self.provider.color_scheme_themes.clone(),
         );
         self.provider
             .shell_sender
@@ -459,6 +528,24 @@ impl<'a> Context<'a> {
         self.window().update_dirty(true);
     }
 
+    /// Overrides the given resource colors of the current theme and restyles all widgets, see
+    /// `Theme::override_colors`.
+    pub fn override_theme_colors(&mut self, colors: HashMap<String, Color>) {
+        let mut theme = self.theme.clone();
+        theme.override_colors(colors);
+        self.switch_theme(theme);
+    }
+
+    /// Returns the theme for the given color scheme if the application has light and dark themes, see
+    /// `Application::color_scheme_themes`.
+    pub fn color_scheme_theme(&self, color_scheme: ColorScheme) -> Option<Theme> {
+        self.provider
+            .color_scheme_themes
+            .as_ref()
+            .and_then(|themes| themes.select(color_scheme))
+            .cloned()
+    }
+
     /// Sets the current language.
     pub fn set_language(&mut self, key: &str) {
         if let Some(localization) = &self.provider.localization {
@@ -556,3 +643,96 @@ pub fn get_all_children(children: &mut Vec<Entity>, parent: Entity, tree: &Tree)
 }
 
 // -- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TestContext;
+
+    // builds a parent with the children named first, second and third, the second one has a child named grandchild
+    fn with_children(test: impl FnOnce(&mut Context, Entity, Vec<Entity>, Entity)) {
+        let mut test_context = TestContext::new();
+
+        let (parent, children, grandchild) = test_context.build(|ctx| {
+            let grandchild = Overlay::new().name("grandchild").build(ctx);
+            let children = vec![
+                Overlay::new().name("first").build(ctx),
+                Overlay::new().name("second").child(grandchild).build(ctx),
+                Overlay::new().name("third").build(ctx),
+            ];
+
+            let mut parent = Overlay::new();
+
+            for child in &children {
+                parent = parent.child(*child);
+            }
+
+            (parent.build(ctx), children, grandchild)
+        });
+
+        test(
+            &mut test_context.context(parent),
+            parent,
+            children,
+            grandchild,
+        );
+    }
+
+    #[test]
+    fn test_children_of() {
+        with_children(|ctx, parent, children, grandchild| {
+            assert_eq!(ctx.children_of(parent).collect::<Vec<_>>(), children);
+            assert_eq!(ctx.children_of(grandchild).count(), 0);
+            assert_eq!(
+                ctx.descendants(parent).collect::<Vec<_>>(),
+                vec![children[0], children[1], grandchild, children[2]]
+            );
+
+            assert_eq!(
+                ctx.find_child_by_name(parent, "grandchild"),
+                Some(grandchild)
+            );
+            assert_eq!(ctx.find_child_by_name(parent, "third"), Some(children[2]));
+            assert_eq!(ctx.find_child_by_name(children[0], "third"), None);
+        });
+    }
+
+    #[test]
+    fn test_remove_child_from() {
+        with_children(|ctx, parent, children, _| {
+            ctx.remove_child_from(children[1], parent);
+
+            assert_eq!(
+                ctx.children_of(parent).collect::<Vec<_>>(),
+                vec![children[0], children[2]]
+            );
+            assert_eq!(ctx.remove_widget_list(), &vec![children[1]]);
+        });
+    }
+
+    #[test]
+    fn test_move_child() {
+        with_children(|ctx, parent, children, grandchild| {
+            ctx.move_child(parent, children[0], 2);
+            assert_eq!(
+                ctx.children_of(parent).collect::<Vec<_>>(),
+                vec![children[1], children[2], children[0]]
+            );
+            assert!(*ctx.get_widget(parent).get::<bool>("dirty"));
+
+            // an index behind the last child moves the child to the end
+            ctx.move_child(parent, children[1], 10);
+            assert_eq!(
+                ctx.children_of(parent).collect::<Vec<_>>(),
+                vec![children[2], children[0], children[1]]
+            );
+
+            // the grandchild is not a child of the parent
+            ctx.move_child(parent, grandchild, 0);
+            assert_eq!(
+                ctx.children_of(parent).collect::<Vec<_>>(),
+                vec![children[2], children[0], children[1]]
+            );
+        });
+    }
+}
@@ -1,12 +1,13 @@
 use std::{fmt::Debug, rc::Rc};
 
-use dces::prelude::{Component, Entity};
+use dces::prelude::{Component, Entity, EntityComponentManager};
 
-use crate::{event::EventHandler, properties::AttachedProperty, theming::Selector};
+use crate::{event::EventHandler, properties::AttachedProperty, theming::Selector, tree::Tree};
 
 pub use self::build_context::*;
 pub use self::context::*;
 pub use self::message_adapter::*;
+pub use self::property_key::*;
 pub use self::registry::*;
 pub use self::state::*;
 pub use self::states_context::*;
@@ -16,6 +17,7 @@ pub use self::widget_container::*;
 mod build_context;
 mod context;
 mod message_adapter;
+mod property_key;
 mod registry;
 mod state;
 mod states_context;
@@ -69,6 +71,17 @@ pub fn remove_flag(flag: &str, widget: &mut WidgetContainer) {
     widget.update(false);
 }
 
+/// Returns the name of the widget, followed by its id if it has one, e.g. `Switch` or `Switch#dark_mode`.
+/// Returns `None` if the entity has no name. Useful to make log messages that reference entities readable.
+pub fn name_of(ecm: &EntityComponentManager<Tree>, entity: Entity) -> Option<String> {
+    let name = ecm.component_store().get::<String>("name", entity).ok()?;
+
+    match ecm.component_store().get::<String>("id", entity) {
+        Ok(id) if !id.is_empty() => Some(format!("{}#{}", name, id)),
+        _ => Some(name.clone()),
+    }
+}
+
 /// Used to define the `parent_type`of a widget.
 pub enum ParentType {
     /// No children could be added to the widget.
@@ -0,0 +1,52 @@
+use std::{fmt, marker::PhantomData};
+
+/// A `PropertyKey` is the name of a property together with the type of the property. Reading a property
+/// with a key of the wrong type is a compile error instead of a missing property at runtime.
+///
+/// # Examples
+///
+/// ```rust
+/// pub const SPACING: PropertyKey<f64> = PropertyKey::new("spacing");
+///
+/// let spacing = ctx.widget().value(SPACING);
+/// ```
+pub struct PropertyKey<P> {
+    name: &'static str,
+    property_type: PhantomData<fn() -> P>,
+}
+
+impl<P> PropertyKey<P> {
+    /// Creates a new key for the property with the given name.
+    pub const fn new(name: &'static str) -> Self {
+        PropertyKey {
+            name,
+            property_type: PhantomData,
+        }
+    }
+
+    /// Gets the name of the property.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+// implemented by hand, derive would require `P: Clone`
+impl<P> Clone for PropertyKey<P> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P> Copy for PropertyKey<P> {}
+
+impl<P> fmt::Debug for PropertyKey<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PropertyKey({})", self.name)
+    }
+}
+
+impl<P> From<PropertyKey<P>> for &'static str {
+    fn from(key: PropertyKey<P>) -> Self {
+        key.name
+    }
+}
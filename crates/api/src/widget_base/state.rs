@@ -45,6 +45,24 @@ pub trait AsAny: Any {
 /// widget!(MyWidget<MyState>)
 /// ```
 ///
+/// Deriving `State` implements `AsAny` and generates an `init_children` method that looks up the fields marked with
+/// the id of a child, an `Option<Entity>` field stays `None` if the child is missing.
+/// ```
+/// use orbtk::prelude::*;
+///
+/// #[derive(Default, State)]
+/// struct MyState {
+///     #[child("my_button")]
+///     button: Entity,
+/// }
+///
+/// impl State for MyState {
+///     fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+///         self.init_children(ctx);
+///     }
+/// }
+/// ```
+///
 /// [`widget`]: ./trait.Widget.html
 /// [`AsAny`]: ./trait.AsAny.html
 
@@ -93,3 +111,38 @@ pub trait State: AsAny {
     /// [`event`]: ../trait.Event.html
     fn update_post_layout(&mut self, _registry: &mut Registry, _ctx: &mut Context) {}
 }
+
+#[cfg(test)]
+mod tests {
+    use dces::prelude::*;
+
+    use super::*;
+    use crate::{prelude::*, proc_macros::State, test_support::TestContext};
+
+    #[derive(Default, State)]
+    struct ContentState {
+        #[child("content")]
+        content: Entity,
+
+        #[child("missing")]
+        missing: Option<Entity>,
+    }
+
+    #[test]
+    fn test_derive_state() {
+        let mut test_context = TestContext::new();
+
+        let (parent, content) = test_context.build(|ctx| {
+            let content = Overlay::new().id("content").build(ctx);
+            let parent = Overlay::new().child(content).build(ctx);
+
+            (parent, content)
+        });
+
+        let mut state = ContentState::default();
+        state.init_children(&mut test_context.context(parent));
+
+        assert_eq!(state.content, content);
+        assert_eq!(state.missing, None);
+    }
+}
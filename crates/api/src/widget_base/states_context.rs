@@ -98,27 +98,23 @@ impl<'a> StatesContext<'a> {
             })
     }
 
-    /// Try to get the state of the given widget.
+    /// Try to get the state of the given widget. Returns `None` if there is no state for the given entity or the
+    /// state is not of type `S`.
     pub fn try_get<S: Component>(&self, entity: Entity) -> Option<&S> {
-        if let Some(e) = self.states.get(&entity) {
-            if let Some(r) = e.as_any().downcast_ref() {
-                return Some(r);
-            }
-        }
-
-        None
+        self.states
+            .get(&entity)
+            .and_then(|state| state.as_any().downcast_ref())
     }
 
-    /// Try to get a mutable reference of the state of the given widget.
+    /// Try to get a mutable reference of the state of the given widget. Returns `None` if there is no state for
+    /// the given entity or the state is not of type `S`, the widget is only marked as dirty if the state is found.
     pub fn try_get_mut<S: Component>(&mut self, entity: Entity) -> Option<&mut S> {
+        self.try_get::<S>(entity)?;
         self.mark_as_dirty(entity);
-        if let Some(e) = self.states.get_mut(&entity) {
-            if let Some(r) = e.as_any_mut().downcast_mut() {
-                return Some(r);
-            }
-        }
 
-        None
+        self.states
+            .get_mut(&entity)
+            .and_then(|state| state.as_any_mut().downcast_mut())
     }
 
     /// Send a message to the given target widget.
@@ -126,3 +122,63 @@ impl<'a> StatesContext<'a> {
         self.message_adapter.send_message(message, target);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc;
+
+    use super::*;
+    use crate::widget_base::AsAny;
+
+    #[derive(Default)]
+    struct CounterState {
+        count: usize,
+    }
+
+    impl AsAny for CounterState {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    impl State for CounterState {}
+
+    #[test]
+    fn test_try_get() {
+        let mut ecm = EntityComponentManager::new(Tree::default());
+        let root = ecm.create_entity().build();
+        let entity = ecm.create_entity().build();
+
+        ecm.component_store_mut()
+            .register("dirty_widgets", root, Vec::<Entity>::new());
+        ecm.component_store_mut().register("dirty", entity, false);
+
+        let mut states: BTreeMap<Entity, Box<dyn State>> = BTreeMap::new();
+        states.insert(entity, Box::new(CounterState { count: 3 }));
+
+        let (sender, _receiver) = mpsc::channel();
+        let message_adapter = MessageAdapter::new(sender);
+        let mut ctx = StatesContext::new(&mut states, &mut ecm, &message_adapter);
+
+        assert_eq!(
+            ctx.try_get::<CounterState>(entity).map(|s| s.count),
+            Some(3)
+        );
+        assert!(ctx.try_get::<String>(entity).is_none());
+        assert!(ctx.try_get::<CounterState>(root).is_none());
+
+        // the wrong type does not mark the widget as dirty
+        assert!(ctx.try_get_mut::<String>(entity).is_none());
+        assert!(ctx.try_get_mut::<CounterState>(root).is_none());
+        assert!(!ecm.component_store().get::<bool>("dirty", entity).unwrap());
+
+        let mut ctx = StatesContext::new(&mut states, &mut ecm, &message_adapter);
+        ctx.try_get_mut::<CounterState>(entity).unwrap().count += 1;
+        assert_eq!(ctx.get::<CounterState>(entity).count, 4);
+        assert!(*ecm.component_store().get::<bool>("dirty", entity).unwrap());
+    }
+}
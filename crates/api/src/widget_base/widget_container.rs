@@ -1,9 +1,11 @@
-use std::any::type_name;
+use std::{any::type_name, time::Duration};
 
 use dces::prelude::*;
 
 use crate::{event::ChangedEvent, event::*, theming::*, tree::*, utils::prelude::*};
 
+use super::PropertyKey;
+
 /// Mark the widget and shared widgets as dirty.
 pub fn mark_as_dirty(key: &str, entity: Entity, ecm: &mut EntityComponentManager<Tree>) {
     let root = ecm.entity_store().root();
@@ -44,12 +46,51 @@ pub fn mark_as_dirty_self(entity: Entity, ecm: &mut EntityComponentManager<Tree>
     }
 }
 
+/// Returns the transform the widget is rendered under, its `render_transform` combined with the render transforms
+/// of its ancestors. Returns `None` if neither the widget nor one of its ancestors is transformed.
+pub fn global_render_transform(
+    entity: Entity,
+    ecm: &EntityComponentManager<Tree>,
+) -> Option<Transform> {
+    let mut transform = None;
+    let mut current = Some(entity);
+
+    while let Some(node) = current {
+        if let Ok(render_transform) = ecm
+            .component_store()
+            .get::<Transform>("render_transform", node)
+        {
+            if *render_transform != Transform::identity() {
+                // the transform of a widget is applied before the transforms of its ancestors
+                transform = Some(
+                    transform
+                        .map(|transform: Transform| transform.then(render_transform))
+                        .unwrap_or(*render_transform),
+                );
+            }
+        }
+
+        current = ecm.entity_store().parent.get(&node).cloned().flatten();
+    }
+
+    transform
+}
+
 /// The `WidgetContainer` wraps the entity of a widget and provides access to its properties, its children properties and its parent properties.
 pub struct WidgetContainer<'a> {
     ecm: &'a mut EntityComponentManager<Tree>,
     current_node: Entity,
     theme: &'a Theme,
     event_adapter: Option<&'a EventAdapter>,
+    batch: Option<Batch>,
+}
+
+// widgets that are marked as dirty and changed events that are pushed after the current batch update
+#[derive(Default)]
+struct Batch {
+    dirty: Vec<Entity>,
+    // the widget that receives the event, the changed widget and the key of the property
+    changed: Vec<(Entity, Entity, String)>,
 }
 
 impl<'a> WidgetContainer<'a> {
@@ -65,22 +106,100 @@ impl<'a> WidgetContainer<'a> {
             current_node: root,
             theme,
             event_adapter,
+            batch: None,
         }
     }
 
     fn mark_as_dirty(&mut self, key: &str, entity: Entity) {
+        if self.batch.is_some() {
+            for entity in self
+                .ecm
+                .component_store()
+                .entities_of_component(key, entity)
+            {
+                self.mark_as_dirty_self(entity);
+            }
+            return;
+        }
+
         mark_as_dirty(key, entity, self.ecm);
     }
 
     fn mark_as_dirty_self(&mut self, entity: Entity) {
+        if let Some(batch) = &mut self.batch {
+            if !batch.dirty.contains(&entity) {
+                batch.dirty.push(entity);
+            }
+            return;
+        }
+
         mark_as_dirty_self(entity, self.ecm);
     }
 
+    fn push_changed_event(&mut self, entity: Entity, key: String) {
+        if let Some(batch) = &mut self.batch {
+            let changed = (entity, self.current_node, key);
+
+            if !batch.changed.contains(&changed) {
+                batch.changed.push(changed);
+            }
+            return;
+        }
+
+        if let Some(event_adapter) = &self.event_adapter {
+            event_adapter.push_event_direct(entity, ChangedEvent(self.current_node, key));
+        }
+    }
+
+    /// Calls the given closure with the widget and defers marking widgets as dirty until the closure returns.
+    /// Each widget that is changed inside of the closure is marked as dirty only once, also if several of its
+    /// properties are set, and its `on_changed` handler of a property is called once, also if the property is set
+    /// several times.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// ctx.widget().update_batch(|widget| {
+    ///     widget.set("text", String16::from("On"));
+    ///     widget.set("selected", true);
+    /// });
+    /// ```
+    pub fn update_batch<F>(&mut self, update: F)
+    where
+        F: FnOnce(&mut WidgetContainer<'a>),
+    {
+        // nested batches are part of the outer batch
+        if self.batch.is_some() {
+            update(self);
+            return;
+        }
+
+        self.batch = Some(Batch::default());
+        update(self);
+
+        let batch = self.batch.take().unwrap_or_default();
+
+        for entity in batch.dirty {
+            mark_as_dirty_self(entity, self.ecm);
+        }
+
+        if let Some(event_adapter) = &self.event_adapter {
+            for (entity, source, key) in batch.changed {
+                event_adapter.push_event_direct(entity, ChangedEvent(source, key));
+            }
+        }
+    }
+
     /// Gets the entity of the widget.
     pub fn entity(&self) -> Entity {
         self.current_node
     }
 
+    /// Returns the transform the widget is rendered under, see `global_render_transform`.
+    pub fn global_render_transform(&self) -> Option<Transform> {
+        global_render_transform(self.current_node, self.ecm)
+    }
+
     /// Remove the dirty flag from the current widget.
     pub fn clear_dirty(&mut self) {
         let root = self.ecm.entity_store().root();
@@ -218,6 +337,59 @@ impl<'a> WidgetContainer<'a> {
         None
     }
 
+    /// Copies the property. Unlike `clone` it is restricted to `Copy` properties, which never allocate. If the
+    /// widget does not contain the property a warning is printed and the default of the property is returned.
+    pub fn get_copy<P>(&self, key: &str) -> P
+    where
+        P: Copy + Component + Default,
+    {
+        if let Ok(property) = self.ecm.component_store().get::<P>(key, self.current_node) {
+            return *property;
+        }
+
+        eprintln!(
+            "Widget: {} with entity: {} does not contain property with type {:?} for key: {}",
+            self.get_name(),
+            self.current_node.0,
+            type_name::<P>(),
+            key
+        );
+
+        P::default()
+    }
+
+    /// Copies the property of the given typed key, see `get_copy`.
+    pub fn value<P>(&self, key: PropertyKey<P>) -> P
+    where
+        P: Copy + Component + Default,
+    {
+        self.get_copy(key.name())
+    }
+
+    /// Gets a reference of the property of the given typed key, see `get`.
+    pub fn value_ref<P>(&self, key: PropertyKey<P>) -> &P
+    where
+        P: Clone + Component,
+    {
+        self.get(key.name())
+    }
+
+    /// Gets a mutable reference of the property of the given typed key, see `get_mut`.
+    pub fn value_mut<P>(&mut self, key: PropertyKey<P>) -> &mut P
+    where
+        P: Clone + Component,
+    {
+        self.get_mut(key.name())
+    }
+
+    /// Sets the property of the given typed key, see `set`.
+    pub fn set_value<P>(&mut self, key: PropertyKey<P>, value: P)
+    where
+        P: Component + Clone + PartialEq,
+    {
+        self.set(key.name(), value);
+    }
+
     fn toggle_enabled_state(&mut self) {
         if *self.get::<bool>("enabled") && self.get::<Selector>("selector").has_state("disabled") {
             self.get_mut::<Selector>("selector")
@@ -297,10 +469,7 @@ impl<'a> WidgetContainer<'a> {
             }
 
             if on_changed {
-                if let Some(event_adapter) = &self.event_adapter {
-                    event_adapter
-                        .push_event_direct(entity, ChangedEvent(self.current_node, target_key));
-                }
+                self.push_changed_event(entity, target_key);
             }
         }
 
@@ -363,7 +532,7 @@ impl<'a> WidgetContainer<'a> {
     /// Returns a mutable reference of a property of type `P` from the given widget entity. If the entity does
     /// not exists or it doesn't have a component of type `P` `None` will be returned.
     pub fn try_get_mut<P: Component>(&mut self, key: &str) -> Option<&mut P> {
-        mark_as_dirty(key, self.current_node, self.ecm);
+        self.mark_as_dirty(key, self.current_node);
         self.ecm
             .component_store_mut()
             .get_mut::<P>(key, self.current_node)
@@ -435,6 +604,77 @@ impl<'a> WidgetContainer<'a> {
         }
     }
 
+    // starts a transition if the style of the widget defines one for the property. The property keeps its previous
+    // value until the `TransitionSystem` animates it to the new value.
+    fn start_transition(
+        &mut self,
+        selector: &Selector,
+        key: &str,
+        from: TransitionValue,
+        to: TransitionValue,
+    ) {
+        let root = self.ecm.entity_store().root();
+
+        // widgets that are not part of the tree are still built, their initial style is not animated
+        let attached = self
+            .ecm
+            .entity_store()
+            .parent
+            .get(&self.current_node)
+            .map_or(false, |parent| parent.is_some());
+
+        // only windows run transitions
+        let has_transition_widgets = self
+            .ecm
+            .component_store()
+            .get::<Vec<Entity>>("transition_widgets", root)
+            .is_ok();
+
+        if from == to || !attached || !has_transition_widgets {
+            return;
+        }
+
+        let duration = match self.theme.transition(selector, key) {
+            Some(duration) => motion::resolve_duration(Duration::from_millis(duration)),
+            None => return,
+        };
+
+        if duration.as_millis() == 0 {
+            return;
+        }
+
+        match &from {
+            TransitionValue::Brush(brush) => self.update_value::<Brush, Brush>(key, brush.clone()),
+            TransitionValue::Number(number) => self.update_value::<f64, f64>(key, *number),
+        }
+
+        // the transitions component is registered with the first transition of the widget
+        if !self.has::<Transitions>("style_transitions") {
+            self.ecm.component_store_mut().register(
+                "style_transitions",
+                self.current_node,
+                Transitions::default(),
+            );
+        }
+
+        self.ecm
+            .component_store_mut()
+            .get_mut::<Transitions>("style_transitions", self.current_node)
+            .unwrap()
+            .push(Transition::new(key, from, to, duration.as_millis() as u64));
+
+        // the `TransitionSystem` only animates the widgets of this list
+        let transition_widgets = self
+            .ecm
+            .component_store_mut()
+            .get_mut::<Vec<Entity>>("transition_widgets", root)
+            .unwrap();
+
+        if !transition_widgets.contains(&self.current_node) {
+            transition_widgets.push(self.current_node);
+        }
+    }
+
     /// Update all properties from theme for the current widget.
     pub fn update(&mut self, force: bool) {
         self.update_widget(self.current_node, force, false);
@@ -479,17 +719,35 @@ impl<'a> WidgetContainer<'a> {
                     _ => {
                         // common mapping
                         if self.is::<Brush>(&key) {
+                            let from = self.clone::<Brush>(&key);
                             self.update_value::<Brush, Value>(&key, Value(value));
+                            let to = self.clone::<Brush>(&key);
+                            self.start_transition(
+                                &selector,
+                                &key,
+                                TransitionValue::Brush(from),
+                                TransitionValue::Brush(to),
+                            );
                         } else if self.is::<f32>(&key) {
                             self.update_value::<f32, Value>(&key, Value(value));
                         } else if self.is::<f64>(&key) {
+                            let from = self.clone::<f64>(&key);
                             self.update_value::<f64, Value>(&key, Value(value));
+                            let to = self.clone::<f64>(&key);
+                            self.start_transition(
+                                &selector,
+                                &key,
+                                TransitionValue::Number(from),
+                                TransitionValue::Number(to),
+                            );
                         } else if self.is::<Thickness>(&key) {
                             self.update_value::<Thickness, Value>(&key, Value(value));
                         } else if self.is::<String>(&key) {
                             self.update_value::<String, Value>(&key, Value(value));
                         } else if self.is::<Alignment>(&key) {
                             self.update_value::<Alignment, Value>(&key, Value(value));
+                        } else if self.is::<BoxShadow>(&key) {
+                            self.update_value::<BoxShadow, Value>(&key, Value(value));
                         }
                     }
                 }
@@ -512,7 +770,7 @@ impl<'a> WidgetContainer<'a> {
             .set_dirty(false);
 
         if should_mark_as_dirty {
-            mark_as_dirty("selector", self.current_node, self.ecm);
+            self.mark_as_dirty("selector", self.current_node);
         }
     }
 
@@ -562,3 +820,75 @@ impl<'a> From<&'a mut super::Context<'_>> for WidgetContainer<'a> {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc;
+
+    use super::*;
+
+    const ROWS: PropertyKey<usize> = PropertyKey::new("rows");
+
+    #[test]
+    fn test_get_copy() {
+        let mut ecm = EntityComponentManager::new(Tree::default());
+        let entity = ecm.create_entity().build();
+        ecm.component_store_mut().register("rows", entity, 5_usize);
+
+        let theme = Theme::default();
+        let widget = WidgetContainer::new(entity, &mut ecm, &theme, None);
+
+        assert_eq!(widget.get_copy::<usize>("rows"), 5);
+        assert_eq!(widget.value(ROWS), 5);
+
+        // a missing property falls back to its default
+        assert_eq!(widget.get_copy::<usize>("columns"), 0);
+    }
+
+    #[test]
+    fn test_update_batch() {
+        let mut ecm = EntityComponentManager::new(Tree::default());
+        let root = ecm.create_entity().build();
+        let entity = ecm.create_entity().build();
+
+        ecm.component_store_mut()
+            .register("dirty_widgets", root, Vec::<Entity>::new());
+        ecm.component_store_mut().register("dirty", entity, false);
+        ecm.component_store_mut()
+            .register("on_changed_filter", entity, Filter::Nothing);
+        ecm.component_store_mut().register("width", entity, 0.0);
+        ecm.component_store_mut().register("height", entity, 0.0);
+        ecm.component_store_mut().register("enabled", entity, true);
+
+        let theme = Theme::default();
+        let (sender, _receiver) = mpsc::channel();
+        let event_adapter = EventAdapter::new(sender);
+        let mut widget = WidgetContainer::new(entity, &mut ecm, &theme, Some(&event_adapter));
+
+        widget.update_batch(|widget| {
+            widget.set("width", 100.0);
+            widget.set("height", 50.0);
+            widget.set("width", 120.0);
+            widget.set("enabled", false);
+
+            // nothing is marked and no changed event is pushed before the batch is finished
+            assert!(!widget.get::<bool>("dirty"));
+            assert!(event_adapter.is_empty());
+        });
+
+        assert!(*widget.get::<bool>("dirty"));
+        assert_eq!(
+            ecm.component_store()
+                .get::<Vec<Entity>>("dirty_widgets", root)
+                .unwrap(),
+            &vec![entity]
+        );
+
+        // one changed event for each of the properties
+        let changed: Vec<String> = event_adapter
+            .event_reader()
+            .map(|event| event.downcast::<ChangedEvent>().unwrap().1)
+            .collect();
+        assert_eq!(changed, vec!["width", "height", "enabled"]);
+    }
+}
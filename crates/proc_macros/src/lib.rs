@@ -3,7 +3,7 @@ extern crate proc_macro;
 use proc_macro::TokenStream;
 use proc_macro2::Span;
 use quote::quote;
-use syn::{parse_macro_input, DataStruct, DeriveInput, Ident, Meta, NestedMeta};
+use syn::{parse_macro_input, DataStruct, DeriveInput, Ident, Lit, Meta, NestedMeta};
 
 #[proc_macro_derive(Pipeline)]
 pub fn derive_pipeline(input: TokenStream) -> TokenStream {
@@ -49,6 +49,77 @@ pub fn derive_as_any(input: TokenStream) -> TokenStream {
     TokenStream::from(gen)
 }
 
+// Implements `AsAny` and an inherent `init_children` that looks up the entities of the fields marked with
+// `#[child(id)]`, to be called from `State::init`. The id is a string literal or a constant, the field is an `Entity`
+// or an `Option<Entity>`.
+#[proc_macro_derive(State, attributes(child))]
+pub fn derive_state(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let ident = &input.ident;
+
+    let mut lookups = vec![];
+
+    if let syn::Data::Struct(DataStruct { ref fields, .. }) = input.data {
+        for field in fields.iter() {
+            for attr in field
+                .attrs
+                .iter()
+                .filter(|attr| attr.path.is_ident("child"))
+            {
+                let field_name = field
+                    .ident
+                    .as_ref()
+                    .unwrap_or_else(|| panic!("Expected the field to have a name"));
+
+                let id = match attr.parse_meta() {
+                    Ok(Meta::List(list)) if list.nested.len() == 1 => match &list.nested[0] {
+                        NestedMeta::Lit(Lit::Str(id)) => quote! { #id },
+                        NestedMeta::Meta(Meta::Path(id)) => quote! { #id },
+                        _ => panic!("Invalid #[child] attribute, expected #[child(\"id\")]"),
+                    },
+                    _ => panic!("Invalid #[child] attribute, expected #[child(\"id\")]"),
+                };
+
+                let lookup = if is_option(&field.ty) {
+                    quote! {
+                        self.#field_name = ctx.entity_of_child(#id);
+                    }
+                } else {
+                    quote! {
+                        self.#field_name = ctx.entity_of_child(#id).unwrap_or_else(|| {
+                            panic!("{}: Could not find child with id: {}.", stringify!(#ident), #id)
+                        });
+                    }
+                };
+
+                lookups.push(lookup);
+            }
+        }
+    }
+
+    let gen = quote! {
+        impl AsAny for #ident {
+            fn as_any(&self) -> &dyn Any {
+                self
+            }
+
+            fn as_any_mut(&mut self) -> &mut dyn Any {
+                self
+            }
+        }
+
+        impl #ident {
+            #[allow(unused_variables)]
+            fn init_children(&mut self, ctx: &mut Context) {
+                #(#lookups)*
+            }
+        }
+    };
+
+    TokenStream::from(gen)
+}
+
 #[proc_macro_derive(IntoRenderObject)]
 pub fn derive_into_render_object(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -157,6 +228,19 @@ pub fn derive_widget_ctx(input: TokenStream) -> TokenStream {
                     _ => continue,
                 };
 
+                // `#[property(Type, collection(item))]` opts in to the methods that change single items
+                let collection_item = list.nested.iter().find_map(|item| match item {
+                    NestedMeta::Meta(Meta::List(collection))
+                        if collection.path.is_ident("collection") =>
+                    {
+                        match collection.nested.first() {
+                            Some(NestedMeta::Meta(Meta::Path(path))) => path.get_ident().cloned(),
+                            _ => panic!("Invalid collection attribute, expected collection(item)"),
+                        }
+                    }
+                    _ => None,
+                });
+
                 for item in list.nested.iter() {
                     match *item {
                         NestedMeta::Meta(Meta::Path(ref path)) => {
@@ -183,7 +267,20 @@ pub fn derive_widget_ctx(input: TokenStream) -> TokenStream {
                                     Span::call_site(),
                                 );
 
+                                let key = Ident::new(
+                                    field_name_str.to_uppercase().as_str(),
+                                    Span::call_site(),
+                                );
+
+                                let key_doc = format!(
+                                    "The typed key of the `{}` property, a misspelled key is a compile error.",
+                                    field_name_str
+                                );
+
                                 let gen = quote! {
+                                    #[doc = #key_doc]
+                                    pub const #key: PropertyKey<#ty> = PropertyKey::new(#field_name_str);
+
                                     /// Gets a reference of the property value. Panics if it is the wrong widget type.
                                     #[inline(always)]
                                     pub fn #getter<'a>(widget: &'a WidgetContainer<'a>) -> &'a #ty {
@@ -213,6 +310,16 @@ pub fn derive_widget_ctx(input: TokenStream) -> TokenStream {
                                 };
 
                                 generated.push(gen);
+
+                                if let Some(item_name) = &collection_item {
+                                    generated.push(collection_methods(
+                                        ident,
+                                        &field_name,
+                                        &item_name.to_string(),
+                                        ty,
+                                        is_option(&field.ty),
+                                    ));
+                                }
                             }
 
                             break;
@@ -232,3 +339,102 @@ pub fn derive_widget_ctx(input: TokenStream) -> TokenStream {
 
     TokenStream::from(gen)
 }
+
+// checks if the field is an `Option`, the fields of the properties declared in `widget!` are optional
+fn is_option(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident == "Option")
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+// generates the `add_*`, `remove_*` and `clear_*` methods of a collection property, the type of the property has to
+// implement `CollectionProperty`.
+fn collection_methods(
+    ident: &Ident,
+    field_name: &Ident,
+    item_name: &str,
+    ty: &Ident,
+    builder: bool,
+) -> proc_macro2::TokenStream {
+    let field_name_str = field_name.to_string();
+    let method =
+        |prefix: &str, name: &str| Ident::new(&format!("{}_{}", prefix, name), Span::call_site());
+
+    let add = method(&field_name_str, "add");
+    let remove = method(&field_name_str, "remove");
+    let clear = method(&field_name_str, "clear");
+
+    let mut gen = quote! {
+        /// Adds an item to the collection property. Panics if it is the wrong widget type.
+        #[inline(always)]
+        pub fn #add(widget: &mut WidgetContainer, item: <#ty as CollectionProperty>::Item) {
+            #ident::panics_on_wrong_type(widget);
+            widget.get_mut::<#ty>(#field_name_str).add_item(item);
+        }
+
+        /// Removes an item from the collection property. Panics if it is the wrong widget type.
+        #[inline(always)]
+        pub fn #remove(widget: &mut WidgetContainer, item: &<#ty as CollectionProperty>::Item) {
+            #ident::panics_on_wrong_type(widget);
+            widget.get_mut::<#ty>(#field_name_str).remove_item(item);
+        }
+
+        /// Removes all items from the collection property. Panics if it is the wrong widget type.
+        #[inline(always)]
+        pub fn #clear(widget: &mut WidgetContainer) {
+            #ident::panics_on_wrong_type(widget);
+            widget.get_mut::<#ty>(#field_name_str).clear_items();
+        }
+    };
+
+    if builder {
+        let add_builder = method("add", item_name);
+        let remove_builder = method("remove", item_name);
+        let clear_builder = method("clear", &field_name_str);
+
+        gen.extend(quote! {
+            /// Adds an item to the collection property. If the property is shared, nothing is added.
+            pub fn #add_builder(mut self, item: <#ty as CollectionProperty>::Item) -> Self {
+                match &mut self.#field_name {
+                    Some(PropertySource::Value(value)) => value.add_item(item),
+                    None => {
+                        let mut value = #ty::default();
+                        value.add_item(item);
+                        self.#field_name = Some(PropertySource::Value(value));
+                    }
+                    _ => {}
+                }
+
+                self
+            }
+
+            /// Removes an item from the collection property. If the property is shared, nothing is removed.
+            pub fn #remove_builder(mut self, item: &<#ty as CollectionProperty>::Item) -> Self {
+                if let Some(PropertySource::Value(value)) = &mut self.#field_name {
+                    value.remove_item(item);
+                }
+
+                self
+            }
+
+            /// Removes all items from the collection property. If the property is shared, nothing is removed.
+            pub fn #clear_builder(mut self) -> Self {
+                match &mut self.#field_name {
+                    Some(PropertySource::Value(value)) => value.clear_items(),
+                    None => self.#field_name = Some(PropertySource::Value(#ty::default())),
+                    _ => {}
+                }
+
+                self
+            }
+        });
+    }
+
+    gen
+}
@@ -0,0 +1,181 @@
+//! Benchmarks of the basic drawing operations of the render backend. Run them with `cargo bench -p orbtk_render`.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+use orbtk_render::{prelude::*, utils::*};
+
+const WIDTH: f64 = 800.0;
+const HEIGHT: f64 = 600.0;
+
+static FONT: &[u8] = include_bytes!("../../theme_default/assets/fonts/Roboto-Regular.ttf");
+
+fn fill_rect(c: &mut Criterion) {
+    let mut render_context = RenderContext2D::new(WIDTH, HEIGHT);
+    render_context.set_fill_style(Color::rgb(51, 153, 255));
+
+    c.bench_function("fill_rect", |b| {
+        b.iter(|| {
+            render_context.fill_rect(black_box(10.0), black_box(10.0), 200.0, 100.0);
+        })
+    });
+}
+
+fn fill_path(c: &mut Criterion) {
+    let mut render_context = RenderContext2D::new(WIDTH, HEIGHT);
+    render_context.set_fill_style(Color::rgb(51, 153, 255));
+
+    c.bench_function("fill_path", |b| {
+        b.iter(|| {
+            render_context.begin_path();
+            render_context.move_to(black_box(20.0), 300.0);
+            render_context.bezier_curve_to(120.0, 100.0, 280.0, 500.0, 380.0, 300.0);
+            render_context.line_to(380.0, 550.0);
+            render_context.arc(200.0, 550.0, 180.0, 0.0, std::f64::consts::PI);
+            render_context.close_path();
+            render_context.fill();
+        })
+    });
+}
+
+// builds a long path like the line of a chart. The path is not drawn, so only the cost of `line_to` is measured,
+// the time per point should be the same for 1k and 10k points
+fn build_path(c: &mut Criterion) {
+    let mut render_context = RenderContext2D::new(WIDTH, HEIGHT);
+    let mut group = c.benchmark_group("line_to");
+
+    for &points in &[1_000, 10_000] {
+        group.throughput(Throughput::Elements(points as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(points),
+            &points,
+            |b, &points| {
+                b.iter(|| {
+                    render_context.begin_path();
+                    render_context.move_to(0.0, HEIGHT / 2.0);
+
+                    for i in 0..points {
+                        let x = i as f64 * WIDTH / points as f64;
+                        render_context
+                            .line_to(black_box(x), HEIGHT / 2.0 + (x / 20.0).sin() * 100.0);
+                    }
+                })
+            },
+        );
+    }
+
+    group.finish();
+
+    // drawing the path with 10k points
+    render_context.set_stroke_style(Color::rgb(51, 153, 255));
+    c.bench_function("stroke_line_to_10000", |b| {
+        b.iter(|| {
+            render_context.begin_path();
+            render_context.move_to(0.0, HEIGHT / 2.0);
+
+            for i in 0..10_000 {
+                let x = i as f64 * WIDTH / 10_000.0;
+                render_context.line_to(black_box(x), HEIGHT / 2.0 + (x / 20.0).sin() * 100.0);
+            }
+
+            render_context.stroke();
+        })
+    });
+}
+
+fn fill_text(c: &mut Criterion) {
+    let mut render_context = RenderContext2D::new(WIDTH, HEIGHT);
+    render_context.register_font("Roboto-Regular", FONT);
+    render_context.set_font_family("Roboto-Regular");
+    render_context.set_font_size(16.0);
+    render_context.set_fill_style(Color::rgb(0, 0, 0));
+
+    c.bench_function("fill_text", |b| {
+        b.iter(|| {
+            render_context.fill_text(
+                black_box("The quick brown fox jumps over the lazy dog"),
+                10.0,
+                10.0,
+            );
+        })
+    });
+
+    // repaints of the same label reuse the rasterized glyphs
+    c.bench_function("fill_text_1000", |b| {
+        b.iter(|| {
+            for _ in 0..1000 {
+                render_context.fill_text(black_box("Sign in"), 10.0, 10.0);
+            }
+        })
+    });
+}
+
+// a fixed corpus of labels like they are measured on each layout of a form
+const CORPUS: &[&str] = &[
+    "Name",
+    "E-Mail",
+    "Password",
+    "Remember me",
+    "Sign in",
+    "Forgot your password?",
+    "The quick brown fox jumps over the lazy dog",
+    "0123456789",
+];
+
+fn measure_text(c: &mut Criterion) {
+    let mut render_context = RenderContext2D::new(WIDTH, HEIGHT);
+    render_context.register_font("Roboto-Regular", FONT);
+    render_context.set_font_family("Roboto-Regular");
+    render_context.set_font_size(16.0);
+
+    c.bench_function("measure_text", |b| {
+        b.iter(|| {
+            for text in CORPUS {
+                black_box(render_context.measure_text(text));
+            }
+        })
+    });
+
+    // each iteration uses a new font size, so every text misses the cache
+    let mut font_size = 16.0;
+    c.bench_function("measure_text_uncached", |b| {
+        b.iter(|| {
+            font_size += 0.001;
+            render_context.set_font_size(font_size);
+
+            for text in CORPUS {
+                black_box(render_context.measure_text(text));
+            }
+        })
+    });
+}
+
+fn fill_gradient(c: &mut Criterion) {
+    let mut render_context = RenderContext2D::new(WIDTH, HEIGHT);
+
+    let linear = Brush::from("linear-gradient(45deg, #3399ff, #ff3399 60%, #ffff33)");
+    c.bench_function("fill_linear_gradient", |b| {
+        b.iter(|| {
+            render_context.set_fill_style(linear.clone());
+            render_context.fill_rect(black_box(10.0), 10.0, 400.0, 300.0);
+        })
+    });
+
+    let radial = Brush::from("radial-gradient(#3399ff, #ff3399)");
+    c.bench_function("fill_radial_gradient", |b| {
+        b.iter(|| {
+            render_context.set_fill_style(radial.clone());
+            render_context.fill_rect(black_box(10.0), 10.0, 400.0, 300.0);
+        })
+    });
+}
+
+criterion_group!(
+    benches,
+    fill_rect,
+    fill_path,
+    build_path,
+    fill_text,
+    measure_text,
+    fill_gradient
+);
+criterion_main!(benches);
@@ -1,5 +1,8 @@
-use crate::utils::*;
-use std::f64::consts::{FRAC_PI_2, PI};
+use crate::{utils::*, TextMetrics};
+use std::{
+    collections::HashMap,
+    f64::consts::{FRAC_PI_2, PI},
+};
 
 pub const TAU: f64 = 6.283_185_307_179_586_f64;
 
@@ -100,6 +103,59 @@ pub fn cubic_bezier_rect(p0: Point, p1: Point, p2: Point, p3: Point) -> Rectangl
     Rectangle::new(mi, Size::new(ma.x() - mi.x(), ma.y() - mi.y()))
 }
 
+/// Approximates the elliptical arc with cubic bezier curves. Returns the start point of the arc and the control points
+/// and end point of each curve. The arc is drawn clockwise from `start_angle` to `end_angle` like the canvas `ellipse`.
+pub fn ellipse_curves(
+    x: f64,
+    y: f64,
+    radius_x: f64,
+    radius_y: f64,
+    rotation: f64,
+    start_angle: f64,
+    end_angle: f64,
+) -> (Point, Vec<(Point, Point, Point)>) {
+    let mut sweep = end_angle - start_angle;
+    if sweep >= TAU {
+        sweep = TAU;
+    } else {
+        sweep = sweep.rem_euclid(TAU);
+    }
+
+    let (rotation_sin, rotation_cos) = rotation.sin_cos();
+
+    // maps a point of the unit circle to the rotated ellipse
+    let map = |unit_x: f64, unit_y: f64| {
+        let ellipse_x = unit_x * radius_x;
+        let ellipse_y = unit_y * radius_y;
+        Point::new(
+            x + ellipse_x * rotation_cos - ellipse_y * rotation_sin,
+            y + ellipse_x * rotation_sin + ellipse_y * rotation_cos,
+        )
+    };
+
+    // every curve covers at most a quarter of the ellipse
+    let count = (sweep / FRAC_PI_2).ceil().max(1.0) as usize;
+    let step = sweep / count as f64;
+    let kappa = 4.0 / 3.0 * (step / 4.0).tan();
+
+    let mut curves = Vec::with_capacity(count);
+    let (mut sin, mut cos) = start_angle.sin_cos();
+    let start = map(cos, sin);
+
+    for i in 1..=count {
+        let (next_sin, next_cos) = (start_angle + step * i as f64).sin_cos();
+        curves.push((
+            map(cos - kappa * sin, sin + kappa * cos),
+            map(next_cos + kappa * next_sin, next_sin - kappa * next_cos),
+            map(next_cos, next_sin),
+        ));
+        sin = next_sin;
+        cos = next_cos;
+    }
+
+    (start, curves)
+}
+
 /// A object used to keep a record of the AABB of a path.
 #[derive(Debug, Copy, Clone)]
 pub struct PathRect {
@@ -159,6 +215,19 @@ impl PathRect {
         }
     }
 
+    /// Records the drawing of a elliptical arc.
+    pub fn record_ellipse(&mut self, start: Point, curves: &[(Point, Point, Point)]) {
+        if self.path_rect.is_none() {
+            self.record_move_to(start.x(), start.y());
+        } else {
+            self.record_line_to(start.x(), start.y());
+        }
+
+        for (cp1, cp2, end) in curves {
+            self.record_bezier_curve_to(cp1.x(), cp1.y(), cp2.x(), cp2.y(), end.x(), end.y());
+        }
+    }
+
     /// Records the movement of the path drawing brush to a new location,
     /// the unique difference with `record_line_to` is that if the path has
     /// not started, It does not assume the drawing of a line from (0.0, 0.0).
@@ -343,9 +412,276 @@ pub fn linear_gradient_ends_from_angle(angle: Angle, size: Size) -> Point {
     z
 }
 
+/// Returns the start and the end of a linear gradient drawn on the given frame. The displacement of the gradient
+/// moves both ends by the same vector.
+pub fn linear_gradient_ends(coords: &LinearGradientCoords, frame: Rectangle) -> (Point, Point) {
+    let (start, end) = match coords {
+        LinearGradientCoords::Ends { start, end, .. } => (*start, *end),
+        LinearGradientCoords::Angle { angle, .. } => {
+            let z = linear_gradient_ends_from_angle(*angle, frame.size());
+            let center = Point::from(frame.size() / 2.0);
+            (center + -z, center + z)
+        }
+        LinearGradientCoords::Direction { direction, .. } => {
+            direction.cross(frame.width(), frame.height())
+        }
+    };
+    let displacement = frame.position() + coords.displacement().pixels(frame.size());
+
+    (start + displacement, end + displacement)
+}
+
+/// Returns the center and the radius of a radial gradient drawn on the given frame. The center is `pos` relative to
+/// the frame or the middle of the frame if `pos` is `None`, the radius reaches the farthest corner of the frame.
+pub fn radial_gradient_circle(pos: Option<OnPlanePos>, frame: Rectangle) -> (Point, f64) {
+    let center = match pos {
+        Some(pos) => pos.pixels(frame.size()),
+        None => Point::from(frame.size() / 2.0),
+    };
+
+    let radius = [
+        (0.0, 0.0),
+        (frame.width(), 0.0),
+        (0.0, frame.height()),
+        (frame.width(), frame.height()),
+    ]
+    .iter()
+    .map(|(x, y)| (x - center.x()).hypot(y - center.y()))
+    .fold(0.0, f64::max);
+
+    (frame.position() + center, radius)
+}
+
+/// Breaks the given text into lines that fits into `max_width`. Lines are greedily broken on whitespace and on
+/// line breaks of the text. Words that are wider than `max_width` are broken between its characters.
+pub fn wrap_text(text: &str, max_width: f64, mut measure: impl FnMut(&str) -> f64) -> Vec<String> {
+    let mut lines = vec![];
+
+    for paragraph in text.lines() {
+        let mut line = String::new();
+
+        for word in paragraph.split_whitespace() {
+            let candidate = if line.is_empty() {
+                word.to_string()
+            } else {
+                format!("{} {}", line, word)
+            };
+
+            if measure(&candidate) <= max_width {
+                line = candidate;
+                continue;
+            }
+
+            if !line.is_empty() {
+                lines.push(line);
+            }
+
+            line = String::new();
+
+            // hard breaks words that doesn't fit in a line
+            for c in word.chars() {
+                line.push(c);
+
+                if line.chars().count() > 1 && measure(&line) > max_width {
+                    line.pop();
+                    lines.push(line);
+                    line = c.to_string();
+                }
+            }
+        }
+
+        lines.push(line);
+    }
+
+    lines
+}
+
+/// Blurs the given region of a premultiplied ARGB pixel buffer in place. The gaussian blur with
+/// the standard deviation `radius` is approximated by three passes of a box blur in each direction.
+/// The cost grows linear with the size of the region and is independent of the radius.
+pub fn blur_pixels(
+    data: &mut [u32],
+    stride: usize,
+    region: (usize, usize, usize, usize),
+    radius: f64,
+) {
+    let (x, y, width, height) = region;
+
+    if radius <= 0.0 || width == 0 || height == 0 || stride == 0 {
+        return;
+    }
+
+    let rows = data.len() / stride;
+    let width = width.min(stride.saturating_sub(x));
+    let height = height.min(rows.saturating_sub(y));
+
+    if width == 0 || height == 0 {
+        return;
+    }
+
+    // three box blurs with the half size r have a variance of r * (r + 1)
+    let box_radius = ((((4.0 * radius * radius + 1.0).sqrt() - 1.0) / 2.0).round() as usize).max(1);
+    let mut line = Vec::with_capacity(width.max(height));
+
+    for _ in 0..3 {
+        for row in y..y + height {
+            let start = row * stride + x;
+            line.clear();
+            line.extend_from_slice(&data[start..start + width]);
+            box_blur_line(&line, box_radius, |i, pixel| data[start + i] = pixel);
+        }
+
+        for column in x..x + width {
+            line.clear();
+            line.extend((y..y + height).map(|row| data[row * stride + column]));
+            box_blur_line(&line, box_radius, |i, pixel| {
+                data[(y + i) * stride + column] = pixel
+            });
+        }
+    }
+}
+
+// Calculates the running average of the pixel line with the given half box size. Pixels outside
+// of the line are clamped to the edge pixels.
+fn box_blur_line(line: &[u32], radius: usize, mut set: impl FnMut(usize, u32)) {
+    let len = line.len() as isize;
+    let radius = radius as isize;
+    let size = (2 * radius + 1) as u32;
+    let pixel = |i: isize| line[i.max(0).min(len - 1) as usize];
+    let mut sums = [0_u32; 4];
+
+    for i in -radius..=radius {
+        add_channels(&mut sums, pixel(i), true);
+    }
+
+    for i in 0..len {
+        set(
+            i as usize,
+            sums.iter()
+                .enumerate()
+                .fold(0, |p, (c, sum)| p | (((sum + size / 2) / size) << (c * 8))),
+        );
+        add_channels(&mut sums, pixel(i + radius + 1), true);
+        add_channels(&mut sums, pixel(i - radius), false);
+    }
+}
+
+fn add_channels(sums: &mut [u32; 4], pixel: u32, add: bool) {
+    for (c, sum) in sums.iter_mut().enumerate() {
+        let value = (pixel >> (c * 8)) & 0xff;
+
+        if add {
+            *sum += value;
+        } else {
+            *sum -= value;
+        }
+    }
+}
+
+/// Converts a premultiplied ARGB pixel to the unpremultiplied RGBA bytes `[r, g, b, a]`.
+pub fn argb_to_rgba(pixel: u32) -> [u8; 4] {
+    let a = pixel >> 24;
+    let channel = |shift: u32| {
+        let value = (pixel >> shift) & 0xff;
+
+        if a == 0 {
+            return 0;
+        }
+
+        ((value * 255 + a / 2) / a).min(255) as u8
+    };
+
+    [channel(16), channel(8), channel(0), a as u8]
+}
+
+/// Converts the unpremultiplied RGBA bytes `[r, g, b, a]` to a premultiplied ARGB pixel.
+pub fn rgba_to_argb(rgba: &[u8]) -> u32 {
+    let a = rgba[3] as u32;
+    let channel = |value: u8| (value as u32 * a + 127) / 255;
+
+    a << 24 | channel(rgba[0]) << 16 | channel(rgba[1]) << 8 | channel(rgba[2])
+}
+
+// font family, font size (as bits) and text
+type TextMetricsKey = (String, u64, String);
+
+/// Caches measured text metrics, so that texts which are drawn each frame are not measured again. If the cache
+/// is full, the least recently used entry is replaced.
+#[derive(Debug, Clone)]
+pub struct TextMetricsCache {
+    capacity: usize,
+    entries: HashMap<TextMetricsKey, (TextMetrics, u64)>,
+    // increased on each access, marks when an entry was used the last time
+    clock: u64,
+}
+
+impl TextMetricsCache {
+    /// Creates a new cache that holds at most `capacity` entries.
+    pub fn new(capacity: usize) -> Self {
+        TextMetricsCache {
+            capacity,
+            entries: HashMap::new(),
+            clock: 0,
+        }
+    }
+
+    /// Returns the cached metrics of the given text.
+    pub fn get(&mut self, family: &str, font_size: f64, text: &str) -> Option<TextMetrics> {
+        self.clock += 1;
+        let clock = self.clock;
+
+        self.entries
+            .get_mut(&(family.to_string(), font_size.to_bits(), text.to_string()))
+            .map(|(metrics, last_used)| {
+                *last_used = clock;
+                *metrics
+            })
+    }
+
+    /// Stores the metrics of the given text.
+    pub fn insert(&mut self, family: &str, font_size: f64, text: &str, metrics: TextMetrics) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let key = (family.to_string(), font_size.to_bits(), text.to_string());
+
+        if self.entries.len() >= self.capacity && !self.entries.contains_key(&key) {
+            let least_recently_used = self
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, last_used))| *last_used)
+                .map(|(key, _)| key.clone());
+
+            if let Some(key) = least_recently_used {
+                self.entries.remove(&key);
+            }
+        }
+
+        self.clock += 1;
+        self.entries.insert(key, (metrics, self.clock));
+    }
+
+    /// Returns the number of cached entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Removes all entries, e.g. after a font is registered.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::PathRect;
+    use super::{linear_gradient_ends, radial_gradient_circle, PathRect};
+    use crate::{utils::*, TextMetrics};
 
     #[test]
     fn test_pathrect_lines() {
@@ -422,4 +758,208 @@ mod tests {
         assert!(98.0 - urect.width().abs() < f64::EPSILON);
         assert!(185.57550765359252 - urect.height().abs() < f64::EPSILON);
     }
+
+    fn variance(data: &[u32]) -> f64 {
+        let values: Vec<f64> = data.iter().map(|p| (p & 0xff) as f64).collect();
+        let mean = values.iter().sum::<f64>() / values.len() as f64;
+        values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / values.len() as f64
+    }
+
+    #[test]
+    fn test_blur_pixels() {
+        let size = 16;
+        let pattern: Vec<u32> = (0..size * size)
+            .map(|i| {
+                if (i % size + i / size) % 2 == 0 {
+                    0xff_ff_ff_ff
+                } else {
+                    0xff_00_00_00
+                }
+            })
+            .collect();
+
+        let mut data = pattern.clone();
+        super::blur_pixels(&mut data, size, (0, 0, size, size), 0.0);
+        assert_eq!(data, pattern);
+
+        super::blur_pixels(&mut data, size, (0, 0, size, size), 2.0);
+        assert!(variance(&data) < variance(&pattern) / 10.0);
+        assert!(data.iter().all(|p| p >> 24 == 0xff));
+
+        // pixels outside of the region stays untouched
+        let mut data = pattern.clone();
+        super::blur_pixels(&mut data, size, (4, 4, 8, 8), 2.0);
+        assert_eq!(data[..4 * size], pattern[..4 * size]);
+        assert!(
+            variance(&data[5 * size + 5..5 * size + 11])
+                < variance(&pattern[5 * size + 5..5 * size + 11])
+        );
+    }
+
+    #[test]
+    fn test_ellipse_curves() {
+        let (start, curves) = super::ellipse_curves(50.0, 40.0, 30.0, 10.0, 0.0, 0.0, super::TAU);
+        assert!((start.x() - 80.0).abs() < 1e-9 && (start.y() - 40.0).abs() < 1e-9);
+        assert_eq!(curves.len(), 4);
+
+        let mut rect = PathRect::new(None);
+        rect.record_ellipse(start, &curves);
+        let urect = rect.get_rect().unwrap();
+        assert!((urect.x() - 20.0).abs() < 1e-6);
+        assert!((urect.y() - 30.0).abs() < 1e-6);
+        assert!((urect.width() - 60.0).abs() < 1e-6);
+        assert!((urect.height() - 20.0).abs() < 1e-6);
+
+        // rotated by 90° the radii are swapped
+        let (start, curves) = super::ellipse_curves(
+            50.0,
+            40.0,
+            30.0,
+            10.0,
+            std::f64::consts::FRAC_PI_2,
+            0.0,
+            super::TAU,
+        );
+        let mut rect = PathRect::new(None);
+        rect.record_ellipse(start, &curves);
+        let urect = rect.get_rect().unwrap();
+        assert!((urect.width() - 20.0).abs() < 1e-6);
+        assert!((urect.height() - 60.0).abs() < 1e-6);
+
+        // half ellipse
+        let (_, curves) =
+            super::ellipse_curves(0.0, 0.0, 10.0, 10.0, 0.0, 0.0, std::f64::consts::PI);
+        assert_eq!(curves.len(), 2);
+        let end = curves[1].2;
+        assert!((end.x() + 10.0).abs() < 1e-9 && end.y().abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_wrap_text() {
+        // every character is 10 pixels wide
+        let measure = |text: &str| text.chars().count() as f64 * 10.0;
+
+        assert_eq!(
+            super::wrap_text("Hello OrbTk world", 120.0, measure),
+            vec!["Hello OrbTk", "world"]
+        );
+        assert_eq!(
+            super::wrap_text("Hello\n\nworld", 120.0, measure),
+            vec!["Hello", "", "world"]
+        );
+        assert_eq!(
+            super::wrap_text("a Supercalifragilistic b", 60.0, measure),
+            vec!["a", "Superc", "alifra", "gilist", "ic b"]
+        );
+        assert_eq!(super::wrap_text("", 60.0, measure), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_rgba_conversion() {
+        assert_eq!(super::argb_to_rgba(0xff_12_34_56), [0x12, 0x34, 0x56, 0xff]);
+        assert_eq!(
+            super::rgba_to_argb(&[0x12, 0x34, 0x56, 0xff]),
+            0xff_12_34_56
+        );
+
+        // half transparent red is stored premultiplied
+        assert_eq!(super::rgba_to_argb(&[255, 0, 0, 128]), 0x80_80_00_00);
+        assert_eq!(super::argb_to_rgba(0x80_80_00_00), [255, 0, 0, 128]);
+
+        assert_eq!(super::argb_to_rgba(0), [0, 0, 0, 0]);
+        assert_eq!(super::rgba_to_argb(&[255, 255, 255, 0]), 0);
+    }
+
+    #[test]
+    fn test_text_metrics_cache() {
+        let metrics = |width| TextMetrics {
+            width,
+            height: 10.0,
+        };
+
+        let mut cache = super::TextMetricsCache::new(2);
+        assert!(cache.get("Roboto", 12.0, "Hello").is_none());
+
+        cache.insert("Roboto", 12.0, "Hello", metrics(30.0));
+        cache.insert("Roboto", 14.0, "Hello", metrics(35.0));
+        assert_eq!(
+            cache.get("Roboto", 12.0, "Hello").map(|m| m.width),
+            Some(30.0)
+        );
+        assert!(cache.get("Roboto", 12.0, "World").is_none());
+        assert!(cache.get("Roboto-Medium", 12.0, "Hello").is_none());
+
+        // the least recently used entry is replaced
+        cache.insert("Roboto", 12.0, "World", metrics(32.0));
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get("Roboto", 14.0, "Hello").is_none());
+        assert!(cache.get("Roboto", 12.0, "Hello").is_some());
+        assert!(cache.get("Roboto", 12.0, "World").is_some());
+
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_radial_gradient_circle() {
+        let frame = Rectangle::new((10.0, 20.0), (100.0, 50.0));
+
+        let (center, radius) = radial_gradient_circle(None, frame);
+        assert_eq!(center, Point::new(60.0, 45.0));
+        assert!((radius - 100.0_f64.hypot(50.0) / 2.0).abs() < f64::EPSILON);
+
+        // the center moves to the top left, the radius reaches the bottom right corner
+        let pos = OnPlanePos::new(
+            OnLinePos::new(25.0, OnLinePosKind::Percentage),
+            OnLinePos::new(10.0, OnLinePosKind::Pixels),
+        );
+        let (center, radius) = radial_gradient_circle(Some(pos), frame);
+        assert_eq!(center, Point::new(35.0, 30.0));
+        assert!((radius - 75.0_f64.hypot(40.0)).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_linear_gradient_ends() {
+        let frame = Rectangle::new((10.0, 20.0), (100.0, 50.0));
+        let all_coords = [
+            LinearGradientCoords::from_ends((0.0, 0.0), (100.0, 50.0)),
+            LinearGradientCoords::from_angle(Angle::from_degrees(45.0)),
+            LinearGradientCoords::default(),
+        ];
+
+        for coords in all_coords.iter() {
+            let (start, end) = linear_gradient_ends(coords, frame);
+
+            // every displacement translates both ends by the same vector
+            for (x, y) in [(0.0, 0.0), (5.0, -5.0), (-20.0, 30.0), (100.0, 50.0)].iter() {
+                let displacement = OnPlanePos::new(
+                    OnLinePos::new(*x, OnLinePosKind::Pixels),
+                    OnLinePos::new(*y, OnLinePosKind::Pixels),
+                );
+                let mut moved = *coords;
+                let (moved_start, moved_end) =
+                    linear_gradient_ends(&moved.with_displacement(displacement), frame);
+
+                assert!(((moved_start - start).x() - x).abs() < 1e-9);
+                assert!(((moved_start - start).y() - y).abs() < 1e-9);
+                assert!(((moved_end - end).x() - x).abs() < 1e-9);
+                assert!(((moved_end - end).y() - y).abs() < 1e-9);
+            }
+        }
+
+        // the ends are relative to the frame
+        let (start, end) = linear_gradient_ends(&all_coords[0], frame);
+        assert_eq!(start, Point::new(10.0, 20.0));
+        assert_eq!(end, Point::new(110.0, 70.0));
+
+        // percentages are relative to the size of the frame
+        let displacement = OnPlanePos::new(
+            OnLinePos::new(10.0, OnLinePosKind::Percentage),
+            OnLinePos::new(50.0, OnLinePosKind::Percentage),
+        );
+        let mut coords = all_coords[0];
+        let (start, end) = linear_gradient_ends(&coords.with_displacement(displacement), frame);
+        assert_eq!(start, Point::new(20.0, 45.0));
+        assert_eq!(end, Point::new(120.0, 95.0));
+    }
 }
@@ -36,8 +36,16 @@ pub struct RenderConfig {
     pub fill_style: utils::Brush,
     pub stroke_style: utils::Brush,
     pub line_width: f64,
+    pub line_cap: utils::LineCap,
+    pub line_join: utils::LineJoin,
+    pub line_dash: Vec<f64>,
+    pub line_dash_offset: f64,
     pub font_config: FontConfig,
+    pub text_align: utils::TextAlignment,
+    pub text_baseline: utils::TextBaseline,
     pub alpha: f32,
+    pub shadow: utils::BoxShadow,
+    pub composite_operation: utils::CompositeOperation,
 }
 
 impl Default for RenderConfig {
@@ -46,12 +54,37 @@ impl Default for RenderConfig {
             fill_style: utils::Brush::default(),
             stroke_style: utils::Brush::default(),
             line_width: 1.,
+            line_cap: utils::LineCap::default(),
+            line_join: utils::LineJoin::default(),
+            line_dash: vec![],
+            line_dash_offset: 0.,
             font_config: FontConfig::default(),
+            text_align: utils::TextAlignment::Start,
+            text_baseline: utils::TextBaseline::Top,
             alpha: 1.,
+            shadow: utils::BoxShadow::default(),
+            composite_operation: utils::CompositeOperation::default(),
         }
     }
 }
 
+/// Counts the draw operations of a frame. The counters are reset on the start of each frame.
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+pub struct RenderStats {
+    pub fills: usize,
+    pub strokes: usize,
+    pub fill_rects: usize,
+    pub images: usize,
+    pub texts: usize,
+}
+
+impl RenderStats {
+    /// Returns the sum of all counted draw operations.
+    pub fn draw_calls(&self) -> usize {
+        self.fills + self.strokes + self.fill_rects + self.images + self.texts
+    }
+}
+
 /// The TextMetrics struct represents the dimension of a text.
 #[derive(Clone, Copy, Default, Debug)]
 pub struct TextMetrics {
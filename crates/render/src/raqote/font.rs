@@ -1,23 +1,124 @@
+use std::{cell::RefCell, collections::HashMap};
+
 use crate::utils::{Color, Rectangle};
 
+// the number of glyph rasters a font keeps, if it is exceeded the cache is cleared
+const GLYPH_CACHE_SIZE: usize = 1024;
+
+// the rasterized coverage of a glyph, positioned relative to the origin of the glyph
+#[derive(Debug, Clone)]
+struct GlyphRaster {
+    min_x: i32,
+    min_y: i32,
+    width: i32,
+    coverage: Vec<f32>,
+}
+
+impl GlyphRaster {
+    // rasterizes the glyph at the horizontal origin, returns `None` if the glyph has no outline (e.g. a space).
+    // The vertical position only depends on the ascent of the font, so its fraction is kept.
+    fn new(glyph: &rusttype::PositionedGlyph) -> Option<Self> {
+        let glyph = glyph
+            .unpositioned()
+            .clone()
+            .positioned(rusttype::point(0.0, glyph.position().y.fract()));
+        let bb = glyph.pixel_bounding_box()?;
+
+        let width = bb.width();
+        let mut coverage = vec![0.0; (width * bb.height()) as usize];
+
+        glyph.draw(|x, y, v| {
+            coverage[(y as i32 * width + x as i32) as usize] = v;
+        });
+
+        Some(GlyphRaster {
+            min_x: bb.min.x,
+            min_y: bb.min.y,
+            width,
+            coverage,
+        })
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Font {
     inner: rusttype::Font<'static>,
+    // rasterized glyphs by glyph id and font size, reused by each call of render_text
+    glyph_cache: RefCell<HashMap<(u16, u64), Option<GlyphRaster>>>,
 }
 
 impl Font {
     pub fn from_bytes(bytes: &'static [u8]) -> Result<Self, &'static str> {
         rusttype::Font::try_from_bytes(bytes)
-            .map(|font| Font { inner: font })
+            .map(|font| Font {
+                inner: font,
+                glyph_cache: RefCell::new(HashMap::new()),
+            })
             .ok_or("Could not load font from bytes")
     }
 
+    /// Removes all rasterized glyphs from the cache of the font.
+    pub fn clear_glyph_cache(&self) {
+        self.glyph_cache.borrow_mut().clear();
+    }
+
+    // rasterizes the glyph if it is not already cached
+    fn cache_glyph(&self, glyph: &rusttype::PositionedGlyph, size: f64) -> (u16, u64) {
+        let key = (glyph.id().0, size.to_bits());
+
+        if self.glyph_cache.borrow().contains_key(&key) {
+            return key;
+        }
+
+        let mut glyph_cache = self.glyph_cache.borrow_mut();
+
+        if glyph_cache.len() >= GLYPH_CACHE_SIZE {
+            glyph_cache.clear();
+        }
+
+        glyph_cache.insert(key, GlyphRaster::new(glyph));
+
+        key
+    }
+
+    // The font size describes the size of the em square, but the rusttype scale describes the height from
+    // the lowest descent to the highest ascent of the font. Converts the font size to the matching scale.
+    fn scale(&self, size: f64) -> rusttype::Scale {
+        let units_per_em = self.inner.units_per_em() as f32;
+
+        if units_per_em <= 0.0 {
+            return rusttype::Scale::uniform(size as f32);
+        }
+
+        let v_metrics = self.inner.v_metrics_unscaled();
+        rusttype::Scale::uniform(
+            size as f32 * (v_metrics.ascent - v_metrics.descent) / units_per_em,
+        )
+    }
+
+    /// Returns the distance between the top of the text and the alphabetic baseline.
+    pub fn ascent(&self, size: f64) -> f64 {
+        self.inner.v_metrics(self.scale(size)).ascent as f64
+    }
+
+    /// Returns the height of a line of text from the highest ascent to the lowest descent.
+    pub fn height(&self, size: f64) -> f64 {
+        let v_metrics = self.inner.v_metrics(self.scale(size));
+        (v_metrics.ascent - v_metrics.descent).ceil() as f64
+    }
+
+    /// Returns the distance between the baselines of two lines for the given size.
+    pub fn line_height(&self, size: f64) -> f64 {
+        let v_metrics = self.inner.v_metrics(self.scale(size));
+        (v_metrics.ascent - v_metrics.descent + v_metrics.line_gap) as f64
+    }
+
     pub fn measure_text(&self, text: &str, size: f64) -> (f64, f64) {
-        let scale = rusttype::Scale::uniform(size as f32);
+        let scale = self.scale(size);
         let v_metrics = self.inner.v_metrics(scale);
         let offset = rusttype::point(0.0, v_metrics.ascent);
 
-        let pixel_height = size.ceil();
+        let pixel_height = self.height(size);
 
         // Glyphs to draw for "RustType". Feel free to try other strings.
         let glyphs: Vec<rusttype::PositionedGlyph> =
@@ -67,7 +168,7 @@ impl Font {
         position: (f64, f64),
         clip: Rectangle,
     ) {
-        let scale = rusttype::Scale::uniform(config.0 as f32);
+        let scale = self.scale(config.0);
 
         // The origin of a line of text is at the baseline (roughly where non-descending letters sit).
         // We don't want to clip the text, so we shift it down with an offset when laying it out.
@@ -88,55 +189,106 @@ impl Font {
             .unwrap_or(0.0)
             .ceil() as i32;
 
-        let pixel_height = config.0.ceil() as i32;
+        let pixel_height = self.height(config.0) as i32;
+
+        let color = config.1.data & 0x00FF_FFFF;
 
         for g in glyphs.iter() {
-            if let Some(bb) = g.pixel_bounding_box() {
-                g.draw(|off_x, off_y, v| {
-                    let off_x = off_x as i32 + bb.min.x;
-                    let off_y = off_y as i32 + bb.min.y;
-
-                    if off_x >= 0
-                        && off_x < pixel_width
-                        && off_y >= 0
-                        && off_y < pixel_height
-                        && position.0 + off_x as f64 >= clip.x()
-                        && position.0 + off_x as f64 <= clip.x() + clip.width()
-                        && position.1 + off_y as f64 >= clip.y()
-                        && position.1 + off_y as f64 <= clip.y() + clip.height()
-                        && position.0 + (off_x as f64) < width
-                        && position.1 + (off_y as f64) < height
-                    {
-                        // Alpha blending from orbclient
-                        let alpha = (config.2 * v * 255.0) as u32;
-                        let new = (alpha << 24) | (config.1.data & 0x00FF_FFFF);
-
-                        let absolute_position = transform.transform_point(raqote::Point::new(
-                            (position.0 + off_x as f64) as f32,
-                            (position.1 + off_y as f64) as f32,
-                        ));
-                        let index = (absolute_position.y as i32 * width as i32
-                            + absolute_position.x as i32)
-                            as usize;
-                        if index >= data.len() {
-                            return;
-                        }
-                        let old = &mut data[index];
-                        if alpha >= 255 {
-                            *old = new;
-                        } else if alpha > 0 {
-                            let n_alpha = 255 - alpha;
-                            let rb = ((n_alpha * (*old & 0x00FF_00FF))
-                                + (alpha * (new & 0x00FF_00FF)))
-                                >> 8;
-                            let ag = (n_alpha * ((*old & 0xFF00_FF00) >> 8))
-                                + (alpha * (0x0100_0000 | ((new & 0x0000_FF00) >> 8)));
-
-                            *old = (rb & 0x00FF_00FF) | (ag & 0xFF00_FF00);
-                        }
+            let key = self.cache_glyph(g, config.0);
+            let glyph_cache = self.glyph_cache.borrow();
+
+            let raster = match glyph_cache.get(&key) {
+                Some(Some(raster)) => raster,
+                _ => continue,
+            };
+
+            // the raster is drawn at the origin, move it to the position of the glyph
+            let min_x = raster.min_x + g.position().x.round() as i32;
+            let min_y = raster.min_y + g.position().y.floor() as i32;
+
+            for (i, v) in raster.coverage.iter().enumerate() {
+                if *v <= 0.0 {
+                    continue;
+                }
+
+                let off_x = min_x + i as i32 % raster.width;
+                let off_y = min_y + i as i32 / raster.width;
+
+                if off_x >= 0
+                    && off_x < pixel_width
+                    && off_y >= 0
+                    && off_y < pixel_height
+                    && position.0 + off_x as f64 >= clip.x()
+                    && position.0 + off_x as f64 <= clip.x() + clip.width()
+                    && position.1 + off_y as f64 >= clip.y()
+                    && position.1 + off_y as f64 <= clip.y() + clip.height()
+                    && position.0 + (off_x as f64) < width
+                    && position.1 + (off_y as f64) < height
+                {
+                    // Alpha blending from orbclient
+                    let alpha = (config.2 * v * 255.0) as u32;
+                    let new = (alpha << 24) | color;
+
+                    let absolute_position = transform.transform_point(raqote::Point::new(
+                        (position.0 + off_x as f64) as f32,
+                        (position.1 + off_y as f64) as f32,
+                    ));
+                    let index = (absolute_position.y as i32 * width as i32
+                        + absolute_position.x as i32) as usize;
+                    if index >= data.len() {
+                        continue;
+                    }
+                    let old = &mut data[index];
+                    if alpha >= 255 {
+                        *old = new;
+                    } else if alpha > 0 {
+                        let n_alpha = 255 - alpha;
+                        let rb =
+                            ((n_alpha * (*old & 0x00FF_00FF)) + (alpha * (new & 0x00FF_00FF))) >> 8;
+                        let ag = (n_alpha * ((*old & 0xFF00_FF00) >> 8))
+                            + (alpha * (0x0100_0000 | ((new & 0x0000_FF00) >> 8)));
+
+                        *old = (rb & 0x00FF_00FF) | (ag & 0xFF00_FF00);
                     }
-                });
+                }
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glyph_cache() {
+        let font = Font::from_bytes(include_bytes!(
+            "../../../theme_default/assets/fonts/Roboto-Regular.ttf"
+        ))
+        .unwrap();
+
+        let render = |font: &Font| {
+            let mut data = vec![0; 200 * 40];
+            font.render_text(
+                "Hello",
+                &mut data,
+                &raqote::Transform::identity(),
+                200.0,
+                40.0,
+                (16.0, Color::rgb(0, 0, 0), 1.0),
+                (0.0, 0.0),
+            );
+            data
+        };
+
+        let first = render(&font);
+        assert!(first.iter().any(|pixel| *pixel != 0));
+        assert_eq!(font.glyph_cache.borrow().len(), 4);
+
+        // the second call draws the cached glyphs
+        assert_eq!(render(&font), first);
+
+        font.clear_glyph_cache();
+        assert!(font.glyph_cache.borrow().is_empty());
+    }
+}
@@ -1,6 +1,6 @@
 use std::{fmt, path::Path};
 
-use crate::RenderTarget;
+use crate::{utils::ImageHandle, RenderTarget};
 
 #[derive(Clone, Default)]
 pub struct Image {
@@ -111,4 +111,45 @@ impl From<String> for Image {
     }
 }
 
+impl From<&Image> for ImageHandle {
+    fn from(image: &Image) -> ImageHandle {
+        // an image that cannot be shared results in an empty handle, brushes skip drawing it
+        ImageHandle::new(
+            image.width() as u32,
+            image.height() as u32,
+            image.data().to_vec(),
+        )
+        .unwrap_or_default()
+    }
+}
+
+impl From<Image> for ImageHandle {
+    fn from(image: Image) -> ImageHandle {
+        ImageHandle::from(&image)
+    }
+}
+
 // --- Conversions ---
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_path() {
+        let path = std::env::temp_dir().join("orbtk_render_test_from_path.png");
+
+        let mut png = image::RgbaImage::new(3, 2);
+        png.put_pixel(1, 0, image::Rgba([255, 0, 0, 255]));
+        png.save(&path).unwrap();
+
+        let image = Image::from_path(path.clone()).unwrap();
+        let _ = std::fs::remove_file(path);
+
+        assert!((image.width() - 3.0).abs() < f64::EPSILON);
+        assert!((image.height() - 2.0).abs() < f64::EPSILON);
+        assert_eq!(image.data().len(), 6);
+        assert_eq!(image.data()[1], 0xFFFF_0000);
+        assert_eq!(image.data()[0], 0);
+    }
+}
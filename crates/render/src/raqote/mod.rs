@@ -1,7 +1,9 @@
 use smallvec::SmallVec;
-use std::{cmp, collections::HashMap};
+use std::{cmp, collections::HashMap, io, path::Path};
 
-use crate::{common::*, utils::*, PipelineTrait, RenderConfig, RenderTarget, TextMetrics};
+use crate::{
+    common::*, utils::*, PipelineTrait, RenderConfig, RenderStats, RenderTarget, TextMetrics,
+};
 
 pub use self::font::*;
 pub use self::image::Image;
@@ -11,15 +13,23 @@ mod image;
 
 type StatesOnStack = [(RenderConfig, PathRect, usize); 2];
 
+// number of measured texts that are cached
+const TEXT_METRICS_CACHE_SIZE: usize = 512;
+
 /// The RenderContext2D trait, provides the rendering ctx. It is used for drawing shapes, text, images, and other objects.
 pub struct RenderContext2D {
     draw_target: raqote::DrawTarget,
-    path: raqote::Path,
+    // the current path, ops are appended without rebuilding the path
+    path_builder: raqote::PathBuilder,
     config: RenderConfig,
     saved_states: SmallVec<StatesOnStack>,
     fonts: HashMap<String, Font>,
+    text_metrics_cache: TextMetricsCache,
     path_rect: PathRect,
     clips_count: usize,
+    stats: RenderStats,
+    auto_clear: bool,
+    clear_transparent: bool,
 
     background: Color,
 }
@@ -29,24 +39,43 @@ impl RenderContext2D {
     pub fn new(width: f64, height: f64) -> Self {
         RenderContext2D {
             draw_target: raqote::DrawTarget::new(width as i32, height as i32),
-            path: raqote::Path {
-                ops: Vec::new(),
-                winding: raqote::Winding::NonZero,
-            },
+            path_builder: raqote::PathBuilder::new(),
             config: RenderConfig::default(),
             saved_states: SmallVec::<StatesOnStack>::new(),
             fonts: HashMap::new(),
+            text_metrics_cache: TextMetricsCache::new(TEXT_METRICS_CACHE_SIZE),
             path_rect: PathRect::new(None),
             clips_count: 0,
+            stats: RenderStats::default(),
+            auto_clear: true,
+            clear_transparent: false,
             background: Color::default(),
         }
     }
 
+    /// Creates a new render ctx 2d that is not attached to a window, e.g. to test drawing code. The raqote
+    /// context always draws into its own buffer, so this is the same as `new`.
+    pub fn new_headless(width: f64, height: f64) -> Self {
+        RenderContext2D::new(width, height)
+    }
+
     /// Set the background of the render context.
     pub fn set_background(&mut self, background: Color) {
         self.background = background;
     }
 
+    /// Sets if the render context is cleared on the start of each frame (default `true`). If it is set to `false`
+    /// the new frame is drawn on top of the pixels of the previous frame.
+    pub fn set_auto_clear(&mut self, auto_clear: bool) {
+        self.auto_clear = auto_clear;
+    }
+
+    /// Sets if the render context is cleared to transparent instead of the background color on the start of
+    /// each frame (default `false`).
+    pub fn set_clear_transparent(&mut self, clear_transparent: bool) {
+        self.clear_transparent = clear_transparent;
+    }
+
     pub fn resize(&mut self, width: f64, height: f64) {
         self.draw_target = raqote::DrawTarget::new(width as i32, height as i32);
     }
@@ -59,6 +88,7 @@ impl RenderContext2D {
 
         if let Ok(font) = Font::from_bytes(font_file) {
             self.fonts.insert(family.to_string(), font);
+            self.text_metrics_cache.clear();
         }
     }
 
@@ -66,20 +96,36 @@ impl RenderContext2D {
 
     /// Draws a filled rectangle whose starting point is at the coordinates {x, y} with the specified width and height and whose style is determined by the fillStyle attribute.
     pub fn fill_rect(&mut self, x: f64, y: f64, width: f64, height: f64) {
-        self.draw_target.fill_rect(
-            x as f32,
-            y as f32,
-            width as f32,
-            height as f32,
-            &brush_to_source(
-                &self.config.fill_style,
-                Rectangle::new((x, y), (width, height)),
-            ),
-            &raqote::DrawOptions {
-                alpha: self.config.alpha,
-                ..Default::default()
-            },
-        );
+        self.stats.fill_rects += 1;
+        let rect = Rectangle::new((x, y), (width, height));
+        let options = self.draw_options();
+
+        if !self.config.shadow.is_transparent() {
+            let mut path_builder = raqote::PathBuilder::new();
+            path_builder.rect(x as f32, y as f32, width as f32, height as f32);
+            self.fill_shadow(&path_builder.finish(), rect);
+        }
+
+        for brush in self.config.fill_style.layers() {
+            let clip = pattern_clip(brush, rect);
+
+            if let Some(clip) = &clip {
+                self.draw_target.push_clip(clip);
+            }
+
+            self.draw_target.fill_rect(
+                x as f32,
+                y as f32,
+                width as f32,
+                height as f32,
+                &brush_to_source(brush, rect),
+                &options,
+            );
+
+            if clip.is_some() {
+                self.draw_target.pop_clip();
+            }
+        }
     }
 
     /// Draws a rectangle that is stroked (outlined) according to the current strokeStyle and other ctx settings.
@@ -88,14 +134,33 @@ impl RenderContext2D {
         self.stroke();
     }
 
+    /// Draws a filled rectangle with rounded corners. The horizontal radius `rx` and vertical radius `ry` of
+    /// the corners are clamped to the half of the width and height.
+    pub fn fill_round_rect(&mut self, x: f64, y: f64, width: f64, height: f64, rx: f64, ry: f64) {
+        self.begin_path();
+        self.round_rect(x, y, width, height, rx, ry);
+        self.fill();
+    }
+
+    /// Draws a stroked (outlined) rectangle with rounded corners. The horizontal radius `rx` and vertical
+    /// radius `ry` of the corners are clamped to the half of the width and height.
+    pub fn stroke_round_rect(&mut self, x: f64, y: f64, width: f64, height: f64, rx: f64, ry: f64) {
+        self.begin_path();
+        self.round_rect(x, y, width, height, rx, ry);
+        self.stroke();
+    }
+
     // Text
 
-    /// Draws (fills) a given text at the given (x, y) position.
+    /// Draws (fills) a given text at the given (x, y) position. The position is interpreted by the current
+    /// text align and text baseline.
     pub fn fill_text(&mut self, text: &str, x: f64, y: f64) {
         if text.is_empty() {
             return;
         }
 
+        self.stats.texts += 1;
+
         let color = match self.config.fill_style {
             Brush::SolidColor(color) => color,
             _ => Color::from("#000000"),
@@ -108,6 +173,7 @@ impl RenderContext2D {
         // The borrow-checker forces the clone
         let text_transform = self.draw_target.get_transform().to_owned();
         if let Some(font) = self.fonts.get(&self.config.font_config.family) {
+            let (x, y) = self.text_origin(font, text, x, y);
             let width = self.draw_target.width() as f64;
             let height = self.draw_target.height() as f64;
 
@@ -136,6 +202,54 @@ impl RenderContext2D {
         }
     }
 
+    /// Draws (fills) the given text at the given (x, y) position and wraps it into lines that fits into
+    /// `max_width`. Returns the height of all drawn lines.
+    pub fn fill_text_wrapped(&mut self, text: &str, x: f64, y: f64, max_width: f64) -> f64 {
+        let font_size = self.config.font_config.font_size;
+        let line_height = self
+            .fonts
+            .get(&self.config.font_config.family)
+            .map_or(font_size, |font| font.line_height(font_size));
+
+        let lines = wrap_text(text, max_width, |line| self.measure_text(line).width);
+
+        for (i, line) in lines.iter().enumerate() {
+            self.fill_text(line, x, y + i as f64 * line_height);
+        }
+
+        lines.len() as f64 * line_height
+    }
+
+    // Moves the given position by the current text align and text baseline to the top left corner of the text.
+    fn text_origin(&self, font: &Font, text: &str, x: f64, y: f64) -> (f64, f64) {
+        let align = self.config.text_align;
+        let baseline = self.config.text_baseline;
+
+        if (align == TextAlignment::Start || align == TextAlignment::Left)
+            && (baseline == TextBaseline::Top || baseline == TextBaseline::Hanging)
+        {
+            return (x, y);
+        }
+
+        let font_size = self.config.font_config.font_size;
+        let (width, height) = font.measure_text(text, font_size);
+
+        let x = match align {
+            TextAlignment::Start | TextAlignment::Left => x,
+            TextAlignment::Center => x - width / 2.0,
+            TextAlignment::End | TextAlignment::Right => x - width,
+        };
+
+        let y = match baseline {
+            TextBaseline::Top | TextBaseline::Hanging => y,
+            TextBaseline::Middle => y - height / 2.0,
+            TextBaseline::Alphabetic => y - font.ascent(font_size),
+            TextBaseline::Ideographic | TextBaseline::Bottom => y - height,
+        };
+
+        (x, y)
+    }
+
     pub fn measure(
         &mut self,
         text: &str,
@@ -147,7 +261,8 @@ impl RenderContext2D {
         self.measure_text(text)
     }
 
-    /// Returns a TextMetrics object.
+    /// Returns a TextMetrics object. The metrics are cached, measuring the same text again with the same font is
+    /// cheap.
     pub fn measure_text(&mut self, text: &str) -> TextMetrics {
         let mut text_metrics = TextMetrics::default();
 
@@ -155,11 +270,21 @@ impl RenderContext2D {
             return text_metrics;
         }
 
-        if let Some(font) = self.fonts.get(&self.config.font_config.family) {
-            let (width, height) = font.measure_text(text, self.config.font_config.font_size);
+        let family = &self.config.font_config.family;
+        let font_size = self.config.font_config.font_size;
+
+        if let Some(text_metrics) = self.text_metrics_cache.get(family, font_size, text) {
+            return text_metrics;
+        }
+
+        if let Some(font) = self.fonts.get(family) {
+            let (width, height) = font.measure_text(text, font_size);
 
             text_metrics.width = width;
             text_metrics.height = height;
+
+            self.text_metrics_cache
+                .insert(family, font_size, text, text_metrics);
         }
 
         text_metrics
@@ -171,14 +296,30 @@ impl RenderContext2D {
             Some(rect) => rect,
             None => return, // The path is empty, do nothing
         };
-        self.draw_target.fill(
-            &self.path,
-            &brush_to_source(&self.config.fill_style, rect),
-            &raqote::DrawOptions {
-                alpha: self.config.alpha,
-                ..Default::default()
-            },
-        );
+        self.stats.fills += 1;
+        let options = self.draw_options();
+        let path = self.take_path();
+
+        if !self.config.shadow.is_transparent() {
+            self.fill_shadow(&path, rect);
+        }
+
+        for brush in self.config.fill_style.layers() {
+            let clip = pattern_clip(brush, rect);
+
+            if let Some(clip) = &clip {
+                self.draw_target.push_clip(clip);
+            }
+
+            self.draw_target
+                .fill(&path, &brush_to_source(brush, rect), &options);
+
+            if clip.is_some() {
+                self.draw_target.pop_clip();
+            }
+        }
+
+        self.restore_path(path);
     }
 
     /// Strokes {outlines} the current or given path with the current stroke style.
@@ -187,82 +328,194 @@ impl RenderContext2D {
             Some(rect) => rect,
             None => return, // The path is empty, do nothing
         };
-        self.draw_target.stroke(
-            &self.path,
-            &brush_to_source(&self.config.stroke_style, rect),
-            &raqote::StrokeStyle {
-                width: self.config.line_width as f32,
-                ..Default::default()
-            },
-            &raqote::DrawOptions {
-                alpha: self.config.alpha,
-                ..Default::default()
-            },
-        );
+        self.stats.strokes += 1;
+        let stroke_style = raqote::StrokeStyle {
+            width: self.config.line_width as f32,
+            cap: line_cap_to_raqote(self.config.line_cap),
+            join: line_join_to_raqote(self.config.line_join),
+            dash_array: self.config.line_dash.iter().map(|d| *d as f32).collect(),
+            dash_offset: self.config.line_dash_offset as f32,
+            ..Default::default()
+        };
+        let options = self.draw_options();
+        let path = self.take_path();
+
+        for brush in self.config.stroke_style.layers() {
+            let clip = pattern_clip(brush, rect);
+
+            if let Some(clip) = &clip {
+                self.draw_target.push_clip(clip);
+            }
+
+            self.draw_target.stroke(
+                &path,
+                &brush_to_source(brush, rect),
+                &stroke_style,
+                &options,
+            );
+
+            if clip.is_some() {
+                self.draw_target.pop_clip();
+            }
+        }
+
+        self.restore_path(path);
+    }
+
+    // finishes the path builder to get the current path, the builder does not provide access to the path it builds.
+    // Hand the path back with `restore_path`, both are cheap because the path is moved and not copied.
+    fn take_path(&mut self) -> raqote::Path {
+        std::mem::replace(&mut self.path_builder, raqote::PathBuilder::new()).finish()
+    }
+
+    fn restore_path(&mut self, path: raqote::Path) {
+        self.path_builder = raqote::PathBuilder::from(path);
+    }
+
+    fn path_is_empty(&mut self) -> bool {
+        let path = self.take_path();
+        let is_empty = path.ops.is_empty();
+        self.restore_path(path);
+        is_empty
     }
 
     /// Starts a new path by emptying the list of sub-paths. Call this when you want to create a new path.
     pub fn begin_path(&mut self) {
-        self.path = raqote::Path {
-            ops: Vec::new(),
-            winding: raqote::Winding::NonZero,
-        };
+        self.path_builder = raqote::PathBuilder::new();
         self.path_rect.rebirth();
     }
 
     /// Attempts to add a straight line from the current point to the start of the current sub-path. If the shape has already been closed or has only one point, this function does nothing.
     pub fn close_path(&mut self) {
-        let mut path_builder = raqote::PathBuilder::from(self.path.clone());
-        path_builder.close();
-        self.path = path_builder.finish();
+        self.path_builder.close();
         self.path_rect.record_path_close();
     }
 
     /// Adds a rectangle to the current path.
     pub fn rect(&mut self, x: f64, y: f64, width: f64, height: f64) {
-        let mut path_builder = raqote::PathBuilder::from(self.path.clone());
-        path_builder.rect(x as f32, y as f32, width as f32, height as f32);
-        self.path = path_builder.finish();
+        self.path_builder
+            .rect(x as f32, y as f32, width as f32, height as f32);
+        self.path_rect.record_rect(x, y, width, height);
+    }
+
+    /// Adds a rectangle with rounded corners to the current path.
+    pub fn round_rect(&mut self, x: f64, y: f64, width: f64, height: f64, rx: f64, ry: f64) {
+        // control point distance to approximate a quarter ellipse with a cubic bézier curve
+        const KAPPA: f64 = 0.552_284_75;
+
+        let rx = rx.max(0.0).min(width / 2.0);
+        let ry = ry.max(0.0).min(height / 2.0);
+        let (kx, ky) = (rx * KAPPA, ry * KAPPA);
+        let (r, b) = (x + width, y + height);
+
+        self.path_builder.move_to((x + rx) as f32, y as f32);
+        self.path_builder.line_to((r - rx) as f32, y as f32);
+        self.path_builder.cubic_to(
+            (r - rx + kx) as f32,
+            y as f32,
+            r as f32,
+            (y + ry - ky) as f32,
+            r as f32,
+            (y + ry) as f32,
+        );
+        self.path_builder.line_to(r as f32, (b - ry) as f32);
+        self.path_builder.cubic_to(
+            r as f32,
+            (b - ry + ky) as f32,
+            (r - rx + kx) as f32,
+            b as f32,
+            (r - rx) as f32,
+            b as f32,
+        );
+        self.path_builder.line_to((x + rx) as f32, b as f32);
+        self.path_builder.cubic_to(
+            (x + rx - kx) as f32,
+            b as f32,
+            x as f32,
+            (b - ry + ky) as f32,
+            x as f32,
+            (b - ry) as f32,
+        );
+        self.path_builder.line_to(x as f32, (y + ry) as f32);
+        self.path_builder.cubic_to(
+            x as f32,
+            (y + ry - ky) as f32,
+            (x + rx - kx) as f32,
+            y as f32,
+            (x + rx) as f32,
+            y as f32,
+        );
+        self.path_builder.close();
         self.path_rect.record_rect(x, y, width, height);
     }
 
     /// Creates a circular arc centered at (x, y) with a radius of radius. The path starts at startAngle and ends at endAngle.
     pub fn arc(&mut self, x: f64, y: f64, radius: f64, start_angle: f64, end_angle: f64) {
-        let mut path_builder = raqote::PathBuilder::from(self.path.clone());
-        path_builder.arc(
+        self.path_builder.arc(
             x as f32,
             y as f32,
             radius as f32,
             start_angle as f32,
             end_angle as f32,
         );
-        self.path = path_builder.finish();
         self.path_rect
             .record_arc(x, y, radius, start_angle, end_angle);
     }
 
+    /// Adds an elliptical arc centered at (x, y) with the radii `radius_x` and `radius_y` to the path. The ellipse is
+    /// rotated by `rotation`, the arc starts at `start_angle` and ends clockwise at `end_angle`.
+    pub fn ellipse(
+        &mut self,
+        x: f64,
+        y: f64,
+        radius_x: f64,
+        radius_y: f64,
+        rotation: f64,
+        start_angle: f64,
+        end_angle: f64,
+    ) {
+        let (start, curves) =
+            ellipse_curves(x, y, radius_x, radius_y, rotation, start_angle, end_angle);
+
+        if self.path_is_empty() {
+            self.path_builder
+                .move_to(start.x() as f32, start.y() as f32);
+        } else {
+            self.path_builder
+                .line_to(start.x() as f32, start.y() as f32);
+        }
+
+        for (cp1, cp2, end) in &curves {
+            self.path_builder.cubic_to(
+                cp1.x() as f32,
+                cp1.y() as f32,
+                cp2.x() as f32,
+                cp2.y() as f32,
+                end.x() as f32,
+                end.y() as f32,
+            );
+        }
+
+        self.path_rect.record_ellipse(start, &curves);
+    }
+
     /// Begins a new sub-path at the point specified by the given {x, y} coordinates.
 
     pub fn move_to(&mut self, x: f64, y: f64) {
-        let mut path_builder = raqote::PathBuilder::from(self.path.clone());
-        path_builder.move_to(x as f32, y as f32);
-        self.path = path_builder.finish();
+        self.path_builder.move_to(x as f32, y as f32);
         self.path_rect.record_move_to(x, y);
     }
 
     /// Adds a straight line to the current sub-path by connecting the sub-path's last point to the specified {x, y} coordinates.
     pub fn line_to(&mut self, x: f64, y: f64) {
-        let mut path_builder = raqote::PathBuilder::from(self.path.clone());
-        path_builder.line_to(x as f32, y as f32);
-        self.path = path_builder.finish();
+        self.path_builder.line_to(x as f32, y as f32);
         self.path_rect.record_line_to(x, y);
     }
 
     /// Adds a quadratic Bézier curve to the current sub-path.
     pub fn quadratic_curve_to(&mut self, cpx: f64, cpy: f64, x: f64, y: f64) {
-        let mut path_builder = raqote::PathBuilder::from(self.path.clone());
-        path_builder.quad_to(cpx as f32, cpy as f32, x as f32, y as f32);
-        self.path = path_builder.finish();
+        self.path_builder
+            .quad_to(cpx as f32, cpy as f32, x as f32, y as f32);
         self.path_rect.record_quadratic_curve_to(cpx, cpy, x, y);
     }
 
@@ -270,8 +523,7 @@ impl RenderContext2D {
     /// It requires three points: the first two are control points and the third one is the end point.
     /// The starting point is the latest point in the current path, which can be changed using MoveTo{} before creating the Bézier curve.
     pub fn bezier_curve_to(&mut self, cp1x: f64, cp1y: f64, cp2x: f64, cp2y: f64, x: f64, y: f64) {
-        let mut path_builder = raqote::PathBuilder::from(self.path.clone());
-        path_builder.cubic_to(
+        self.path_builder.cubic_to(
             cp1x as f32,
             cp1y as f32,
             cp2x as f32,
@@ -293,15 +545,13 @@ impl RenderContext2D {
                 width: render_target.width() as i32,
                 height: render_target.height() as i32,
             },
-            &raqote::DrawOptions {
-                alpha: self.config.alpha,
-                ..Default::default()
-            },
+            &self.draw_options(),
         );
     }
 
     /// Draws the image.
     pub fn draw_image(&mut self, image: &Image, x: f64, y: f64) {
+        self.stats.images += 1;
         self.draw_target.draw_image_at(
             x as f32,
             y as f32,
@@ -310,41 +560,46 @@ impl RenderContext2D {
                 width: image.width() as i32,
                 height: image.height() as i32,
             },
-            &raqote::DrawOptions {
-                alpha: self.config.alpha,
-                ..Default::default()
-            },
+            &self.draw_options(),
         );
     }
 
     /// Draws the given part of the image.
     pub fn draw_image_with_clip(&mut self, image: &Image, clip: Rectangle, x: f64, y: f64) {
-        let mut y = y as i32;
-        let stride = image.width();
-        let mut offset = clip.y().mul_add(stride, clip.x()) as usize;
-        let last_offset = cmp::min(
-            ((clip.y() + clip.height()).mul_add(stride, clip.x())) as usize,
-            image.data().len(),
+        self.stats.images += 1;
+
+        // The clip is limited to the bounds of the image.
+        let stride = image.width() as usize;
+        let clip_x = (clip.x().max(0.0) as usize).min(stride);
+        let clip_y = (clip.y().max(0.0) as usize).min(image.height() as usize);
+        let clip_width = cmp::min(clip.width().max(0.0) as usize, stride - clip_x);
+        let clip_height = cmp::min(
+            clip.height().max(0.0) as usize,
+            image.height() as usize - clip_y,
         );
-        while offset < last_offset {
-            let next_offset = offset + stride as usize;
 
-            self.draw_target.draw_image_at(
-                x as f32,
-                y as f32,
-                &raqote::Image {
-                    data: &image.data()[offset..],
-                    width: clip.width() as i32,
-                    height: 1,
-                },
-                &raqote::DrawOptions {
-                    alpha: self.config.alpha,
-                    ..Default::default()
-                },
-            );
-            offset = next_offset;
-            y += 1;
+        if clip_width == 0 || clip_height == 0 {
+            return;
+        }
+
+        // Copies the clipped rows into a contiguous buffer to draw it at once.
+        let mut data = Vec::with_capacity(clip_width * clip_height);
+
+        for row in clip_y..clip_y + clip_height {
+            let offset = row * stride + clip_x;
+            data.extend_from_slice(&image.data()[offset..offset + clip_width]);
         }
+
+        self.draw_target.draw_image_at(
+            x as f32,
+            y as f32,
+            &raqote::Image {
+                data: &data,
+                width: clip_width as i32,
+                height: clip_height as i32,
+            },
+            &self.draw_options(),
+        );
     }
 
     pub fn draw_pipeline(
@@ -362,11 +617,115 @@ impl RenderContext2D {
 
     /// Creates a clipping path from the current sub-paths. Everything drawn after clip() is called appears inside the clipping path only.
     pub fn clip(&mut self) {
-        self.draw_target.push_clip(&self.path);
+        let path = self.take_path();
+        self.draw_target.push_clip(&path);
+        self.restore_path(path);
         self.path_rect.record_clip();
         self.clips_count += 1;
     }
 
+    // Filters
+
+    /// Blurs the already drawn pixels of the given region with a gaussian blur of the standard deviation `radius`.
+    /// The region is given in canvas pixels, the current transform and clip are not applied. Each call reads and
+    /// writes every pixel of the region six times, so blurring large regions every frame is expensive.
+    /// A `radius` of `0.0` does nothing.
+    pub fn blur_region(&mut self, x: f64, y: f64, width: f64, height: f64, radius: f64) {
+        if radius <= 0.0 {
+            return;
+        }
+
+        let x = x.max(0.0);
+        let y = y.max(0.0);
+        let stride = self.draw_target.width() as usize;
+
+        blur_pixels(
+            self.draw_target.get_data_mut(),
+            stride,
+            (
+                x.round() as usize,
+                y.round() as usize,
+                width.max(0.0).round() as usize,
+                height.max(0.0).round() as usize,
+            ),
+            radius,
+        );
+    }
+
+    // Pixel manipulation
+
+    /// Returns the pixels of the given region as RGBA bytes. Each pixel is stored row by row as four bytes in
+    /// the order red, green, blue, alpha. The color channels are not premultiplied by alpha, like the
+    /// `getImageData` of the HTML canvas. The region is given in canvas pixels, the current transform is not
+    /// applied and pixels outside of the canvas are transparent black.
+    pub fn get_image_data(&self, x: f64, y: f64, width: f64, height: f64) -> Vec<u8> {
+        let (x, y) = (x.round() as i32, y.round() as i32);
+        let (width, height) = (
+            width.max(0.0).round() as i32,
+            height.max(0.0).round() as i32,
+        );
+        let (canvas_width, canvas_height) = (self.draw_target.width(), self.draw_target.height());
+        let data = self.draw_target.get_data();
+        let mut image_data = Vec::with_capacity((width * height * 4) as usize);
+
+        for row in y..y + height {
+            for column in x..x + width {
+                if row < 0 || column < 0 || row >= canvas_height || column >= canvas_width {
+                    image_data.extend_from_slice(&[0, 0, 0, 0]);
+                } else {
+                    image_data.extend_from_slice(&argb_to_rgba(
+                        data[(row * canvas_width + column) as usize],
+                    ));
+                }
+            }
+        }
+
+        image_data
+    }
+
+    /// Replaces the pixels of the given region by the RGBA bytes of `data`, in the format returned by
+    /// `get_image_data`. The pixels are not blended with the drawn pixels, the current transform, clip and alpha
+    /// are not applied. Pixels outside of the canvas are skipped.
+    pub fn put_image_data(&mut self, data: &[u8], x: f64, y: f64, width: f64, height: f64) {
+        let (x, y) = (x.round() as i32, y.round() as i32);
+        let width = width.max(0.0).round() as i32;
+        let height = height.max(0.0).round() as i32;
+        let (canvas_width, canvas_height) = (self.draw_target.width(), self.draw_target.height());
+        let pixels = self.draw_target.get_data_mut();
+
+        for (i, rgba) in data
+            .chunks_exact(4)
+            .take((width * height) as usize)
+            .enumerate()
+        {
+            let row = y + i as i32 / width;
+            let column = x + i as i32 % width;
+
+            if row < 0 || column < 0 || row >= canvas_height || column >= canvas_width {
+                continue;
+            }
+
+            pixels[(row * canvas_width + column) as usize] = rgba_to_argb(rgba);
+        }
+    }
+
+    /// Saves the pixels of the canvas as PNG file, e.g. to export the current frame or to compare it in tests.
+    /// The color channels are not premultiplied by alpha, see `get_image_data`.
+    pub fn save_png(&self, path: &Path) -> io::Result<()> {
+        let (width, height) = (self.draw_target.width(), self.draw_target.height());
+        let data = self.get_image_data(0.0, 0.0, width as f64, height as f64);
+
+        ::image::save_buffer_with_format(
+            path,
+            &data,
+            width as u32,
+            height as u32,
+            ::image::ColorType::Rgba8,
+            ::image::ImageFormat::Png,
+        )
+        .map_err(|error| io::Error::new(io::ErrorKind::Other, error))
+    }
+
     // Line styles
 
     /// Sets the thickness of lines.
@@ -374,11 +733,57 @@ impl RenderContext2D {
         self.config.line_width = line_width;
     }
 
+    /// Sets the shape used to draw the end points of lines.
+    pub fn set_line_cap(&mut self, line_cap: LineCap) {
+        self.config.line_cap = line_cap;
+    }
+
+    /// Sets the shape used to join two line segments where they meet.
+    pub fn set_line_join(&mut self, line_join: LineJoin) {
+        self.config.line_join = line_join;
+    }
+
+    /// Sets the alternating lengths of lines and gaps used to stroke dashed lines. An empty slice
+    /// resets to solid lines.
+    pub fn set_line_dash(&mut self, segments: &[f64]) {
+        self.config.line_dash = segments.to_vec();
+    }
+
+    /// Sets the offset where the dash pattern of lines starts.
+    pub fn set_line_dash_offset(&mut self, offset: f64) {
+        self.config.line_dash_offset = offset;
+    }
+
     /// Sets the alpha value,
     pub fn set_alpha(&mut self, alpha: f32) {
         self.config.alpha = alpha;
     }
 
+    /// Gets the current alpha value.
+    pub fn alpha(&self) -> f32 {
+        self.config.alpha
+    }
+
+    /// Sets how shapes and images are combined with the already drawn pixels. Text is always drawn with
+    /// `CompositeOperation::SourceOver`.
+    pub fn set_global_composite_operation(&mut self, composite_operation: CompositeOperation) {
+        self.config.composite_operation = composite_operation;
+    }
+
+    /// Gets the current composite operation.
+    pub fn global_composite_operation(&self) -> CompositeOperation {
+        self.config.composite_operation
+    }
+
+    // Returns the draw options of the current alpha and composite operation.
+    fn draw_options(&self) -> raqote::DrawOptions {
+        raqote::DrawOptions {
+            alpha: self.config.alpha,
+            blend_mode: composite_operation_to_raqote(self.config.composite_operation),
+            ..Default::default()
+        }
+    }
+
     /// Specifies the font family.
     pub fn set_font_family(&mut self, family: impl Into<String>) {
         self.config.font_config.family = family.into();
@@ -386,7 +791,17 @@ impl RenderContext2D {
 
     /// Specifies the font size.
     pub fn set_font_size(&mut self, size: f64) {
-        self.config.font_config.font_size = size + 4.0;
+        self.config.font_config.font_size = size;
+    }
+
+    /// Specifies the horizontal alignment of text relative to the x position of `fill_text`.
+    pub fn set_text_align(&mut self, text_align: TextAlignment) {
+        self.config.text_align = text_align;
+    }
+
+    /// Specifies the vertical alignment of text relative to the y position of `fill_text`.
+    pub fn set_text_baseline(&mut self, text_baseline: TextBaseline) {
+        self.config.text_baseline = text_baseline;
     }
 
     // Fill and stroke style
@@ -401,6 +816,95 @@ impl RenderContext2D {
         self.config.stroke_style = stroke_style.into();
     }
 
+    // Shadows
+
+    /// Sets a drop shadow that is drawn below the shapes of `fill`, `fill_rect` and `fill_round_rect`. The offset
+    /// is given in canvas pixels and is not affected by the current transform, `blur` is the standard deviation
+    /// of the gaussian blur. A transparent `color` disables the shadow.
+    pub fn set_shadow(&mut self, offset_x: f64, offset_y: f64, blur: f64, color: Color) {
+        self.config.shadow = BoxShadow::new((offset_x, offset_y), blur.max(0.0), 0.0, color);
+    }
+
+    /// Removes the drop shadow.
+    pub fn clear_shadow(&mut self) {
+        self.config.shadow = BoxShadow::default();
+    }
+
+    // Draws the shadow of the given path, the rect is the bounding rect of the path. The coverage of the path is
+    // drawn into an offscreen target that covers only the blurred shadow, blurred and composited on the canvas.
+    fn fill_shadow(&mut self, path: &raqote::Path, rect: Rectangle) {
+        let shadow = self.config.shadow;
+        let transform = *self.draw_target.get_transform();
+
+        // bounds of the shadow in canvas pixels
+        let corners = [
+            (rect.x(), rect.y()),
+            (rect.x() + rect.width(), rect.y()),
+            (rect.x(), rect.y() + rect.height()),
+            (rect.x() + rect.width(), rect.y() + rect.height()),
+        ]
+        .iter()
+        .map(|(x, y)| {
+            (
+                *x as f32 * transform.m11 + *y as f32 * transform.m21 + transform.m31,
+                *x as f32 * transform.m12 + *y as f32 * transform.m22 + transform.m32,
+            )
+        })
+        .collect::<Vec<(f32, f32)>>();
+        let padding = (shadow.blur * 3.0).ceil() as f32;
+        let min_x = corners.iter().fold(f32::MAX, |m, c| m.min(c.0)) + shadow.offset.x() as f32;
+        let min_y = corners.iter().fold(f32::MAX, |m, c| m.min(c.1)) + shadow.offset.y() as f32;
+        let max_x = corners.iter().fold(f32::MIN, |m, c| m.max(c.0)) + shadow.offset.x() as f32;
+        let max_y = corners.iter().fold(f32::MIN, |m, c| m.max(c.1)) + shadow.offset.y() as f32;
+
+        let x = ((min_x - padding).floor() as i32).max(0);
+        let y = ((min_y - padding).floor() as i32).max(0);
+        let width = ((max_x + padding).ceil() as i32).min(self.draw_target.width()) - x;
+        let height = ((max_y + padding).ceil() as i32).min(self.draw_target.height()) - y;
+
+        if width <= 0 || height <= 0 {
+            return;
+        }
+
+        let mut shadow_target = raqote::DrawTarget::new(width, height);
+        shadow_target.set_transform(&raqote::Transform::row_major(
+            transform.m11,
+            transform.m12,
+            transform.m21,
+            transform.m22,
+            transform.m31 + shadow.offset.x() as f32 - x as f32,
+            transform.m32 + shadow.offset.y() as f32 - y as f32,
+        ));
+        shadow_target.fill(
+            path,
+            &brush_to_source(&Brush::from(shadow.color), rect),
+            &raqote::DrawOptions {
+                alpha: self.config.alpha,
+                ..Default::default()
+            },
+        );
+        blur_pixels(
+            shadow_target.get_data_mut(),
+            width as usize,
+            (0, 0, width as usize, height as usize),
+            shadow.blur,
+        );
+
+        self.draw_target
+            .set_transform(&raqote::Transform::identity());
+        self.draw_target.draw_image_at(
+            x as f32,
+            y as f32,
+            &raqote::Image {
+                width,
+                height,
+                data: shadow_target.get_data(),
+            },
+            &raqote::DrawOptions::default(),
+        );
+        self.draw_target.set_transform(&transform);
+    }
+
     // Transformations
 
     /// Sets the transformation.
@@ -477,13 +981,28 @@ impl RenderContext2D {
         self.draw_target.get_data_u8_mut()
     }
 
+    /// Returns the counted draw operations since the start of the current frame.
+    pub fn render_stats(&self) -> RenderStats {
+        self.stats
+    }
+
     pub fn start(&mut self) {
-        self.clear(&Brush::from(self.background));
+        self.stats = RenderStats::default();
+
+        if !self.auto_clear {
+            return;
+        }
+
+        if self.clear_transparent {
+            self.clear(&Brush::from(Color::rgba(0, 0, 0, 0)));
+        } else {
+            self.clear(&Brush::from(self.background));
+        }
     }
     pub fn finish(&mut self) {}
 }
 
-fn brush_to_source<'a>(brush: &Brush, frame: Rectangle) -> raqote::Source<'a> {
+fn brush_to_source(brush: &Brush, frame: Rectangle) -> raqote::Source<'_> {
     match brush {
         Brush::SolidColor(color) => raqote::Source::Solid(raqote::SolidSource {
             r: color.r(),
@@ -500,71 +1019,429 @@ fn brush_to_source<'a>(brush: &Brush, frame: Rectangle) -> raqote::Source<'a> {
                 true => raqote::Spread::Repeat,
                 false => raqote::Spread::Pad,
             };
-            match coords {
-                LinearGradientCoords::Ends { start, end } => {
-                    let g_stops =
-                        build_unit_percent_gradient(&stops, end.distance(*start), |p, c| {
-                            raqote::GradientStop {
-                                position: p as f32,
-                                color: raqote::Color::new(c.a(), c.r(), c.g(), c.b()),
-                            }
-                        });
-                    let start = frame.position() + *start;
-                    let end = frame.position() + *end;
-                    raqote::Source::new_linear_gradient(
-                        raqote::Gradient { stops: g_stops },
-                        raqote::Point::new(start.x() as f32, start.y() as f32),
-                        raqote::Point::new(end.x() as f32, end.y() as f32),
-                        spread,
-                    )
-                }
-                LinearGradientCoords::Angle {
-                    angle,
-                    displacement,
-                } => {
-                    let z = linear_gradient_ends_from_angle(*angle, frame.size());
-                    let disp = displacement.pixels(frame.size());
-                    let start = frame.position() + frame.size() / 2.0 + -z + disp;
-                    let end = frame.position() + frame.size() / 2.0 + z + disp;
-                    let g_stops =
-                        build_unit_percent_gradient(&stops, end.distance(start), |p, c| {
-                            raqote::GradientStop {
-                                position: p as f32,
-                                color: raqote::Color::new(c.a(), c.r(), c.g(), c.b()),
-                            }
-                        });
-                    raqote::Source::new_linear_gradient(
-                        raqote::Gradient { stops: g_stops },
-                        raqote::Point::new(start.x() as f32, start.y() as f32),
-                        raqote::Point::new(end.x() as f32, end.y() as f32),
-                        spread,
-                    )
+            let (start, end) = linear_gradient_ends(coords, frame);
+            let g_stops = build_unit_percent_gradient(&stops, end.distance(start), |p, c| {
+                raqote::GradientStop {
+                    position: p as f32,
+                    color: raqote::Color::new(c.a(), c.r(), c.g(), c.b()),
                 }
-                LinearGradientCoords::Direction {
-                    direction,
-                    displacement,
-                } => {
-                    let width = frame.width();
-                    let height = frame.height();
-                    let (mut start, mut end) = direction.cross(width, height);
-                    let g_stops =
-                        build_unit_percent_gradient(&stops, end.distance(start), |p, c| {
-                            raqote::GradientStop {
-                                position: p as f32,
-                                color: raqote::Color::new(c.a(), c.r(), c.g(), c.b()),
-                            }
-                        });
-                    let displacement = displacement.pixels(frame.size());
-                    start = start + frame.position() + displacement;
-                    end = end + frame.position() + displacement;
-                    raqote::Source::new_linear_gradient(
-                        raqote::Gradient { stops: g_stops },
-                        raqote::Point::new(start.x() as f32, start.y() as f32),
-                        raqote::Point::new(end.x() as f32, end.y() as f32),
-                        spread,
-                    )
-                }
-            }
+            });
+            raqote::Source::new_linear_gradient(
+                raqote::Gradient { stops: g_stops },
+                raqote::Point::new(start.x() as f32, start.y() as f32),
+                raqote::Point::new(end.x() as f32, end.y() as f32),
+                spread,
+            )
+        }
+        Brush::Gradient(Gradient {
+            kind: GradientKind::Radial(radial),
+            stops,
+            repeat,
+        }) => {
+            let spread = match repeat {
+                true => raqote::Spread::Repeat,
+                false => raqote::Spread::Pad,
+            };
+            // the gradient reaches the farthest corner of the frame
+            let (center, radius) = radial_gradient_circle(radial.pos, frame);
+            let g_stops =
+                build_unit_percent_gradient(&stops, radius, |p, c| raqote::GradientStop {
+                    position: p as f32,
+                    color: raqote::Color::new(c.a(), c.r(), c.g(), c.b()),
+                });
+            raqote::Source::new_radial_gradient(
+                raqote::Gradient { stops: g_stops },
+                raqote::Point::new(center.x() as f32, center.y() as f32),
+                radius as f32,
+                spread,
+            )
+        }
+        Brush::Pattern {
+            image,
+            repeat_x,
+            repeat_y,
+        } => raqote::Source::Image(
+            raqote::Image {
+                width: image.width() as i32,
+                height: image.height() as i32,
+                data: image.data(),
+            },
+            if *repeat_x || *repeat_y {
+                raqote::ExtendMode::Repeat
+            } else {
+                raqote::ExtendMode::Pad
+            },
+            raqote::FilterMode::Nearest,
+            raqote::Transform::row_major(1.0, 0.0, 0.0, 1.0, -frame.x() as f32, -frame.y() as f32),
+        ),
+        // the layers of stacked brushes are drawn one by one, see `Brush::layers`
+        Brush::Stacked(_) => raqote::Source::Solid(raqote::SolidSource {
+            r: 0,
+            g: 0,
+            b: 0,
+            a: 0,
+        }),
+    }
+}
+
+// Pattern brushes that are not repeated in both directions are limited to the size of the image by a clip.
+fn pattern_clip(brush: &Brush, frame: Rectangle) -> Option<raqote::Path> {
+    if let Brush::Pattern {
+        image,
+        repeat_x,
+        repeat_y,
+    } = brush
+    {
+        if *repeat_x && *repeat_y {
+            return None;
         }
+
+        let width = if *repeat_x {
+            frame.width()
+        } else {
+            image.width() as f64
+        };
+        let height = if *repeat_y {
+            frame.height()
+        } else {
+            image.height() as f64
+        };
+
+        let mut path_builder = raqote::PathBuilder::new();
+        path_builder.rect(
+            frame.x() as f32,
+            frame.y() as f32,
+            width as f32,
+            height as f32,
+        );
+        return Some(path_builder.finish());
+    }
+
+    None
+}
+
+fn composite_operation_to_raqote(composite_operation: CompositeOperation) -> raqote::BlendMode {
+    match composite_operation {
+        CompositeOperation::SourceOver => raqote::BlendMode::SrcOver,
+        CompositeOperation::SourceIn => raqote::BlendMode::SrcIn,
+        CompositeOperation::SourceOut => raqote::BlendMode::SrcOut,
+        CompositeOperation::SourceAtop => raqote::BlendMode::SrcAtop,
+        CompositeOperation::DestinationOver => raqote::BlendMode::DstOver,
+        CompositeOperation::DestinationIn => raqote::BlendMode::DstIn,
+        CompositeOperation::DestinationOut => raqote::BlendMode::DstOut,
+        CompositeOperation::DestinationAtop => raqote::BlendMode::DstAtop,
+        CompositeOperation::Lighter => raqote::BlendMode::Add,
+        CompositeOperation::Copy => raqote::BlendMode::Src,
+        CompositeOperation::Xor => raqote::BlendMode::Xor,
+        CompositeOperation::Multiply => raqote::BlendMode::Multiply,
+        CompositeOperation::Screen => raqote::BlendMode::Screen,
+        CompositeOperation::Overlay => raqote::BlendMode::Overlay,
+        CompositeOperation::Darken => raqote::BlendMode::Darken,
+        CompositeOperation::Lighten => raqote::BlendMode::Lighten,
+        CompositeOperation::ColorDodge => raqote::BlendMode::ColorDodge,
+        CompositeOperation::ColorBurn => raqote::BlendMode::ColorBurn,
+        CompositeOperation::HardLight => raqote::BlendMode::HardLight,
+        CompositeOperation::SoftLight => raqote::BlendMode::SoftLight,
+        CompositeOperation::Difference => raqote::BlendMode::Difference,
+        CompositeOperation::Exclusion => raqote::BlendMode::Exclusion,
+    }
+}
+
+fn line_cap_to_raqote(line_cap: LineCap) -> raqote::LineCap {
+    match line_cap {
+        LineCap::Butt => raqote::LineCap::Butt,
+        LineCap::Round => raqote::LineCap::Round,
+        LineCap::Square => raqote::LineCap::Square,
+    }
+}
+
+fn line_join_to_raqote(line_join: LineJoin) -> raqote::LineJoin {
+    match line_join {
+        LineJoin::Miter => raqote::LineJoin::Miter,
+        LineJoin::Round => raqote::LineJoin::Round,
+        LineJoin::Bevel => raqote::LineJoin::Bevel,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_stats() {
+        let mut render_context = RenderContext2D::new(100.0, 100.0);
+        render_context.fill_rect(0.0, 0.0, 10.0, 10.0);
+        render_context.start();
+        assert_eq!(render_context.render_stats(), RenderStats::default());
+
+        render_context.set_fill_style("#ff0000");
+
+        for i in 0..3 {
+            render_context.begin_path();
+            render_context.rect(i as f64 * 20.0, 0.0, 10.0, 10.0);
+            render_context.fill();
+        }
+
+        render_context.fill_text("OrbTk", 0.0, 50.0);
+
+        let stats = render_context.render_stats();
+        assert_eq!(stats.fills, 3);
+        assert_eq!(stats.texts, 1);
+        assert_eq!(stats.strokes, 0);
+        assert_eq!(stats.fill_rects, 0);
+        assert_eq!(stats.images, 0);
+        assert_eq!(stats.draw_calls(), 4);
+    }
+
+    #[test]
+    fn test_save_png() {
+        let mut render_context = RenderContext2D::new_headless(4.0, 2.0);
+        render_context.set_fill_style("#ff0000");
+        render_context.fill_rect(0.0, 0.0, 2.0, 2.0);
+        render_context.set_fill_style(Color::rgba(0, 0, 255, 128));
+        render_context.fill_rect(2.0, 0.0, 1.0, 1.0);
+
+        let path = std::env::temp_dir().join("orbtk_render_test_save_png.png");
+        render_context.save_png(&path).unwrap();
+
+        let png = ::image::open(&path).unwrap().to_rgba8();
+        let _ = std::fs::remove_file(path);
+
+        assert_eq!(png.dimensions(), (4, 2));
+        assert_eq!(png.get_pixel(0, 0).0, [255, 0, 0, 255]);
+        assert_eq!(png.get_pixel(3, 1).0, [0, 0, 0, 0]);
+        assert_eq!(
+            png.into_raw(),
+            render_context.get_image_data(0.0, 0.0, 4.0, 2.0)
+        );
+    }
+
+    #[test]
+    fn test_measure_text_scales_with_font_size() {
+        let mut render_context = RenderContext2D::new(100.0, 100.0);
+        render_context.register_font(
+            "Roboto-Regular",
+            include_bytes!("../../../theme_default/assets/fonts/Roboto-Regular.ttf"),
+        );
+        render_context.set_font_family("Roboto-Regular");
+
+        render_context.set_font_size(12.0);
+        let small = render_context.measure_text("OrbTk");
+        render_context.set_font_size(24.0);
+        let medium = render_context.measure_text("OrbTk");
+        render_context.set_font_size(48.0);
+        let large = render_context.measure_text("OrbTk");
+
+        assert!(small.height >= 12.0);
+        assert!((medium.height - 2.0 * small.height).abs() <= 2.0);
+        assert!((large.height - 4.0 * small.height).abs() <= 4.0);
+        assert!((large.height - 2.0 * medium.height).abs() <= 2.0);
+    }
+
+    #[test]
+    fn test_draw_image_with_clip() {
+        let black = 0xff_00_00_00;
+        let white = 0xff_ff_ff_ff;
+        let checkerboard = (0..16)
+            .map(|i| {
+                if (i % 4 + i / 4) % 2 == 0 {
+                    black
+                } else {
+                    white
+                }
+            })
+            .collect();
+        let image = Image::from_data(4, 4, checkerboard).unwrap();
+
+        let mut render_context = RenderContext2D::new(4.0, 4.0);
+        render_context.draw_image_with_clip(
+            &image,
+            Rectangle::new((1.0, 1.0), (2.0, 2.0)),
+            0.0,
+            0.0,
+        );
+
+        #[rustfmt::skip]
+        let expected = [
+            black, white, 0, 0,
+            white, black, 0, 0,
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+        ];
+        assert_eq!(render_context.data(), &expected[..]);
+    }
+
+    #[test]
+    fn test_ellipse() {
+        let mut render_context = RenderContext2D::new(100.0, 100.0);
+        render_context.set_stroke_style("#ff0000");
+        render_context.begin_path();
+        render_context.ellipse(50.0, 50.0, 40.0, 20.0, 0.0, 0.0, 2.0 * std::f64::consts::PI);
+
+        let rect = render_context.path_rect.get_rect().unwrap();
+        assert!((rect.x() - 10.0).abs() < 1e-6);
+        assert!((rect.y() - 30.0).abs() < 1e-6);
+        assert!((rect.width() - 80.0).abs() < 1e-6);
+        assert!((rect.height() - 40.0).abs() < 1e-6);
+
+        render_context.stroke();
+        let pixel = |x: usize, y: usize| render_context.data()[y * 100 + x];
+        assert_ne!(pixel(10, 50), 0);
+        assert_ne!(pixel(50, 30), 0);
+        assert_eq!(pixel(50, 50), 0);
+        assert_eq!(pixel(50, 20), 0);
+    }
+
+    #[test]
+    fn test_pattern_brush() {
+        let red = 0xff_ff_00_00;
+        let blue = 0xff_00_00_ff;
+        let image = ImageHandle::new(2, 1, vec![red, blue]).unwrap();
+
+        let mut render_context = RenderContext2D::new(6.0, 2.0);
+        render_context.set_fill_style(Brush::pattern(image.clone()));
+        render_context.fill_rect(0.0, 0.0, 6.0, 1.0);
+        assert_eq!(
+            &render_context.data()[..6],
+            &[red, blue, red, blue, red, blue]
+        );
+
+        render_context.set_fill_style(Brush::Pattern {
+            image,
+            repeat_x: false,
+            repeat_y: true,
+        });
+        render_context.fill_rect(1.0, 1.0, 5.0, 1.0);
+        assert_eq!(&render_context.data()[6..], &[0, red, blue, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_empty_pattern_brush() {
+        let mut render_context = RenderContext2D::new(2.0, 2.0);
+        render_context.set_fill_style(Brush::pattern(&Image::new(0, 0)));
+        render_context.fill_rect(0.0, 0.0, 2.0, 2.0);
+        render_context.rect(0.0, 0.0, 2.0, 2.0);
+        render_context.fill();
+
+        assert!(render_context.data().iter().all(|pixel| *pixel == 0));
+    }
+
+    #[test]
+    fn test_stacked_brush() {
+        let mut render_context = RenderContext2D::new(2.0, 1.0);
+        render_context.set_fill_style(Brush::Stacked(vec![
+            Brush::from(Color::rgba(128, 0, 0, 128)),
+            Brush::from(Color::rgba(0, 0, 128, 128)),
+        ]));
+        render_context.fill_rect(0.0, 0.0, 1.0, 1.0);
+
+        // the blue layer is composited over the red one
+        let pixel = render_context.data()[0];
+        let channel = |shift: u32| ((pixel >> shift) & 0xff) as i32;
+        assert!((channel(24) - 192).abs() <= 1);
+        assert!((channel(16) - 64).abs() <= 1);
+        assert_eq!(channel(8), 0);
+        assert_eq!(channel(0), 128);
+        assert_eq!(render_context.data()[1], 0);
+    }
+
+    #[test]
+    fn test_shadow() {
+        let red = 0xff_ff_00_00;
+        let black = 0xff_00_00_00;
+
+        let mut render_context = RenderContext2D::new(40.0, 40.0);
+        render_context.set_shadow(10.0, 10.0, 0.0, Color::rgb(0, 0, 0));
+        render_context.set_fill_style("#ff0000");
+        render_context.fill_rect(5.0, 5.0, 10.0, 10.0);
+        assert_eq!(render_context.data()[7 * 40 + 7], red);
+        assert_eq!(render_context.data()[20 * 40 + 20], black);
+        assert_eq!(render_context.data()[30 * 40 + 30], 0);
+
+        render_context.clear_shadow();
+        render_context.fill_rect(25.0, 5.0, 10.0, 10.0);
+        assert_eq!(render_context.data()[17 * 40 + 37], 0);
+
+        // the blurred shadow reaches outside of the shape
+        let mut render_context = RenderContext2D::new(40.0, 40.0);
+        render_context.set_shadow(0.0, 0.0, 2.0, Color::rgb(0, 0, 0));
+        render_context.set_fill_style("#ff0000");
+        render_context.fill_round_rect(10.0, 10.0, 20.0, 20.0, 4.0, 4.0);
+        assert_eq!(render_context.data()[20 * 40 + 20], red);
+        assert!(render_context.data()[20 * 40 + 9] >> 24 > 0);
+        assert_eq!(render_context.data()[20 * 40 + 2], 0);
+    }
+
+    #[test]
+    fn test_composite_operation() {
+        let mut render_context = RenderContext2D::new(2.0, 1.0);
+        assert_eq!(
+            render_context.global_composite_operation(),
+            CompositeOperation::SourceOver
+        );
+
+        render_context.set_fill_style("#0000ff");
+        render_context.fill_rect(0.0, 0.0, 2.0, 1.0);
+        render_context.set_global_composite_operation(CompositeOperation::Multiply);
+        render_context.set_fill_style("#ff00ff");
+        render_context.fill_rect(0.0, 0.0, 1.0, 1.0);
+
+        // magenta multiplied with blue keeps the blue channel
+        assert_eq!(render_context.data()[0], 0xff_00_00_ff);
+        assert_eq!(render_context.data()[1], 0xff_00_00_ff);
+
+        // red multiplied with blue is black
+        render_context.set_fill_style("#ff0000");
+        render_context.fill_rect(1.0, 0.0, 1.0, 1.0);
+        assert_eq!(render_context.data()[1], 0xff_00_00_00);
+    }
+
+    #[test]
+    fn test_image_data() {
+        let mut render_context = RenderContext2D::new(4.0, 4.0);
+        render_context.set_fill_style("#ff0000");
+        render_context.fill_rect(0.0, 0.0, 2.0, 2.0);
+        render_context.set_fill_style("#0000ff");
+        render_context.fill_rect(2.0, 2.0, 2.0, 2.0);
+
+        let image_data = render_context.get_image_data(1.0, 1.0, 2.0, 2.0);
+        assert_eq!(
+            image_data,
+            vec![255, 0, 0, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255]
+        );
+
+        // pixels outside of the canvas are transparent
+        assert_eq!(
+            render_context.get_image_data(-1.0, 0.0, 1.0, 1.0),
+            vec![0, 0, 0, 0]
+        );
+
+        render_context.put_image_data(&image_data, 0.0, 2.0, 2.0, 2.0);
+        assert_eq!(
+            render_context.get_image_data(0.0, 2.0, 2.0, 2.0),
+            image_data
+        );
+        assert_eq!(render_context.data()[8], 0xff_ff_00_00);
+        assert_eq!(render_context.data()[13], 0xff_00_00_ff);
+    }
+
+    #[test]
+    fn test_auto_clear() {
+        let mut render_context = RenderContext2D::new(10.0, 10.0);
+        render_context.set_background(Color::rgb(255, 255, 255));
+        render_context.start();
+        assert_eq!(render_context.data()[0], 0xff_ff_ff_ff);
+
+        render_context.set_fill_style("#ff0000");
+        render_context.fill_rect(0.0, 0.0, 5.0, 5.0);
+        render_context.set_auto_clear(false);
+        render_context.start();
+        assert_eq!(render_context.data()[0], 0xff_ff_00_00);
+        assert_eq!(render_context.data()[99], 0xff_ff_ff_ff);
+
+        render_context.set_auto_clear(true);
+        render_context.set_clear_transparent(true);
+        render_context.start();
+        assert!(render_context.data().iter().all(|pixel| *pixel == 0));
     }
 }
@@ -9,7 +9,8 @@ use stdweb::{
 
 // pub use crate::image::Image as InnerImage;
 use crate::{
-    common::*, utils::*, FontConfig, PipelineTrait, RenderConfig, RenderTarget, TextMetrics,
+    common::*, utils::*, FontConfig, PipelineTrait, RenderConfig, RenderStats, RenderTarget,
+    TextMetrics,
 };
 
 pub use self::image::*;
@@ -26,6 +27,9 @@ pub struct RenderContext2D {
     saved_state: SmallVec<StatesOnStack>,
     path_rect: PathRect,
     export_data: Vec<u32>,
+    stats: RenderStats,
+    auto_clear: bool,
+    clear_transparent: bool,
     background: Color,
 }
 
@@ -52,6 +56,9 @@ impl RenderContext2D {
             font_config: FontConfig::default(),
             path_rect: PathRect::new(None),
             export_data,
+            stats: RenderStats::default(),
+            auto_clear: true,
+            clear_transparent: false,
             background: Color::default(),
         }
     }
@@ -61,6 +68,18 @@ impl RenderContext2D {
         self.background = background;
     }
 
+    /// Sets if the render context is cleared on the start of each frame (default `true`). If it is set to `false`
+    /// the new frame is drawn on top of the pixels of the previous frame.
+    pub fn set_auto_clear(&mut self, auto_clear: bool) {
+        self.auto_clear = auto_clear;
+    }
+
+    /// Sets if the render context is cleared to transparent instead of the background color on the start of
+    /// each frame (default `false`).
+    pub fn set_clear_transparent(&mut self, clear_transparent: bool) {
+        self.clear_transparent = clear_transparent;
+    }
+
     /// Creates a new render ctx 2d.
     pub fn from_context(canvas_render_context_2_d: CanvasRenderingContext2d) -> Self {
         let export_data = vec![
@@ -77,6 +96,9 @@ impl RenderContext2D {
             font_config: FontConfig::default(),
             path_rect: PathRect::new(None),
             export_data,
+            stats: RenderStats::default(),
+            auto_clear: true,
+            clear_transparent: false,
             background: Color::default(),
         }
     }
@@ -86,13 +108,21 @@ impl RenderContext2D {
     /// Draws a filled rectangle whose starting point is at the coordinates {x, y} with the
     /// specified width and height and whose style is determined by the fillStyle attribute.
     pub fn fill_rect(&mut self, x: f64, y: f64, width: f64, height: f64) {
-        self.fill_style(&self.config.fill_style);
-        self.canvas_render_context_2_d
-            .fill_rect(x, y, width, height);
+        self.stats.fill_rects += 1;
+
+        for (i, brush) in self.config.fill_style.layers().iter().enumerate() {
+            self.fill_style(brush);
+            self.apply_shadow(i == 0);
+            self.canvas_render_context_2_d
+                .fill_rect(x, y, width, height);
+        }
+
+        self.apply_shadow(false);
     }
 
     /// Draws a rectangle that is stroked (outlined) according to the current strokeStyle and other ctx settings.
     pub fn stroke_rect(&mut self, x: f64, y: f64, width: f64, height: f64) {
+        self.stats.strokes += 1;
         self.stroke_style(&self.config.fill_style);
         self.canvas_render_context_2_d
             .stroke_rect(x, y, width, height);
@@ -100,17 +130,48 @@ impl RenderContext2D {
 
     // Text
 
-    /// Draws (fills) a given text at the given (x, y) position.
+    /// Draws (fills) a given text at the given (x, y) position. The position is interpreted by the current
+    /// text align and text baseline.
     pub fn fill_text(&mut self, text: &str, x: f64, y: f64) {
+        self.stats.texts += 1;
         self.fill_style(&self.config.fill_style);
         self.canvas_render_context_2_d
-            .set_text_baseline(stdweb::web::TextBaseline::Middle);
-        self.canvas_render_context_2_d.fill_text(
-            text,
-            x,
-            y + self.font_config.font_size.ceil() / 2.0,
-            None,
-        );
+            .set_text_align(match self.config.text_align {
+                TextAlignment::Start => stdweb::web::TextAlign::Start,
+                TextAlignment::Left => stdweb::web::TextAlign::Left,
+                TextAlignment::Center => stdweb::web::TextAlign::Center,
+                TextAlignment::End => stdweb::web::TextAlign::End,
+                TextAlignment::Right => stdweb::web::TextAlign::Right,
+            });
+
+        // The top of the em square is emulated by the middle baseline to match the text position of the raqote backend.
+        let (baseline, y) = match self.config.text_baseline {
+            TextBaseline::Top => (
+                stdweb::web::TextBaseline::Middle,
+                y + self.font_config.font_size.ceil() / 2.0,
+            ),
+            TextBaseline::Hanging => (stdweb::web::TextBaseline::Hanging, y),
+            TextBaseline::Middle => (stdweb::web::TextBaseline::Middle, y),
+            TextBaseline::Alphabetic => (stdweb::web::TextBaseline::Alphabetic, y),
+            TextBaseline::Ideographic => (stdweb::web::TextBaseline::Ideographic, y),
+            TextBaseline::Bottom => (stdweb::web::TextBaseline::Bottom, y),
+        };
+
+        self.canvas_render_context_2_d.set_text_baseline(baseline);
+        self.canvas_render_context_2_d.fill_text(text, x, y, None);
+    }
+
+    /// Draws (fills) the given text at the given (x, y) position and wraps it into lines that fits into
+    /// `max_width`. Returns the height of all drawn lines.
+    pub fn fill_text_wrapped(&mut self, text: &str, x: f64, y: f64, max_width: f64) -> f64 {
+        let line_height = self.font_config.font_size.ceil();
+        let lines = wrap_text(text, max_width, |line| self.measure_text(line).width);
+
+        for (i, line) in lines.iter().enumerate() {
+            self.fill_text(line, x, y + i as f64 * line_height);
+        }
+
+        lines.len() as f64 * line_height
     }
 
     pub fn measure(
@@ -138,14 +199,25 @@ impl RenderContext2D {
 
     /// Fills the current or given path with the current file style.
     pub fn fill(&mut self) {
-        self.fill_style(&self.config.fill_style);
-        self.canvas_render_context_2_d.fill(FillRule::default());
+        self.stats.fills += 1;
+
+        for (i, brush) in self.config.fill_style.layers().iter().enumerate() {
+            self.fill_style(brush);
+            self.apply_shadow(i == 0);
+            self.canvas_render_context_2_d.fill(FillRule::default());
+        }
+
+        self.apply_shadow(false);
     }
 
     /// Strokes {outlines} the current or given path with the current stroke style.
     pub fn stroke(&mut self) {
-        self.stroke_style(&self.config.stroke_style);
-        self.canvas_render_context_2_d.stroke();
+        self.stats.strokes += 1;
+
+        for brush in self.config.stroke_style.layers() {
+            self.stroke_style(brush);
+            self.canvas_render_context_2_d.stroke();
+        }
     }
 
     /// Starts a new path by emptying the list of sub-paths. Call this when you want to create a new path.
@@ -175,6 +247,29 @@ impl RenderContext2D {
             .record_arc(x, y, radius, start_angle, end_angle);
     }
 
+    /// Adds an elliptical arc centered at (x, y) with the radii `radius_x` and `radius_y` to the path. The ellipse is
+    /// rotated by `rotation`, the arc starts at `start_angle` and ends clockwise at `end_angle`.
+    pub fn ellipse(
+        &mut self,
+        x: f64,
+        y: f64,
+        radius_x: f64,
+        radius_y: f64,
+        rotation: f64,
+        start_angle: f64,
+        end_angle: f64,
+    ) {
+        js!(
+            @{&self.canvas_render_context_2_d}.ellipse(
+                @{&x}, @{&y}, @{&radius_x}, @{&radius_y}, @{&rotation}, @{&start_angle}, @{&end_angle}
+            );
+        );
+
+        let (start, curves) =
+            ellipse_curves(x, y, radius_x, radius_y, rotation, start_angle, end_angle);
+        self.path_rect.record_ellipse(start, &curves);
+    }
+
     /// Begins a new sub-path at the point specified by the given {x, y} coordinates.
 
     pub fn move_to(&mut self, x: f64, y: f64) {
@@ -214,6 +309,7 @@ impl RenderContext2D {
 
     /// Draws the image.
     pub fn draw_image(&mut self, image: &Image, x: f64, y: f64) {
+        self.stats.images += 1;
         js!(
             var img = document.image_store.image(@{&image.source});
 
@@ -232,6 +328,7 @@ impl RenderContext2D {
 
     /// Draws the given part of the image.
     pub fn draw_image_with_clip(&mut self, image: &Image, clip: Rectangle, x: f64, y: f64) {
+        self.stats.images += 1;
         js!(
             var img = document.image_store.image(@{&image.source});
 
@@ -312,6 +409,70 @@ impl RenderContext2D {
         self.path_rect.record_clip();
     }
 
+    // Filters
+
+    /// Blurs the already drawn pixels of the given region with a gaussian blur of the standard deviation `radius`.
+    /// The region is given in canvas pixels. The canvas is redrawn with a blur filter into the region, so blurring
+    /// large regions every frame is expensive. A `radius` of `0.0` does nothing.
+    pub fn blur_region(&mut self, x: f64, y: f64, width: f64, height: f64, radius: f64) {
+        if radius <= 0.0 {
+            return;
+        }
+
+        js!(
+            var context = @{&self.canvas_render_context_2_d};
+            context.save();
+            context.setTransform(1, 0, 0, 1, 0, 0);
+            context.beginPath();
+            context.rect(@{&x}, @{&y}, @{&width}, @{&height});
+            context.clip();
+            context.filter = "blur(" + @{&radius} + "px)";
+            context.drawImage(context.canvas, 0, 0);
+            context.restore();
+        );
+    }
+
+    // Pixel manipulation
+
+    /// Returns the pixels of the given region as RGBA bytes. Each pixel is stored row by row as four bytes in
+    /// the order red, green, blue, alpha. The color channels are not premultiplied by alpha. The region is given
+    /// in canvas pixels, the current transform is not applied and pixels outside of the canvas are transparent
+    /// black.
+    pub fn get_image_data(&self, x: f64, y: f64, width: f64, height: f64) -> Vec<u8> {
+        if width < 1.0 || height < 1.0 {
+            return vec![];
+        }
+
+        let data: stdweb::web::TypedArray<u8> = js!(
+            var image_data = @{&self.canvas_render_context_2_d}.getImageData(@{x}, @{y}, @{width}, @{height});
+            return new Uint8Array(image_data.data.buffer);
+        )
+        .try_into()
+        .unwrap();
+
+        data.to_vec()
+    }
+
+    /// Replaces the pixels of the given region by the RGBA bytes of `data`, in the format returned by
+    /// `get_image_data`. The pixels are not blended with the drawn pixels, the current transform, clip and alpha
+    /// are not applied. Pixels outside of the canvas are skipped.
+    pub fn put_image_data(&mut self, data: &[u8], x: f64, y: f64, width: f64, height: f64) {
+        let (width, height) = (width.round() as u32, height.round() as u32);
+        let len = (width * height * 4) as usize;
+
+        if width == 0 || height == 0 || data.len() < len {
+            return;
+        }
+
+        let data = stdweb::web::TypedArray::<u8>::from(&data[..len]);
+
+        js!(
+            @{&self.canvas_render_context_2_d}.putImageData(
+                new ImageData(new Uint8ClampedArray(@{data}), @{width}, @{height}), @{x}, @{y}
+            );
+        );
+    }
+
     // Line styles
 
     /// Sets the thickness of lines.
@@ -320,12 +481,64 @@ impl RenderContext2D {
         self.canvas_render_context_2_d.set_line_width(line_width);
     }
 
+    /// Sets the shape used to draw the end points of lines.
+    pub fn set_line_cap(&mut self, line_cap: LineCap) {
+        self.config.line_cap = line_cap;
+        self.canvas_render_context_2_d.set_line_cap(match line_cap {
+            LineCap::Butt => stdweb::web::LineCap::Butt,
+            LineCap::Round => stdweb::web::LineCap::Round,
+            LineCap::Square => stdweb::web::LineCap::Square,
+        });
+    }
+
+    /// Sets the shape used to join two line segments where they meet.
+    pub fn set_line_join(&mut self, line_join: LineJoin) {
+        self.config.line_join = line_join;
+        self.canvas_render_context_2_d
+            .set_line_join(match line_join {
+                LineJoin::Miter => stdweb::web::LineJoin::Miter,
+                LineJoin::Round => stdweb::web::LineJoin::Round,
+                LineJoin::Bevel => stdweb::web::LineJoin::Bevel,
+            });
+    }
+
+    /// Sets the alternating lengths of lines and gaps used to stroke dashed lines. An empty slice
+    /// resets to solid lines.
+    pub fn set_line_dash(&mut self, segments: &[f64]) {
+        self.config.line_dash = segments.to_vec();
+        self.canvas_render_context_2_d
+            .set_line_dash(segments.to_vec());
+    }
+
+    /// Sets the offset where the dash pattern of lines starts.
+    pub fn set_line_dash_offset(&mut self, offset: f64) {
+        self.config.line_dash_offset = offset;
+        self.canvas_render_context_2_d.set_line_dash_offset(offset);
+    }
+
     /// Sets the alpha value,
     pub fn set_alpha(&mut self, alpha: f32) {
+        self.config.alpha = alpha;
         self.canvas_render_context_2_d
             .set_global_alpha(alpha as f64);
     }
 
+    /// Gets the current alpha value.
+    pub fn alpha(&self) -> f32 {
+        self.config.alpha
+    }
+
+    /// Sets how shapes, images and text are combined with the already drawn pixels.
+    pub fn set_global_composite_operation(&mut self, composite_operation: CompositeOperation) {
+        self.config.composite_operation = composite_operation;
+        js!(@{&self.canvas_render_context_2_d}.globalCompositeOperation = @{composite_operation.as_str()};);
+    }
+
+    /// Gets the current composite operation.
+    pub fn global_composite_operation(&self) -> CompositeOperation {
+        self.config.composite_operation
+    }
+
     /// Specific the font family.
     pub fn set_font_family(&mut self, family: impl Into<String>) {
         self.font_config.family = family.into();
@@ -340,6 +553,16 @@ impl RenderContext2D {
             .set_font(&self.font_config.to_string());
     }
 
+    /// Specifies the horizontal alignment of text relative to the x position of `fill_text`.
+    pub fn set_text_align(&mut self, text_align: TextAlignment) {
+        self.config.text_align = text_align;
+    }
+
+    /// Specifies the vertical alignment of text relative to the y position of `fill_text`.
+    pub fn set_text_baseline(&mut self, text_baseline: TextBaseline) {
+        self.config.text_baseline = text_baseline;
+    }
+
     // Fill and stroke style
 
     /// Specifies the fill color to use inside shapes.
@@ -352,6 +575,41 @@ impl RenderContext2D {
         self.config.stroke_style = stroke_style.into();
     }
 
+    // Shadows
+
+    /// Sets a drop shadow that is drawn below the shapes of `fill` and `fill_rect`. The offset is given in
+    /// canvas pixels and is not affected by the current transform, `blur` is the standard deviation of the
+    /// gaussian blur. A transparent `color` disables the shadow.
+    pub fn set_shadow(&mut self, offset_x: f64, offset_y: f64, blur: f64, color: Color) {
+        self.config.shadow = BoxShadow::new((offset_x, offset_y), blur.max(0.0), 0.0, color);
+    }
+
+    /// Removes the drop shadow.
+    pub fn clear_shadow(&mut self) {
+        self.config.shadow = BoxShadow::default();
+    }
+
+    // Applies the shadow of the config to the canvas, only the bottom layer of a stacked brush gets a shadow.
+    fn apply_shadow(&self, enabled: bool) {
+        let shadow = self.config.shadow;
+
+        if !enabled || shadow.is_transparent() {
+            self.canvas_render_context_2_d
+                .set_shadow_color("rgba(0, 0, 0, 0)");
+            return;
+        }
+
+        // the canvas shadow blur is twice the standard deviation
+        self.canvas_render_context_2_d
+            .set_shadow_color(&shadow.color.to_string());
+        self.canvas_render_context_2_d
+            .set_shadow_blur(shadow.blur * 2.0);
+        self.canvas_render_context_2_d
+            .set_shadow_offset_x(shadow.offset.x());
+        self.canvas_render_context_2_d
+            .set_shadow_offset_y(shadow.offset.y());
+    }
+
     // Transformations
 
     /// Sets the transformation.
@@ -469,9 +727,30 @@ impl RenderContext2D {
         &self.export_data
     }
 
+    /// Returns the counted draw operations since the start of the current frame.
+    pub fn render_stats(&self) -> RenderStats {
+        self.stats
+    }
+
     pub fn start(&mut self) {
-        let background = Brush::from(self.background);
-        self.clear(&background)
+        self.stats = RenderStats::default();
+
+        if !self.auto_clear {
+            return;
+        }
+
+        if self.clear_transparent {
+            let canvas = self.canvas_render_context_2_d.get_canvas();
+            self.canvas_render_context_2_d.clear_rect(
+                0.0,
+                0.0,
+                canvas.width() as f64,
+                canvas.height() as f64,
+            );
+        } else {
+            let background = Brush::from(self.background);
+            self.clear(&background)
+        }
     }
     pub fn finish(&mut self) {}
 
@@ -495,8 +774,27 @@ impl RenderContext2D {
                         self.canvas_render_context_2_d
                             .set_fill_style_gradient(&web_gradient);
                     }
+                    GradientKind::Radial(radial) => {
+                        let web_gradient = self.radial_gradient(&radial, &gradient.stops, frame);
+                        self.canvas_render_context_2_d
+                            .set_fill_style_gradient(&web_gradient);
+                    }
                 }
             }
+            Brush::Pattern {
+                image,
+                repeat_x,
+                repeat_y,
+            } => {
+                let frame = match self.path_rect.get_rect() {
+                    Some(frame) => frame,
+                    None => return, // There are no path to fill
+                };
+                let pattern = self.pattern(image, *repeat_x, *repeat_y, frame);
+                js!(@{&self.canvas_render_context_2_d}.fillStyle = @{pattern};);
+            }
+            // the layers of stacked brushes are drawn one by one, see `Brush::layers`
+            Brush::Stacked(_) => {}
         }
     }
 
@@ -517,107 +815,114 @@ impl RenderContext2D {
                         self.canvas_render_context_2_d
                             .set_stroke_style_gradient(&web_gradient);
                     }
+                    GradientKind::Radial(radial) => {
+                        let web_gradient = self.radial_gradient(&radial, &gradient.stops, frame);
+                        self.canvas_render_context_2_d
+                            .set_stroke_style_gradient(&web_gradient);
+                    }
                 }
             }
+            Brush::Pattern {
+                image,
+                repeat_x,
+                repeat_y,
+            } => {
+                let frame = match self.path_rect.get_rect() {
+                    Some(frame) => frame,
+                    None => return, // There are no path to fill
+                };
+                let pattern = self.pattern(image, *repeat_x, *repeat_y, frame);
+                js!(@{&self.canvas_render_context_2_d}.strokeStyle = @{pattern};);
+            }
+            // the layers of stacked brushes are drawn one by one, see `Brush::layers`
+            Brush::Stacked(_) => {}
         }
     }
 
+    // Creates a canvas pattern of the given image, which starts at the top left corner of the frame.
+    fn pattern(
+        &self,
+        image: &ImageHandle,
+        repeat_x: bool,
+        repeat_y: bool,
+        frame: Rectangle,
+    ) -> stdweb::Value {
+        let repetition = match (repeat_x, repeat_y) {
+            (true, true) => "repeat",
+            (true, false) => "repeat-x",
+            (false, true) => "repeat-y",
+            (false, false) => "no-repeat",
+        };
+
+        // converts ARGB pixels to the RGBA bytes of the canvas
+        let data: Vec<u8> = image
+            .data()
+            .iter()
+            .flat_map(|pixel| {
+                let bytes = pixel.to_be_bytes();
+                vec![bytes[1], bytes[2], bytes[3], bytes[0]]
+            })
+            .collect();
+        let data = stdweb::web::TypedArray::<u8>::from(data.as_slice());
+
+        js!(
+            var canvas = document.createElement("canvas");
+            canvas.width = @{image.width()};
+            canvas.height = @{image.height()};
+            canvas.getContext("2d").putImageData(
+                new ImageData(new Uint8ClampedArray(@{data}), @{image.width()}, @{image.height()}), 0, 0
+            );
+
+            var pattern = @{&self.canvas_render_context_2_d}.createPattern(canvas, @{repetition});
+            pattern.setTransform(new DOMMatrix().translate(@{frame.x()}, @{frame.y()}));
+            return pattern;
+        )
+    }
+
+    // Creates a radial gradient around its position on the frame that reaches the farthest corner of the frame.
+    fn radial_gradient(
+        &self,
+        radial: &RadialGradient,
+        stops: &[GradientStop],
+        frame: Rectangle,
+    ) -> CanvasGradient {
+        let (center, radius) = radial_gradient_circle(radial.pos, frame);
+
+        let web_gradient = self
+            .canvas_render_context_2_d
+            .create_radial_gradient(center.x(), center.y(), 0.0, center.x(), center.y(), radius)
+            .unwrap();
+
+        build_unit_percent_gradient(stops, radius, |p, c| {
+            web_gradient
+                .add_color_stop(p, c.to_string().as_str())
+                .unwrap();
+        });
+
+        web_gradient
+    }
+
     fn linear_gradient(
         &self,
         coords: &LinearGradientCoords,
         stops: &[GradientStop],
         frame: Rectangle,
     ) -> CanvasGradient {
-        match coords {
-            LinearGradientCoords::Ends { start, end } => {
-                let start = frame.position() + *start;
-                let end = frame.position() + *end;
-
-                let web_gradient = self.canvas_render_context_2_d.create_linear_gradient(
-                    start.x(),
-                    start.y(),
-                    end.x(),
-                    end.y(),
-                );
-
-                build_unit_percent_gradient(stops, end.distance(start), |p, c| {
-                    web_gradient
-                        .add_color_stop(p, c.to_string().as_str())
-                        .unwrap();
-                });
-
-                web_gradient
-            }
-            LinearGradientCoords::Angle {
-                angle,
-                displacement,
-            } => {
-                let z = linear_gradient_ends_from_angle(*angle, frame.size());
-                let disp = displacement.pixels(frame.size());
-                let start = frame.position() + frame.size() / 2.0 + -z + disp;
-                let end = frame.position() + frame.size() / 2.0 + z + disp;
-                let web_gradient = self.canvas_render_context_2_d.create_linear_gradient(
-                    start.x(),
-                    start.y(),
-                    end.x(),
-                    end.y(),
-                );
-
-                build_unit_percent_gradient(stops, end.distance(start), |p, c| {
-                    web_gradient
-                        .add_color_stop(p, c.to_string().as_str())
-                        .unwrap();
-                });
-
-                web_gradient
-            }
-            LinearGradientCoords::Angle {
-                angle,
-                displacement,
-            } => {
-                let z = linear_gradient_ends_from_angle(*angle, frame.size());
-                let disp = displacement.pixels(frame.size());
-                let start = frame.position() + frame.size() / 2.0 + -z + disp;
-                let end = frame.position() + frame.size() / 2.0 + z + disp;
-                let web_gradient = self.canvas_render_context_2_d.create_linear_gradient(
-                    start.x(),
-                    start.y(),
-                    end.x(),
-                    end.y(),
-                );
-
-                build_unit_percent_gradient(stops, end.distance(start), |p, c| {
-                    web_gradient
-                        .add_color_stop(p, c.to_string().as_str())
-                        .unwrap();
-                });
-
-                web_gradient
-            }
-            LinearGradientCoords::Direction {
-                direction,
-                displacement,
-            } => {
-                let disp = displacement.pixels(frame.size());
-                let (mut start, mut end) = direction.cross(frame.width(), frame.height());
-                start = start + frame.position() + disp;
-                end = end + frame.position() + disp;
-                let web_gradient = self.canvas_render_context_2_d.create_linear_gradient(
-                    start.x(),
-                    start.y(),
-                    end.x(),
-                    end.y(),
-                );
-
-                build_unit_percent_gradient(stops, end.distance(start), |p, c| {
-                    web_gradient
-                        .add_color_stop(p, c.to_string().as_str())
-                        .unwrap();
-                });
-
-                web_gradient
-            }
-        }
+        let (start, end) = linear_gradient_ends(coords, frame);
+        let web_gradient = self.canvas_render_context_2_d.create_linear_gradient(
+            start.x(),
+            start.y(),
+            end.x(),
+            end.y(),
+        );
+
+        build_unit_percent_gradient(stops, end.distance(start), |p, c| {
+            web_gradient
+                .add_color_stop(p, c.to_string().as_str())
+                .unwrap();
+        });
+
+        web_gradient
     }
 }
 
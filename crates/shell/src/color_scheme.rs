@@ -0,0 +1,52 @@
+//! This module contains elements to describe the light / dark preference of the system.
+
+/// Describes if the user prefers a light or a dark appearance.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ColorScheme {
+    Light,
+    Dark,
+
+    /// The preference could not be read from the platform.
+    Unknown,
+}
+
+impl Default for ColorScheme {
+    fn default() -> Self {
+        ColorScheme::Unknown
+    }
+}
+
+impl ColorScheme {
+    /// Reads the color scheme from the name of a desktop theme, e.g. the `GTK_THEME` variable `Adwaita:dark`.
+    /// Names with a dark variant are `Dark`, all other names `Light` and an empty name `Unknown`.
+    pub fn from_theme_name(name: &str) -> Self {
+        let name = name.trim().to_lowercase();
+
+        if name.is_empty() {
+            return ColorScheme::Unknown;
+        }
+
+        if name.ends_with(":dark") || name.ends_with("-dark") || name.ends_with("_dark") {
+            return ColorScheme::Dark;
+        }
+
+        ColorScheme::Light
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_theme_name() {
+        assert_eq!(
+            ColorScheme::from_theme_name("Adwaita:dark"),
+            ColorScheme::Dark
+        );
+        assert_eq!(ColorScheme::from_theme_name("Arc-Dark"), ColorScheme::Dark);
+        assert_eq!(ColorScheme::from_theme_name("Adwaita"), ColorScheme::Light);
+        assert_eq!(ColorScheme::from_theme_name("Darkly"), ColorScheme::Light);
+        assert_eq!(ColorScheme::from_theme_name(""), ColorScheme::Unknown);
+    }
+}
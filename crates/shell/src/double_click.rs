@@ -0,0 +1,102 @@
+//! This module contains the detection of double clicks.
+
+use std::time::Duration;
+
+use crate::{event::MouseButton, utils::Point};
+
+/// Detects double clicks from the button up events of the mouse.
+///
+/// Two clicks of the same button form a double click if the second click follows within `time` and
+/// lands within `distance` pixels of the first one. A third click starts a new double click.
+/// Times are given as duration since an arbitrary but fixed start, e.g. the creation of the window.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DoubleClick {
+    time: Duration,
+    distance: f64,
+    last_click: Option<(MouseButton, Point, Duration)>,
+}
+
+impl Default for DoubleClick {
+    fn default() -> Self {
+        DoubleClick::new(Duration::from_millis(400), 4.0)
+    }
+}
+
+impl DoubleClick {
+    /// Creates a new double click detection with the maximum time and distance between two clicks.
+    pub fn new(time: Duration, distance: f64) -> Self {
+        DoubleClick {
+            time,
+            distance,
+            last_click: None,
+        }
+    }
+
+    /// Gets the maximum time between two clicks.
+    pub fn time(&self) -> Duration {
+        self.time
+    }
+
+    /// Gets the maximum distance between two clicks.
+    pub fn distance(&self) -> f64 {
+        self.distance
+    }
+
+    /// Handles a click of the given button at the time `now`. Returns `true` if the click completes a double click.
+    pub fn click(&mut self, button: MouseButton, position: Point, now: Duration) -> bool {
+        if let Some((last_button, last_position, last_time)) = self.last_click.take() {
+            let in_time = now
+                .checked_sub(last_time)
+                .map_or(false, |elapsed| elapsed <= self.time);
+            let in_distance = (position.x() - last_position.x()).abs() <= self.distance
+                && (position.y() - last_position.y()).abs() <= self.distance;
+
+            if last_button == button && in_time && in_distance {
+                return true;
+            }
+        }
+
+        self.last_click = Some((button, position, now));
+        false
+    }
+
+    /// Forgets the last click e.g. if the window loses the focus.
+    pub fn reset(&mut self) {
+        self.last_click = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn test_double_click() {
+        let mut double_click = DoubleClick::default();
+        let position = Point::new(10.0, 10.0);
+
+        assert!(!double_click.click(MouseButton::Left, position, ms(1000)));
+        assert!(double_click.click(MouseButton::Left, position, ms(1400)));
+
+        // the third click starts a new double click
+        assert!(!double_click.click(MouseButton::Left, position, ms(1500)));
+
+        // too slow
+        assert!(!double_click.click(MouseButton::Left, position, ms(2000)));
+
+        // too far away
+        assert!(!double_click.click(MouseButton::Left, Point::new(15.0, 10.0), ms(2100)));
+        assert!(double_click.click(MouseButton::Left, Point::new(12.0, 13.0), ms(2200)));
+
+        // different buttons
+        assert!(!double_click.click(MouseButton::Left, position, ms(3000)));
+        assert!(!double_click.click(MouseButton::Right, position, ms(3100)));
+
+        double_click.reset();
+        assert!(!double_click.click(MouseButton::Right, position, ms(3200)));
+    }
+}
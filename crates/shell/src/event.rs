@@ -1,6 +1,6 @@
 //! This module contains elements to work with window events.
 
-use std::char;
+use std::{char, ops::BitOr};
 
 use orbtk_utils::Point;
 
@@ -47,6 +47,7 @@ pub enum Key {
     Down,
     Dot,
     Eight,
+    End,
     Enter,
     Escape,
     ExclamationMark,
@@ -77,6 +78,8 @@ pub enum Key {
     NumpadMultiply,
     NumpadSubtract,
     One,
+    PageDown,
+    PageUp,
     QuestionMark,
     Quote,
     Six,
@@ -388,6 +391,96 @@ pub enum ButtonState {
     Up,
 }
 
+/// Describes the set of modifier keys (Ctrl, Shift, Alt, Super) that are pressed.
+#[derive(Default, PartialEq, Eq, Clone, Copy, Debug, Hash)]
+pub struct Modifiers(u8);
+
+impl Modifiers {
+    /// No modifier is pressed.
+    pub const NONE: Modifiers = Modifiers(0);
+
+    /// The control key.
+    pub const CTRL: Modifiers = Modifiers(1);
+
+    /// One of the shift keys.
+    pub const SHIFT: Modifiers = Modifiers(1 << 1);
+
+    /// The alt key.
+    pub const ALT: Modifiers = Modifiers(1 << 2);
+
+    /// The super key (Windows / Command / Meta).
+    pub const SUPER: Modifiers = Modifiers(1 << 3);
+
+    /// Checks if no modifier is pressed.
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Checks if all modifiers of `other` are pressed.
+    pub fn contains(self, other: Modifiers) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Marks the given modifiers as pressed.
+    pub fn insert(&mut self, other: Modifiers) {
+        self.0 |= other.0;
+    }
+
+    /// Marks the given modifiers as released.
+    pub fn remove(&mut self, other: Modifiers) {
+        self.0 &= !other.0;
+    }
+
+    /// Inserts or removes the given modifiers.
+    pub fn set(&mut self, other: Modifiers, pressed: bool) {
+        if pressed {
+            self.insert(other);
+        } else {
+            self.remove(other);
+        }
+    }
+
+    /// Checks if the control key is pressed.
+    pub fn ctrl(self) -> bool {
+        self.contains(Modifiers::CTRL)
+    }
+
+    /// Checks if one of the shift keys is pressed.
+    pub fn shift(self) -> bool {
+        self.contains(Modifiers::SHIFT)
+    }
+
+    /// Checks if the alt key is pressed.
+    pub fn alt(self) -> bool {
+        self.contains(Modifiers::ALT)
+    }
+
+    /// Checks if the super key is pressed.
+    pub fn logo(self) -> bool {
+        self.contains(Modifiers::SUPER)
+    }
+
+    /// Updates the modifier state by a pressed or released key. Keys that are not modifiers are ignored.
+    pub fn update(&mut self, key: Key, pressed: bool) {
+        let modifier = match key {
+            Key::Control => Modifiers::CTRL,
+            Key::ShiftL | Key::ShiftR => Modifiers::SHIFT,
+            Key::Alt => Modifiers::ALT,
+            _ => return,
+        };
+
+        self.set(modifier, pressed);
+    }
+}
+
+impl BitOr for Modifiers {
+    type Output = Modifiers;
+
+    fn bitor(self, rhs: Modifiers) -> Modifiers {
+        Modifiers(self.0 | rhs.0)
+    }
+}
+
 /// Represents a mouse event.
 #[derive(PartialEq, Clone, Copy, Debug)]
 pub struct MouseEvent {
@@ -397,6 +490,8 @@ pub struct MouseEvent {
     pub position: Point,
     ///  The mouse button state
     pub state: ButtonState,
+    /// The modifier keys that are pressed during the event
+    pub modifiers: Modifiers,
 }
 
 /// Represents a keyboard key event.
@@ -408,4 +503,41 @@ pub struct KeyEvent {
     pub key: Key,
     /// The text string
     pub text: String,
+    /// `true` if the event is repeated because the key is held
+    pub repeat: bool,
+    /// The modifier keys that are pressed during the event
+    pub modifiers: Modifiers,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_modifiers() {
+        let mut modifiers = Modifiers::default();
+        assert!(modifiers.is_empty());
+
+        modifiers.update(Key::Control, true);
+        let event = KeyEvent {
+            state: ButtonState::Down,
+            key: Key::C(false),
+            text: String::from("c"),
+            repeat: false,
+            modifiers,
+        };
+        assert!(event.modifiers.ctrl());
+        assert!(!event.modifiers.shift());
+
+        modifiers.update(Key::ShiftL, true);
+        assert!(modifiers.contains(Modifiers::CTRL | Modifiers::SHIFT));
+
+        modifiers.update(Key::C(false), true);
+        assert_eq!(modifiers, Modifiers::CTRL | Modifiers::SHIFT);
+
+        modifiers.update(Key::Control, false);
+        modifiers.update(Key::ShiftL, false);
+        assert!(modifiers.is_empty());
+        assert!(!modifiers.ctrl());
+    }
 }
@@ -0,0 +1,76 @@
+//! This module contains the pausing of the frame loop while a window is inactive.
+
+/// Pauses the frames requested by animations while the window does not have the input focus, so continuously
+/// animating widgets do not keep the processor busy in the background.
+///
+/// Frames requested while the loop is paused are not lost, one frame is run once the window is focused again.
+/// The loop starts running, it is paused by the first event that reports the window as unfocused.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct FrameLoop {
+    paused: bool,
+    pending: bool,
+}
+
+impl FrameLoop {
+    /// Creates a new running frame loop.
+    pub fn new() -> Self {
+        FrameLoop::default()
+    }
+
+    /// Returns `true` if the loop is paused because the window is inactive.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Handles a focus change of the window. Returns `true` if frames were requested while the loop was paused
+    /// and one of them should be run now.
+    pub fn focus(&mut self, focused: bool) -> bool {
+        self.paused = !focused;
+
+        if self.paused {
+            return false;
+        }
+
+        let pending = self.pending;
+        self.pending = false;
+        pending
+    }
+
+    /// Requests a frame. Returns `true` if the frame should be run now or `false` if it is postponed until the
+    /// window is focused again.
+    pub fn request_frame(&mut self) -> bool {
+        if self.paused {
+            self.pending = true;
+            return false;
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_focus() {
+        let mut frame_loop = FrameLoop::new();
+        assert!(!frame_loop.is_paused());
+        assert!(frame_loop.request_frame());
+
+        // losing the focus pauses the loop
+        assert!(!frame_loop.focus(false));
+        assert!(frame_loop.is_paused());
+        assert!(!frame_loop.request_frame());
+        assert!(!frame_loop.request_frame());
+
+        // gaining the focus resumes the loop and runs one of the postponed frames
+        assert!(frame_loop.focus(true));
+        assert!(!frame_loop.is_paused());
+        assert!(frame_loop.request_frame());
+
+        // nothing is postponed if no frame is requested while the loop is paused
+        frame_loop.focus(false);
+        assert!(!frame_loop.focus(true));
+    }
+}
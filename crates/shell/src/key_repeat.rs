@@ -0,0 +1,168 @@
+//! This module contains the normalization of key repeats.
+
+use std::time::Duration;
+
+use crate::event::{ButtonState, KeyEvent};
+
+/// Generates repeated key down events while a key is held.
+///
+/// The first key down event of a key is forwarded, repeated key down events of the platform for the
+/// held key are dropped and replaced by repeats after `initial_delay` in the rate of `interval`.
+/// Times are given as duration since an arbitrary but fixed start, e.g. the creation of the window.
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeyRepeat {
+    initial_delay: Duration,
+    interval: Duration,
+    held: Option<(KeyEvent, Duration)>,
+}
+
+impl Default for KeyRepeat {
+    fn default() -> Self {
+        KeyRepeat::new(Duration::from_millis(500), Duration::from_millis(33))
+    }
+}
+
+impl KeyRepeat {
+    /// Creates a new key repeat with the delay until the first repeat and the interval between two repeats.
+    pub fn new(initial_delay: Duration, interval: Duration) -> Self {
+        KeyRepeat {
+            initial_delay,
+            // an interval of zero would never end repeating
+            interval: interval.max(Duration::from_millis(1)),
+            held: None,
+        }
+    }
+
+    /// Gets the delay until the first repeat.
+    pub fn initial_delay(&self) -> Duration {
+        self.initial_delay
+    }
+
+    /// Gets the interval between two repeats.
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Handles a key event of the platform at the time `now`. Returns the event that should be forwarded or
+    /// `None` if the event is a platform repeat of the held key.
+    pub fn key_event(&mut self, event: KeyEvent, now: Duration) -> Option<KeyEvent> {
+        let is_held = match &self.held {
+            Some((held, _)) => held.key == event.key,
+            None => false,
+        };
+
+        match event.state {
+            ButtonState::Down => {
+                if is_held {
+                    return None;
+                }
+
+                let event = KeyEvent {
+                    repeat: false,
+                    ..event
+                };
+                self.held = Some((event.clone(), now + self.initial_delay));
+                Some(event)
+            }
+            ButtonState::Up => {
+                if is_held {
+                    self.held = None;
+                }
+
+                Some(event)
+            }
+        }
+    }
+
+    /// Returns the repeated key down events of the held key that are due at the time `now`.
+    pub fn repeats(&mut self, now: Duration) -> Vec<KeyEvent> {
+        let mut repeats = vec![];
+
+        if let Some((event, next_repeat)) = &mut self.held {
+            while *next_repeat <= now {
+                repeats.push(KeyEvent {
+                    repeat: true,
+                    ..event.clone()
+                });
+                *next_repeat += self.interval;
+            }
+        }
+
+        repeats
+    }
+
+    /// Stops the repeating of the held key e.g. if the window loses the focus and the key up event is not received.
+    pub fn release(&mut self) {
+        self.held = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::{Key, Modifiers};
+
+    fn key_event(key: Key, state: ButtonState) -> KeyEvent {
+        KeyEvent {
+            state,
+            key,
+            text: String::default(),
+            repeat: false,
+            modifiers: Modifiers::default(),
+        }
+    }
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn test_held_key_repeats() {
+        let mut key_repeat = KeyRepeat::new(ms(500), ms(100));
+
+        let press = key_repeat
+            .key_event(key_event(Key::Down, ButtonState::Down), ms(0))
+            .unwrap();
+        assert!(!press.repeat);
+
+        // platform repeats are dropped
+        assert!(key_repeat
+            .key_event(key_event(Key::Down, ButtonState::Down), ms(50))
+            .is_none());
+
+        assert!(key_repeat.repeats(ms(499)).is_empty());
+
+        let repeats = key_repeat.repeats(ms(500));
+        assert_eq!(repeats.len(), 1);
+        assert!(repeats[0].repeat);
+        assert_eq!(repeats[0].key, Key::Down);
+        assert_eq!(repeats[0].state, ButtonState::Down);
+
+        assert_eq!(key_repeat.repeats(ms(799)).len(), 2);
+        assert_eq!(key_repeat.repeats(ms(800)).len(), 1);
+
+        let release = key_repeat
+            .key_event(key_event(Key::Down, ButtonState::Up), ms(850))
+            .unwrap();
+        assert_eq!(release.state, ButtonState::Up);
+        assert!(key_repeat.repeats(ms(2000)).is_empty());
+    }
+
+    #[test]
+    fn test_new_key_replaces_held_key() {
+        let mut key_repeat = KeyRepeat::new(ms(500), ms(100));
+        key_repeat.key_event(key_event(Key::Left, ButtonState::Down), ms(0));
+        assert!(key_repeat
+            .key_event(key_event(Key::Right, ButtonState::Down), ms(200))
+            .is_some());
+
+        // releasing the previous key does not stop the repeating of the held key
+        key_repeat.key_event(key_event(Key::Left, ButtonState::Up), ms(300));
+        let repeats = key_repeat.repeats(ms(700));
+        assert_eq!(repeats.len(), 1);
+        assert_eq!(repeats[0].key, Key::Right);
+
+        key_repeat.release();
+        assert!(key_repeat.repeats(ms(2000)).is_empty());
+    }
+}
@@ -27,8 +27,13 @@ runner.run()
 #[macro_use]
 extern crate lazy_static;
 
+pub mod color_scheme;
+pub mod double_click;
 pub mod event;
+pub mod frame_loop;
+pub mod key_repeat;
 pub mod prelude;
+pub mod size_limits;
 pub mod window_adapter;
 
 pub use orbtk_utils::prelude as utils;
@@ -62,6 +67,97 @@ pub enum WindowRequest {
 
     /// Request to change the title of the `Windows`.
     ChangeTitle(String),
+
+    /// Request to move the `Windows` with the mouse while the left mouse button is pressed.
+    DragWindow,
+
+    /// Request to resize the `Windows` on the given edge with the mouse while the left mouse button is pressed.
+    DragResizeWindow(ResizeDirection),
+
+    /// Request to change if the `Windows` is always on top of other windows. The orbclient and web shells
+    /// cannot change it after the window is created and ignore the request.
+    SetAlwaysOnTop(bool),
+
+    /// Request to show the `Windows` fullscreen or to restore its previous size and position.
+    SetFullscreen(bool),
+
+    /// Request to change the icon of the mouse cursor over the `Windows`.
+    SetCursor(CursorIcon),
+}
+
+/// Describes the icon of the mouse cursor.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum CursorIcon {
+    /// The default cursor of the platform, usually an arrow.
+    Default,
+
+    /// Indicates a link or a clickable widget, usually a hand.
+    Pointer,
+
+    /// Indicates text that could be selected or edited.
+    Text,
+
+    /// Indicates an edge that could be moved to the left and to the right.
+    EwResize,
+
+    /// Indicates an edge that could be moved up and down.
+    NsResize,
+
+    /// Indicates something that could be grabbed and dragged.
+    Grab,
+}
+
+impl Default for CursorIcon {
+    fn default() -> Self {
+        CursorIcon::Default
+    }
+}
+
+/// Describes the edge or corner of a window that is used to resize it.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ResizeDirection {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl ResizeDirection {
+    /// Returns `true` if the direction moves the left edge of the window.
+    pub fn is_west(self) -> bool {
+        matches!(
+            self,
+            ResizeDirection::West | ResizeDirection::NorthWest | ResizeDirection::SouthWest
+        )
+    }
+
+    /// Returns `true` if the direction moves the right edge of the window.
+    pub fn is_east(self) -> bool {
+        matches!(
+            self,
+            ResizeDirection::East | ResizeDirection::NorthEast | ResizeDirection::SouthEast
+        )
+    }
+
+    /// Returns `true` if the direction moves the top edge of the window.
+    pub fn is_north(self) -> bool {
+        matches!(
+            self,
+            ResizeDirection::North | ResizeDirection::NorthEast | ResizeDirection::NorthWest
+        )
+    }
+
+    /// Returns `true` if the direction moves the bottom edge of the window.
+    pub fn is_south(self) -> bool {
+        matches!(
+            self,
+            ResizeDirection::South | ResizeDirection::SouthEast | ResizeDirection::SouthWest
+        )
+    }
 }
 
 /// Used to send a request to the application shell.
@@ -105,6 +201,12 @@ pub struct WindowSettings {
     /// The initial size of the window.
     pub size: (f64, f64),
 
+    /// The minimum size of the window, `None` if it is not limited.
+    pub min_size: Option<(f64, f64)>,
+
+    /// The maximum size of the window, `None` if it is not limited.
+    pub max_size: Option<(f64, f64)>,
+
     /// List of fonts to register.
     pub fonts: HashMap<String, &'static [u8]>,
 }
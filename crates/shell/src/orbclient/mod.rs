@@ -17,6 +17,14 @@ mod window_builder;
 /// Does nothing. This function is only use by the web backend.
 pub fn initialize() {}
 
+/// Reads the light / dark preference of the system from the `GTK_THEME` environment variable. Returns
+/// `ColorScheme::Unknown` if it is not set.
+pub fn system_color_scheme() -> ColorScheme {
+    std::env::var("GTK_THEME")
+        .map(|name| ColorScheme::from_theme_name(&name))
+        .unwrap_or_default()
+}
+
 /// Represents an application shell that could handle multiple windows.
 pub struct Shell<A: 'static>
 where
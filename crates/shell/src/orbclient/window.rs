@@ -1,17 +1,24 @@
-use std::sync::{
-    atomic::{AtomicBool, Ordering},
-    mpsc, Arc,
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Arc,
+    },
+    time::Instant,
 };
 
 #[cfg(not(target_os = "redox"))]
 use std::thread;
 
-use super::MouseState;
+use super::{MouseState, WindowState};
 use crate::{
-    event::{ButtonState, Key, KeyEvent, MouseButton, MouseEvent},
+    double_click::DoubleClick,
+    event::{ButtonState, Key, KeyEvent, Modifiers, MouseButton, MouseEvent},
+    frame_loop::FrameLoop,
+    key_repeat::KeyRepeat,
     render::RenderContext2D,
+    size_limits::SizeLimits,
     window_adapter::WindowAdapter,
-    WindowRequest,
+    CursorIcon, ResizeDirection, WindowRequest,
 };
 
 #[cfg(not(target_os = "redox"))]
@@ -34,14 +41,27 @@ where
     adapter: A,
     render_context: RenderContext2D,
     request_receiver: Option<mpsc::Receiver<WindowRequest>>,
-    //window_state: WindowState,
+    window_state: WindowState,
     mouse: MouseState,
+    window_drag: Option<(f32, f32)>,
+    window_resize: Option<(ResizeDirection, (f32, f32), (u32, u32))>,
+    key_repeat: KeyRepeat,
+    double_click: DoubleClick,
+    frame_loop: FrameLoop,
+    size_limits: SizeLimits,
+    // position and size of the window before it was shown fullscreen
+    windowed_bounds: Option<(i32, i32, u32, u32)>,
+    modifiers: Modifiers,
+    start: Instant,
     update: bool,
     redraw: Arc<AtomicBool>,
     close: bool,
     has_clipboard_update: bool,
     #[cfg(not(target_os = "redox"))]
     _sdl2_sync_thread: Option<thread::JoinHandle<()>>,
+    // the cursor has to be kept alive while it is shown
+    #[cfg(not(target_os = "redox"))]
+    cursor: Option<sdl2::mouse::Cursor>,
 }
 
 // internal method to sync if OrbClient backend is sdl2
@@ -87,8 +107,17 @@ where
             adapter,
             render_context,
             request_receiver,
-            // window_state: WindowState::default(),
+            window_state: WindowState::default(),
             mouse: MouseState::default(),
+            window_drag: None,
+            window_resize: None,
+            key_repeat: KeyRepeat::default(),
+            double_click: DoubleClick::default(),
+            frame_loop: FrameLoop::default(),
+            size_limits: SizeLimits::default(),
+            windowed_bounds: None,
+            modifiers: Modifiers::default(),
+            start: Instant::now(),
             update: true,
             redraw: Arc::new(AtomicBool::new(true)),
             close: false,
@@ -123,12 +152,22 @@ where
             render_context,
             _sdl2_sync_thread,
             request_receiver,
-            // window_state: WindowState::default(),
+            window_state: WindowState::default(),
             mouse: MouseState::default(),
+            window_drag: None,
+            window_resize: None,
+            key_repeat: KeyRepeat::default(),
+            double_click: DoubleClick::default(),
+            frame_loop: FrameLoop::default(),
+            size_limits: SizeLimits::default(),
+            windowed_bounds: None,
+            modifiers: Modifiers::default(),
+            start: Instant::now(),
             update: true,
             redraw,
             close: false,
             has_clipboard_update: true,
+            cursor: None,
         }
     }
 
@@ -163,6 +202,16 @@ where
         !self.close
     }
 
+    /// Check if the window has the input focus.
+    pub fn is_active(&self) -> bool {
+        self.window_state.active
+    }
+
+    /// Check if the frames requested by animations are paused, because the window does not have the input focus.
+    pub fn is_paused(&self) -> bool {
+        self.frame_loop.is_paused()
+    }
+
     fn push_mouse_event(&mut self, pressed: bool, button: MouseButton) {
         let state = if pressed {
             ButtonState::Down
@@ -170,11 +219,22 @@ where
             ButtonState::Up
         };
 
-        self.adapter.mouse_event(MouseEvent {
+        let event = MouseEvent {
             position: Point::new(self.mouse.mouse_pos.0 as f64, self.mouse.mouse_pos.1 as f64),
             button,
             state,
-        });
+            modifiers: self.modifiers,
+        };
+
+        self.adapter.mouse_event(event);
+
+        if !pressed
+            && self
+                .double_click
+                .click(button, event.position, self.start.elapsed())
+        {
+            self.adapter.double_click(event);
+        }
     }
 
     fn push_key_event(&mut self, key_event: orbclient::KeyEvent) {
@@ -201,6 +261,7 @@ where
                     orbclient::K_CTRL => key = Key::Control,
                     orbclient::K_DEL => key = Key::Delete,
                     orbclient::K_DOWN => key = Key::Down,
+                    orbclient::K_END => key = Key::End,
                     orbclient::K_ENTER => key = Key::Enter,
                     orbclient::K_ESC => key = Key::Escape,
                     orbclient::K_HOME => {
@@ -208,6 +269,8 @@ where
                     }
                     orbclient::K_LEFT => key = Key::Left,
                     orbclient::K_LEFT_SHIFT => key = Key::ShiftL,
+                    orbclient::K_PGDN => key = Key::PageDown,
+                    orbclient::K_PGUP => key = Key::PageUp,
                     orbclient::K_RIGHT => key = Key::Right,
                     orbclient::K_RIGHT_SHIFT => key = Key::ShiftR,
                     orbclient::K_TAB => key = Key::Tab,
@@ -218,7 +281,135 @@ where
             }
         };
 
-        self.adapter.key_event(KeyEvent { key, text, state });
+        self.modifiers.update(key, key_event.pressed);
+
+        let key_event = KeyEvent {
+            key,
+            text,
+            state,
+            repeat: false,
+            modifiers: self.modifiers,
+        };
+
+        if let Some(key_event) = self.key_repeat.key_event(key_event, self.start.elapsed()) {
+            self.adapter.key_event(key_event);
+        }
+    }
+
+    /// Sets the key repeat that generates the repeated key events of held keys.
+    pub fn set_key_repeat(&mut self, key_repeat: KeyRepeat) {
+        self.key_repeat = key_repeat;
+    }
+
+    /// Sets the maximum time and distance between the two clicks of a double click.
+    pub fn set_double_click(&mut self, double_click: DoubleClick) {
+        self.double_click = double_click;
+    }
+
+    /// Sets the minimum and maximum size the window can be resized to.
+    pub fn set_size_limits(&mut self, size_limits: SizeLimits) {
+        self.size_limits = size_limits;
+    }
+
+    // Resizes the window on the edges given by direction, the opposite edges keep their position.
+    fn resize_by_mouse(
+        &mut self,
+        direction: ResizeDirection,
+        start: (f32, f32),
+        start_size: (u32, u32),
+        mouse_x: i32,
+        mouse_y: i32,
+    ) {
+        let (mut x, mut y) = (self.window.x(), self.window.y());
+        let (mut width, mut height) = (self.window.width() as i32, self.window.height() as i32);
+
+        // east and south edges are relative to the unchanged window position
+        if direction.is_east() {
+            width = start_size.0 as i32 + mouse_x - start.0 as i32;
+        }
+
+        if direction.is_south() {
+            height = start_size.1 as i32 + mouse_y - start.1 as i32;
+        }
+
+        // west and north edges move the window, the mouse keeps its position relative to the window
+        if direction.is_west() {
+            let delta = (mouse_x - start.0 as i32).min(width - 1);
+            x += delta;
+            width -= delta;
+        }
+
+        if direction.is_north() {
+            let delta = (mouse_y - start.1 as i32).min(height - 1);
+            y += delta;
+            height -= delta;
+        }
+
+        // west and north edges are moved back if the size is clamped to the size limits
+        let (clamped_width, clamped_height) = self.size_limits.clamp(width as f64, height as f64);
+        let (clamped_width, clamped_height) = (clamped_width as i32, clamped_height as i32);
+
+        if direction.is_west() {
+            x += width - clamped_width;
+        }
+
+        if direction.is_north() {
+            y += height - clamped_height;
+        }
+
+        self.window.set_pos(x, y);
+        self.window
+            .set_size(clamped_width.max(1) as u32, clamped_height.max(1) as u32);
+    }
+
+    // Covers the whole display with the window or restores its previous position and size. orbclient sets the
+    // window flags only on creation of the window, so the decorations of the window are kept.
+    fn set_fullscreen(&mut self, fullscreen: bool) {
+        if fullscreen == self.windowed_bounds.is_some() {
+            return;
+        }
+
+        if fullscreen {
+            let (width, height) = match orbclient::get_display_size() {
+                Ok(size) => size,
+                Err(_) => return,
+            };
+
+            self.windowed_bounds = Some((
+                self.window.x(),
+                self.window.y(),
+                self.window.width(),
+                self.window.height(),
+            ));
+            self.window.set_pos(0, 0);
+            self.window.set_size(width, height);
+        } else if let Some((x, y, width, height)) = self.windowed_bounds.take() {
+            self.window.set_pos(x, y);
+            self.window.set_size(width, height);
+        }
+    }
+
+    #[cfg(target_os = "redox")]
+    fn set_cursor(&mut self, _cursor_icon: CursorIcon) {
+        // orbital does not provide cursor icons, the request is ignored.
+    }
+
+    #[cfg(not(target_os = "redox"))]
+    fn set_cursor(&mut self, cursor_icon: CursorIcon) {
+        let system_cursor = match cursor_icon {
+            CursorIcon::Default => sdl2::mouse::SystemCursor::Arrow,
+            CursorIcon::Pointer => sdl2::mouse::SystemCursor::Hand,
+            CursorIcon::Text => sdl2::mouse::SystemCursor::IBeam,
+            CursorIcon::EwResize => sdl2::mouse::SystemCursor::SizeWE,
+            CursorIcon::NsResize => sdl2::mouse::SystemCursor::SizeNS,
+            // sdl2 has no grab cursor
+            CursorIcon::Grab => sdl2::mouse::SystemCursor::Hand,
+        };
+
+        if let Ok(cursor) = sdl2::mouse::Cursor::from_system(system_cursor) {
+            cursor.set();
+            self.cursor = Some(cursor);
+        }
     }
 
     /// Updates the clipboard.
@@ -250,7 +441,23 @@ where
                     self.update = true;
                 }
                 orbclient::EventOption::Mouse(event) => {
+                    // the position is kept up to date while the window is dragged or resized, the release of
+                    // the mouse button that ends it is sent with the current position
                     self.mouse.mouse_pos = (event.x as f32, event.y as f32);
+
+                    if let Some(start) = self.window_drag {
+                        // moves the window, the mouse keeps its position relative to the window
+                        let x = self.window.x() + event.x - start.0 as i32;
+                        let y = self.window.y() + event.y - start.1 as i32;
+                        self.window.set_pos(x, y);
+                        continue;
+                    }
+
+                    if let Some((direction, start, start_size)) = self.window_resize {
+                        self.resize_by_mouse(direction, start, start_size, event.x, event.y);
+                        continue;
+                    }
+
                     self.adapter.mouse(event.x as f64, event.y as f64);
                     self.update = true;
                 }
@@ -259,6 +466,11 @@ where
                     self.has_clipboard_update = true;
                 }
                 orbclient::EventOption::Button(event) => {
+                    if !event.left {
+                        self.window_drag = None;
+                        self.window_resize = None;
+                    }
+
                     if event.left != self.mouse.button_left {
                         if event.left {
                             self.push_mouse_event(true, MouseButton::Left);
@@ -294,15 +506,44 @@ where
                     self.update = true;
                 }
                 orbclient::EventOption::Quit(_) => {
-                    self.close = true;
+                    self.adapter.quit_event();
                     self.update = true
                 }
-                orbclient::EventOption::Focus(_) => {}
+                orbclient::EventOption::Focus(event) => {
+                    if self.frame_loop.focus(event.focused) {
+                        self.update = true;
+                    }
+
+                    if event.focused != self.window_state.active {
+                        if !event.focused {
+                            // releases of modifiers are not received while the window is inactive
+                            self.key_repeat.release();
+                            self.double_click.reset();
+                            self.modifiers = Modifiers::default();
+                        }
+
+                        self.window_state.active = event.focused;
+                        self.adapter.active(event.focused);
+                        self.update = true;
+                    }
+                }
                 orbclient::EventOption::Move(_) => {}
                 orbclient::EventOption::Resize(event) => {
-                    self.adapter.resize(event.width as f64, event.height as f64);
-                    self.render_context
-                        .resize(event.width as f64, event.height as f64);
+                    // fullscreen windows cover the display regardless of the size limits
+                    let (width, height) = if self.windowed_bounds.is_some() {
+                        (event.width as f64, event.height as f64)
+                    } else {
+                        self.size_limits
+                            .clamp(event.width as f64, event.height as f64)
+                    };
+
+                    // the window manager does not know the size limits, so the window is resized back
+                    if (width as u32, height as u32) != (event.width, event.height) {
+                        self.window.set_size(width as u32, height as u32);
+                    }
+
+                    self.adapter.resize(width, height);
+                    self.render_context.resize(width, height);
                     self.update = true;
                     self.redraw.store(true, Ordering::Relaxed);
                 }
@@ -323,28 +564,67 @@ where
                 orbclient::EventOption::Hover(_) => {}
             }
         }
+
+        for key_event in self.key_repeat.repeats(self.start.elapsed()) {
+            self.adapter.key_event(key_event);
+            self.update = true;
+        }
     }
 
     /// Receives window request from the application and handles them.
     pub fn receive_requests(&mut self) {
-        if let Some(request_receiver) = &self.request_receiver {
-            for request in request_receiver.try_iter() {
-                match request {
-                    WindowRequest::Redraw => {
-                        if !self.update && !self.redraw.load(Ordering::Relaxed) {
-                            self.update = true;
-                            self.redraw.store(true, Ordering::Relaxed)
-                        }
-                    }
-                    WindowRequest::ChangeTitle(title) => {
-                        self.window.set_title(title.as_str());
+        // the requests are collected first, some of them are handled by methods that borrow the window mutably
+        let requests: Vec<WindowRequest> = match &self.request_receiver {
+            Some(request_receiver) => request_receiver.try_iter().collect(),
+            None => return,
+        };
+
+        for request in requests {
+            match request {
+                WindowRequest::Redraw => {
+                    // animations are paused while the window is inactive
+                    if self.frame_loop.request_frame()
+                        && !self.update
+                        && !self.redraw.load(Ordering::Relaxed)
+                    {
                         self.update = true;
                         self.redraw.store(true, Ordering::Relaxed)
                     }
-                    WindowRequest::Close => {
-                        self.close = true;
+                }
+                WindowRequest::ChangeTitle(title) => {
+                    self.window.set_title(title.as_str());
+                    self.update = true;
+                    self.redraw.store(true, Ordering::Relaxed)
+                }
+                WindowRequest::Close => {
+                    self.close = true;
+                }
+                WindowRequest::DragWindow => {
+                    if self.mouse.button_left {
+                        self.window_drag = Some(self.mouse.mouse_pos);
+                    }
+                }
+                WindowRequest::DragResizeWindow(direction) => {
+                    if self.mouse.button_left {
+                        self.window_resize = Some((
+                            direction,
+                            self.mouse.mouse_pos,
+                            (self.window.width(), self.window.height()),
+                        ));
                     }
                 }
+                WindowRequest::SetAlwaysOnTop(_) => {
+                    // orbclient sets the window flags only on creation of the window, so the
+                    // request is ignored until it provides a way to change them afterwards.
+                }
+                WindowRequest::SetCursor(cursor_icon) => {
+                    self.set_cursor(cursor_icon);
+                }
+                WindowRequest::SetFullscreen(fullscreen) => {
+                    self.set_fullscreen(fullscreen);
+                    self.update = true;
+                    self.redraw.store(true, Ordering::Relaxed)
+                }
             }
         }
     }
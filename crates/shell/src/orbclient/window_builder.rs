@@ -2,7 +2,8 @@ use std::{collections::HashMap, sync::mpsc};
 
 use super::{Shell, Window};
 use crate::{
-    render::RenderContext2D, utils::Rectangle, window_adapter::WindowAdapter, WindowRequest,
+    double_click::DoubleClick, key_repeat::KeyRepeat, render::RenderContext2D,
+    size_limits::SizeLimits, utils::Rectangle, window_adapter::WindowAdapter, WindowRequest,
     WindowSettings,
 };
 
@@ -19,7 +20,10 @@ where
     borderless: bool,
     fonts: HashMap<String, &'static [u8]>,
     bounds: Rectangle,
+    size_limits: SizeLimits,
     request_receiver: Option<mpsc::Receiver<WindowRequest>>,
+    key_repeat: KeyRepeat,
+    double_click: DoubleClick,
 }
 
 impl<'a, A> WindowBuilder<'a, A>
@@ -37,7 +41,10 @@ where
             borderless: false,
             fonts: HashMap::new(),
             bounds: Rectangle::new((0.0, 0.0), (100.0, 75.0)),
+            size_limits: SizeLimits::default(),
             request_receiver: None,
+            key_repeat: KeyRepeat::default(),
+            double_click: DoubleClick::default(),
         }
     }
 
@@ -52,7 +59,10 @@ where
             borderless: settings.borderless,
             fonts: settings.fonts,
             bounds: Rectangle::new(settings.position, (settings.size.0, settings.size.1)),
+            size_limits: SizeLimits::new(settings.min_size, settings.max_size),
             request_receiver: None,
+            key_repeat: KeyRepeat::default(),
+            double_click: DoubleClick::default(),
         }
     }
 
@@ -86,6 +96,18 @@ where
         self
     }
 
+    /// Sets the minimum size the window can be resized to.
+    pub fn min_size(mut self, width: f64, height: f64) -> Self {
+        self.size_limits.set_min_size(Some((width, height)));
+        self
+    }
+
+    /// Sets the maximum size the window can be resized to.
+    pub fn max_size(mut self, width: f64, height: f64) -> Self {
+        self.size_limits.set_max_size(Some((width, height)));
+        self
+    }
+
     /// Registers a new font with family key.
     pub fn font(mut self, family: impl Into<String>, font_file: &'static [u8]) -> Self {
         self.fonts.insert(family.into(), font_file);
@@ -98,8 +120,25 @@ where
         self
     }
 
+    /// Sets the delay and rate of the key events that are repeated while a key is held.
+    pub fn key_repeat(mut self, key_repeat: KeyRepeat) -> Self {
+        self.key_repeat = key_repeat;
+        self
+    }
+
+    /// Sets the maximum time and distance between the two clicks of a double click.
+    pub fn double_click(mut self, double_click: DoubleClick) -> Self {
+        self.double_click = double_click;
+        self
+    }
+
     /// Builds the window shell and add it to the application `Shell`.
-    pub fn build(self) {
+    pub fn build(mut self) {
+        let (width, height) = self
+            .size_limits
+            .clamp(self.bounds.width(), self.bounds.height());
+        self.bounds.set_size(width, height);
+
         let mut render_context = RenderContext2D::new(self.bounds.width(), self.bounds.height());
 
         let mut flags = vec![];
@@ -130,11 +169,11 @@ where
             render_context.register_font(&family, font);
         }
 
-        self.shell.window_shells.push(Window::new(
-            window,
-            self.adapter,
-            render_context,
-            self.request_receiver,
-        ));
+        let mut window = Window::new(window, self.adapter, render_context, self.request_receiver);
+        window.set_key_repeat(self.key_repeat);
+        window.set_double_click(self.double_click);
+        window.set_size_limits(self.size_limits);
+
+        self.shell.window_shells.push(window);
     }
 }
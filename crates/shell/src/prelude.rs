@@ -1,3 +1,5 @@
 pub use crate::{
-    event::*, platform::*, window_adapter::*, ShellRequest, WindowRequest, WindowSettings,
+    color_scheme::*, double_click::*, event::*, frame_loop::*, key_repeat::*, platform::*,
+    size_limits::*, window_adapter::*, CursorIcon, ResizeDirection, ShellRequest, WindowRequest,
+    WindowSettings,
 };
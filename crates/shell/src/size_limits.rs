@@ -0,0 +1,77 @@
+//! This module contains the size limits of windows.
+
+/// Describes the minimum and the maximum size of a window. Windows that are resized by the user are kept
+/// within the limits. By default the size of a window is not limited.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct SizeLimits {
+    min_size: Option<(f64, f64)>,
+    max_size: Option<(f64, f64)>,
+}
+
+impl SizeLimits {
+    /// Creates new size limits from the optional minimum and maximum size.
+    pub fn new(min_size: Option<(f64, f64)>, max_size: Option<(f64, f64)>) -> Self {
+        SizeLimits { min_size, max_size }
+    }
+
+    /// Gets the minimum size.
+    pub fn min_size(&self) -> Option<(f64, f64)> {
+        self.min_size
+    }
+
+    /// Sets the minimum size.
+    pub fn set_min_size(&mut self, min_size: Option<(f64, f64)>) {
+        self.min_size = min_size;
+    }
+
+    /// Gets the maximum size.
+    pub fn max_size(&self) -> Option<(f64, f64)> {
+        self.max_size
+    }
+
+    /// Sets the maximum size.
+    pub fn set_max_size(&mut self, max_size: Option<(f64, f64)>) {
+        self.max_size = max_size;
+    }
+
+    /// Returns the given size clamped to the limits. If the limits contradict each other the minimum size wins.
+    pub fn clamp(&self, width: f64, height: f64) -> (f64, f64) {
+        let (mut width, mut height) = (width, height);
+
+        if let Some((max_width, max_height)) = self.max_size {
+            width = width.min(max_width);
+            height = height.min(max_height);
+        }
+
+        if let Some((min_width, min_height)) = self.min_size {
+            width = width.max(min_width);
+            height = height.max(min_height);
+        }
+
+        (width, height)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clamp() {
+        let size_limits = SizeLimits::default();
+        assert_eq!(size_limits.clamp(1.0, 10000.0), (1.0, 10000.0));
+
+        // resizing below the minimum size
+        let mut size_limits = SizeLimits::new(Some((200.0, 100.0)), None);
+        assert_eq!(size_limits.clamp(150.0, 120.0), (200.0, 120.0));
+        assert_eq!(size_limits.clamp(10.0, 10.0), (200.0, 100.0));
+
+        // resizing above the maximum size
+        size_limits.set_max_size(Some((400.0, 300.0)));
+        assert_eq!(size_limits.clamp(500.0, 250.0), (400.0, 250.0));
+        assert_eq!(size_limits.clamp(300.0, 200.0), (300.0, 200.0));
+
+        size_limits.set_max_size(Some((100.0, 50.0)));
+        assert_eq!(size_limits.clamp(300.0, 200.0), (200.0, 100.0));
+    }
+}
@@ -1,6 +1,6 @@
 //! This module contains a platform specific implementation of the window shell.
 use std::sync::mpsc;
-use stdweb::web::window;
+use stdweb::{js, unstable::TryInto, web::window};
 
 use crate::prelude::*;
 
@@ -29,6 +29,21 @@ pub fn initialize() {
     stdweb::initialize();
 }
 
+/// Reads the light / dark preference of the browser by the `prefers-color-scheme` media query.
+pub fn system_color_scheme() -> ColorScheme {
+    let dark: bool = js!(
+        return window.matchMedia !== undefined && window.matchMedia("(prefers-color-scheme: dark)").matches;
+    )
+    .try_into()
+    .unwrap_or(false);
+
+    if dark {
+        return ColorScheme::Dark;
+    }
+
+    ColorScheme::Light
+}
+
 /// Represents an application shell that could handle multiple windows.
 pub struct Shell<A: 'static>
 where
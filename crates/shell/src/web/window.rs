@@ -1,4 +1,4 @@
-use std::sync::mpsc;
+use std::{sync::mpsc, time::Duration};
 
 use stdweb::{
     js,
@@ -9,10 +9,12 @@ use stdweb::{
 
 use super::EventState;
 use crate::{
-    event::{ButtonState, Key, KeyEvent, MouseButton, MouseEvent},
+    color_scheme::ColorScheme,
+    double_click::DoubleClick,
+    event::{ButtonState, Key, KeyEvent, Modifiers, MouseButton, MouseEvent},
     render::RenderContext2D,
     window_adapter::WindowAdapter,
-    WindowRequest,
+    CursorIcon, WindowRequest,
 };
 
 use orbtk_utils::Point;
@@ -29,6 +31,8 @@ where
     event_state: EventState,
     canvas: CanvasElement,
     old_canvas: Option<CanvasElement>,
+    color_scheme: ColorScheme,
+    double_click: DoubleClick,
     update: bool,
     redraw: bool,
     close: bool,
@@ -61,6 +65,8 @@ where
             event_state,
             canvas,
             old_canvas: None,
+            color_scheme: super::system_color_scheme(),
+            double_click: DoubleClick::default(),
             update: true,
             redraw: true,
             close: false,
@@ -109,16 +115,39 @@ where
                 position: Point::new(event.client_x() as f64, event.client_y() as f64),
                 button: get_mouse_button(event.button()),
                 state: ButtonState::Down,
+                modifiers: get_modifiers(
+                    event.ctrl_key(),
+                    event.shift_key(),
+                    event.alt_key(),
+                    event.meta_key(),
+                ),
             });
             self.update = true;
         }
 
         while let Some(event) = self.event_state.mouse_up_events.borrow_mut().pop() {
-            self.adapter.mouse_event(MouseEvent {
+            let event = MouseEvent {
                 position: Point::new(event.client_x() as f64, event.client_y() as f64),
                 button: get_mouse_button(event.button()),
                 state: ButtonState::Up,
-            });
+                modifiers: get_modifiers(
+                    event.ctrl_key(),
+                    event.shift_key(),
+                    event.alt_key(),
+                    event.meta_key(),
+                ),
+            };
+
+            self.adapter.mouse_event(event);
+
+            if self.double_click.click(
+                event.button,
+                event.position,
+                Duration::from_millis(stdweb::web::Date::now() as u64),
+            ) {
+                self.adapter.double_click(event);
+            }
+
             self.update = true;
         }
 
@@ -136,6 +165,12 @@ where
                 ),
                 button: MouseButton::Left,
                 state: ButtonState::Down,
+                modifiers: get_modifiers(
+                    event.ctrl_key(),
+                    event.shift_key(),
+                    event.alt_key(),
+                    event.meta_key(),
+                ),
             });
             self.update = true;
         }
@@ -148,6 +183,12 @@ where
                 ),
                 button: MouseButton::Left,
                 state: ButtonState::Up,
+                modifiers: get_modifiers(
+                    event.ctrl_key(),
+                    event.shift_key(),
+                    event.alt_key(),
+                    event.meta_key(),
+                ),
             });
 
             self.update = true;
@@ -170,6 +211,13 @@ where
                 key: key.0,
                 state: ButtonState::Down,
                 text: key.1,
+                repeat: event.repeat(),
+                modifiers: get_modifiers(
+                    event.ctrl_key(),
+                    event.shift_key(),
+                    event.alt_key(),
+                    event.meta_key(),
+                ),
             });
             self.update = true;
         }
@@ -181,6 +229,13 @@ where
                 key: key.0,
                 state: ButtonState::Up,
                 text: key.1,
+                repeat: false,
+                modifiers: get_modifiers(
+                    event.ctrl_key(),
+                    event.shift_key(),
+                    event.alt_key(),
+                    event.meta_key(),
+                ),
             });
             self.update = true;
         }
@@ -245,6 +300,14 @@ where
             self.canvas = canvas;
             self.update = true;
         }
+
+        let color_scheme = super::system_color_scheme();
+
+        if color_scheme != self.color_scheme {
+            self.color_scheme = color_scheme;
+            self.adapter.color_scheme_changed(color_scheme);
+            self.update = true;
+        }
     }
 
     /// Receives window request from the application and handles them.
@@ -264,6 +327,17 @@ where
                     WindowRequest::Close => {
                         self.close = true;
                     }
+                    WindowRequest::DragWindow => {}
+                    WindowRequest::DragResizeWindow(_) => {}
+                    // the browser decides about the order of its windows
+                    WindowRequest::SetAlwaysOnTop(_) => {}
+                    WindowRequest::SetFullscreen(_) => {}
+                    WindowRequest::SetCursor(cursor_icon) => {
+                        let cursor = css_cursor(cursor_icon);
+                        js! {
+                            document.body.style.cursor = @{cursor};
+                        }
+                    }
                 }
             }
         }
@@ -298,6 +372,18 @@ where
 
 // -- Helpers --
 
+// returns the css name of the given cursor icon
+fn css_cursor(cursor_icon: CursorIcon) -> &'static str {
+    match cursor_icon {
+        CursorIcon::Default => "default",
+        CursorIcon::Pointer => "pointer",
+        CursorIcon::Text => "text",
+        CursorIcon::EwResize => "ew-resize",
+        CursorIcon::NsResize => "ns-resize",
+        CursorIcon::Grab => "grab",
+    }
+}
+
 fn get_mouse_button(button: event::MouseButton) -> MouseButton {
     match button {
         event::MouseButton::Wheel => MouseButton::Middle,
@@ -306,6 +392,15 @@ fn get_mouse_button(button: event::MouseButton) -> MouseButton {
     }
 }
 
+fn get_modifiers(ctrl: bool, shift: bool, alt: bool, meta: bool) -> Modifiers {
+    let mut modifiers = Modifiers::default();
+    modifiers.set(Modifiers::CTRL, ctrl);
+    modifiers.set(Modifiers::SHIFT, shift);
+    modifiers.set(Modifiers::ALT, alt);
+    modifiers.set(Modifiers::SUPER, meta);
+    modifiers
+}
+
 fn get_key(code: &str, key: String) -> (Key, String) {
     let mut text = String::from("");
 
@@ -318,6 +413,9 @@ fn get_key(code: &str, key: String) -> (Key, String) {
         "ArrowDown" => Key::Down,
         "Backspace" => Key::Backspace,
         "CapsLock" => Key::CapsLock,
+        "End" => Key::End,
+        "PageDown" => Key::PageDown,
+        "PageUp" => Key::PageUp,
         _ => {
             text = key.clone();
             Key::from(key.chars().next().unwrap())
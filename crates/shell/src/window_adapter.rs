@@ -1,7 +1,7 @@
 //! This module contains traits to inject custom logic into the window shell.
 
 use crate::render::RenderContext2D;
-use crate::{event::*, utils::Point};
+use crate::{color_scheme::ColorScheme, event::*, utils::Point};
 
 /// The `WindowAdapter` represents the bridge to the `Shell` backend.
 /// It receives events from the `Window` and runs it's own logic.  
@@ -21,6 +21,9 @@ pub trait WindowAdapter {
     /// Is called after the state of a mouse button is changed.
     fn mouse_event(&mut self, _event: MouseEvent) {}
 
+    /// Is called after the mouse button up event that completes a double click.
+    fn double_click(&mut self, _event: MouseEvent) {}
+
     /// Is called if mouse wheel or trackpad detect scroll event.
     fn scroll(&mut self, _delta_x: f64, _delta_y: f64) {}
 
@@ -30,7 +33,8 @@ pub trait WindowAdapter {
     /// Is called when the keyboard emits an text input.
     fn text_input(&mut self, _text: String) {}
 
-    /// Is called after the quit event of the window is called.
+    /// Is called after the quit event of the window is called. The window is not closed
+    /// by the shell, the adapter has to send a `WindowRequest::Close` to close it.
     fn quit_event(&mut self) {}
 
     /// Gets the current mouse position.
@@ -39,6 +43,9 @@ pub trait WindowAdapter {
     /// Is called if active state of the window is changed.
     fn active(&mut self, active: bool);
 
+    /// Is called if the light / dark preference of the system is changed.
+    fn color_scheme_changed(&mut self, _color_scheme: ColorScheme) {}
+
     /// This method is called when a file is dropped on the window.
     fn file_drop_event(&mut self, file_name: String);
 
@@ -6,7 +6,8 @@ use serde_derive::{Deserialize, Serialize};
 use crate::State;
 
 /// Defines a style. A style could be base on other styles and contains a list for properties
-/// and a list of state properties.
+/// and a list of state properties. The transitions map defines the duration in milliseconds of the animation of
+/// a property after a state change.
 #[derive(Default, Clone, Debug, Serialize, Deserialize)]
 pub struct StyleConfig {
     // set default string to base style
@@ -16,4 +17,6 @@ pub struct StyleConfig {
     pub states: Vec<State>,
     #[serde(default)]
     pub properties: HashMap<String, Value>,
+    #[serde(default)]
+    pub transitions: HashMap<String, u64>,
 }
@@ -0,0 +1,86 @@
+use std::collections::HashMap;
+
+use ron::{de::from_str, Value};
+
+/// Contains the properties of a single widget that overrides the properties read from the theme.
+///
+/// An inline style could be read from a RON map like `{ "background": "#ff0000", "padding": 4 }` or from a CSS-like
+/// list like `background: #ff0000; padding: 4`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InlineStyle {
+    /// Represents the map of properties.
+    pub properties: HashMap<String, Value>,
+}
+
+impl InlineStyle {
+    /// Creates a new inline style without properties.
+    pub fn new() -> Self {
+        InlineStyle::default()
+    }
+
+    /// Returns `true` if the inline style has no properties.
+    pub fn is_empty(&self) -> bool {
+        self.properties.is_empty()
+    }
+}
+
+// reads a single value, values that are not valid RON are read as string
+fn read_value(value: &str) -> Value {
+    from_str(value).unwrap_or_else(|_| Value::String(value.to_string()))
+}
+
+impl From<&str> for InlineStyle {
+    fn from(s: &str) -> Self {
+        let s = s.trim();
+
+        if s.starts_with('{') {
+            return InlineStyle {
+                properties: from_str(s).unwrap_or_default(),
+            };
+        }
+
+        let mut properties = HashMap::new();
+
+        for declaration in s.split(';') {
+            let mut parts = declaration.splitn(2, ':');
+
+            if let (Some(key), Some(value)) = (parts.next(), parts.next()) {
+                let key = key.trim();
+
+                if !key.is_empty() {
+                    properties.insert(key.to_string(), read_value(value.trim()));
+                }
+            }
+        }
+
+        InlineStyle { properties }
+    }
+}
+
+impl From<String> for InlineStyle {
+    fn from(s: String) -> Self {
+        InlineStyle::from(s.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        let ron_style = InlineStyle::from(r##"{ "background": "#ff0000", "padding": 4.0 }"##);
+        let css_style = InlineStyle::from("background: #ff0000; padding: 4.0;");
+
+        assert_eq!(ron_style, css_style);
+        assert_eq!(
+            css_style.properties.get("background"),
+            Some(&Value::String("#ff0000".to_string()))
+        );
+        assert_eq!(
+            css_style.properties.get("padding"),
+            Some(&Value::Number(ron::Number::from(4.0)))
+        );
+        assert!(InlineStyle::from("").is_empty());
+    }
+}
@@ -1,11 +1,15 @@
+pub use self::inline_style::*;
 pub use self::selector::*;
 pub use self::state::*;
 pub use self::style::*;
 pub use self::theme::*;
+pub use self::transition::*;
 
 pub mod config;
+mod inline_style;
 pub mod prelude;
 mod selector;
 mod state;
 mod style;
 mod theme;
+mod transition;
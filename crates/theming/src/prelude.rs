@@ -1 +1 @@
-pub use crate::{Selector, Style, Theme};
+pub use crate::{InlineStyle, Selector, Style, Theme, Transition, TransitionValue, Transitions};
@@ -1,5 +1,7 @@
 use std::fmt;
 
+use crate::InlineStyle;
+
 /// The selector is used to read a property value from the `Theme`.
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct Selector {
@@ -11,6 +13,9 @@ pub struct Selector {
 
     /// Check if the selector is dirty.
     dirty: bool,
+
+    /// Represents the inline style of the widget. Its properties overrides the properties of the style.
+    pub inline_style: InlineStyle,
 }
 
 impl Selector {
@@ -20,6 +25,7 @@ impl Selector {
             style: Some(style.into()),
             states: vec![],
             dirty: true,
+            inline_style: InlineStyle::default(),
         }
     }
 
@@ -80,6 +86,12 @@ impl Selector {
         self.dirty = dirty;
     }
 
+    /// Sets the inline style from a RON map or a CSS-like list of properties, see `InlineStyle`.
+    pub fn set_inline_style(&mut self, inline_style: impl Into<InlineStyle>) {
+        self.inline_style = inline_style.into();
+        self.dirty = true;
+    }
+
     /// Check if the selector has the given state.
     pub fn has_state(&self, state: &str) -> bool {
         self.states.contains(&state.to_string())
@@ -12,6 +12,9 @@ pub struct Style {
 
     /// Represents the list of states.
     pub states: Vec<State>,
+
+    /// Represents the map of transition durations in milliseconds.
+    pub transitions: HashMap<String, u64>,
 }
 
 impl Style {
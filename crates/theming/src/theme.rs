@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use orbtk_utils::Color;
 use ron::Value;
 
 use crate::{
@@ -7,28 +8,74 @@ use crate::{
     Selector, State, Style,
 };
 
+// (style key, state key, property key) of a property that is read from a resource
+type ResourceRef = (String, Option<String>, String);
+
 /// Theme is used to read properties for a given selector with a internal state.
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct Theme {
     styles: HashMap<String, Style>,
     fonts: HashMap<String, &'static [u8]>,
+    resources: HashMap<String, Value>,
+    resource_refs: HashMap<ResourceRef, String>,
 }
 
 impl Theme {
     /// Creates a theme from the given config.
     pub fn from_config(config: ThemeConfig) -> Self {
         let mut styles = HashMap::new();
+        let mut resource_refs = HashMap::new();
 
         for style_key in config.styles.keys() {
-            Theme::read_config(style_key, style_key, &config, &mut styles)
+            Theme::read_config(
+                style_key,
+                style_key,
+                &config,
+                &mut styles,
+                &mut resource_refs,
+            )
         }
 
         Theme {
             styles,
             fonts: HashMap::new(),
+            resources: config.resources,
+            resource_refs,
+        }
+    }
+
+    /// Overrides the colors of the given resource keys. All style properties that are read from one of the resources
+    /// are patched with the new color, the other properties are unchanged. Use `Context::override_theme_colors` to
+    /// restyle the widgets of a window.
+    pub fn override_colors(&mut self, colors: HashMap<String, Color>) {
+        for (resource_key, color) in colors {
+            let value = Value::String(color.to_string());
+
+            for ((style_key, state_key, property_key), _) in self
+                .resource_refs
+                .iter()
+                .filter(|(_, key)| **key == resource_key)
+            {
+                if let Some(style) = self.styles.get_mut(style_key) {
+                    if let Some(state_key) = state_key {
+                        if let Some(state) = style.states.iter_mut().find(|s| s.key == *state_key) {
+                            state.properties.insert(property_key.clone(), value.clone());
+                        }
+                    } else {
+                        style.properties.insert(property_key.clone(), value.clone());
+                    }
+                }
+            }
+
+            self.resources.insert(resource_key, value);
         }
     }
 
+    /// Returns the map of resources.
+    pub fn resources(&self) -> &HashMap<String, Value> {
+        &self.resources
+    }
+
     /// Registers a new font file as binary.
     pub fn register_font(mut self, key: &str, font: &'static [u8]) -> Self {
         self.fonts.insert(key.to_string(), font);
@@ -50,31 +97,47 @@ impl Theme {
             return None;
         }
 
-        if let Some(style) = &selector.style {
-            let mut properties = HashMap::new();
+        if selector.style.is_none() && selector.inline_style.is_empty() {
+            return None;
+        }
 
-            if let Some(style) = self.styles.get(style) {
-                for (key, value) in &style.properties {
-                    properties.insert(key.clone(), value.clone());
-                }
+        let mut properties = HashMap::new();
 
-                // reverse order because last active state has highest priority
-                for state in style.states.iter().rev() {
-                    if selector.states().contains(&state.key) {
-                        for (key, value) in &state.properties {
-                            // properties of the selected state overrides default properties
-                            properties.insert(key.clone(), value.clone());
-                        }
+        if let Some(style) = selector.style.as_ref().and_then(|s| self.styles.get(s)) {
+            for (key, value) in &style.properties {
+                properties.insert(key.clone(), value.clone());
+            }
 
-                        break;
+            // reverse order because last active state has highest priority
+            for state in style.states.iter().rev() {
+                if selector.states().contains(&state.key) {
+                    for (key, value) in &state.properties {
+                        // properties of the selected state overrides default properties
+                        properties.insert(key.clone(), value.clone());
                     }
+
+                    break;
                 }
             }
+        }
 
-            return Some(properties);
+        // inline properties overrides the properties of the style and its states
+        for (key, value) in &selector.inline_style.properties {
+            properties.insert(key.clone(), value.clone());
         }
 
-        None
+        Some(properties)
+    }
+
+    /// Returns the transition duration in milliseconds of the given property if the style of the selector defines a
+    /// transition for it.
+    pub fn transition(&self, selector: &Selector, key: &str) -> Option<u64> {
+        selector
+            .style
+            .as_ref()
+            .and_then(|s| self.styles.get(s))
+            .and_then(|s| s.transitions.get(key))
+            .cloned()
     }
 
     // reads the given config and copy it's data in the given styles map
@@ -83,6 +146,7 @@ impl Theme {
         base_key: &str,
         config: &ThemeConfig,
         styles: &mut HashMap<String, Style>,
+        resource_refs: &mut HashMap<ResourceRef, String>,
     ) {
         if style_key.is_empty() {
             return;
@@ -95,7 +159,7 @@ impl Theme {
         // start from topmost base
         if let Some(style) = config.styles.get(base_key) {
             if !style.base.is_empty() && style.base != *style_key {
-                Theme::read_config(style_key, &style.base, config, styles);
+                Theme::read_config(style_key, &style.base, config, styles, resource_refs);
             }
         }
 
@@ -103,17 +167,42 @@ impl Theme {
             if let Some(style) = styles.get_mut(style_key) {
                 // reads the properties
                 for (property_key, property_value) in &style_config.properties {
+                    Theme::read_resource_ref(
+                        (style_key.to_string(), None, property_key.clone()),
+                        property_value,
+                        &config.resources,
+                        resource_refs,
+                    );
                     style.properties.insert(
                         property_key.clone(),
                         Theme::read_value(property_value, &config.resources),
                     );
                 }
 
+                // reads the transitions
+                for (property_key, duration) in &style_config.transitions {
+                    style.transitions.insert(property_key.clone(), *duration);
+                }
+
                 // reads the states
                 for state in &style_config.states {
                     let mut new_state = State::new(state.key.clone());
 
+                    // the state replaces a state with the same key of a higher base
+                    resource_refs
+                        .retain(|(s, st, _), _| s != style_key || st.as_ref() != Some(&state.key));
+
                     for (property_key, property_value) in &state.properties {
+                        Theme::read_resource_ref(
+                            (
+                                style_key.to_string(),
+                                Some(state.key.clone()),
+                                property_key.clone(),
+                            ),
+                            property_value,
+                            &config.resources,
+                            resource_refs,
+                        );
                         new_state.properties.insert(
                             property_key.clone(),
                             Theme::read_value(property_value, &config.resources),
@@ -132,6 +221,26 @@ impl Theme {
         }
     }
 
+    // remembers the resource key if the property value is a place holder, otherwise forgets the resource key of a
+    // property that is overridden by a lower base style
+    fn read_resource_ref(
+        key: ResourceRef,
+        property_value: &Value,
+        resources: &HashMap<String, Value>,
+        resource_refs: &mut HashMap<ResourceRef, String>,
+    ) {
+        if let Ok(value) = property_value.clone().into_rust::<String>() {
+            let resource_key = value.replace(RESOURCE_KEY, "");
+
+            if resources.contains_key(&resource_key) {
+                resource_refs.insert(key, resource_key);
+                return;
+            }
+        }
+
+        resource_refs.remove(&key);
+    }
+
     // if the property value is a place holder replace it with the corresponding value of the resources
     fn read_value(property_value: &Value, resources: &HashMap<String, Value>) -> Value {
         if let Ok(value) = property_value.clone().into_rust::<String>() {
@@ -143,3 +252,116 @@ impl Theme {
         property_value.clone()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const THEME: &str = r##"
+Theme (
+    styles: {
+        "base": (
+            properties: {
+                "foreground": "$foreground",
+            },
+        ),
+        "button": (
+            base: "base",
+            properties: {
+                "background": "$accent",
+            },
+            states: [
+                (
+                    key: "pressed",
+                    properties: {
+                        "background": "$pressed",
+                        "foreground": "$accent",
+                    },
+                ),
+            ],
+        ),
+        "text_box": (
+            base: "base",
+            properties: {
+                "border_brush": "$accent",
+                "foreground": "#000000",
+            },
+        ),
+    },
+    resources: {
+        "accent": "#5b0f22",
+        "foreground": "#dfebf5",
+        "pressed": "#c8ced3",
+    },
+)
+"##;
+
+    fn property(theme: &Theme, style: &str, state: Option<&str>, key: &str) -> String {
+        let mut selector = Selector::new(style);
+
+        if let Some(state) = state {
+            selector.push_state(state);
+        }
+
+        theme
+            .properties(&selector)
+            .unwrap()
+            .remove(key)
+            .unwrap()
+            .into_rust::<String>()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_override_colors() {
+        let mut theme = Theme::from_config(ThemeConfig::from(THEME));
+
+        let mut colors = HashMap::new();
+        colors.insert("accent".to_string(), Color::rgb(0, 0, 255));
+        theme.override_colors(colors);
+
+        assert_eq!(property(&theme, "button", None, "background"), "#0000ff");
+        assert_eq!(
+            property(&theme, "button", Some("pressed"), "foreground"),
+            "#0000ff"
+        );
+        assert_eq!(
+            property(&theme, "text_box", None, "border_brush"),
+            "#0000ff"
+        );
+        assert_eq!(
+            theme.resources().get("accent"),
+            Some(&Value::String("#0000ff".to_string()))
+        );
+
+        // properties that are not read from the accent resource are unchanged
+        assert_eq!(
+            property(&theme, "button", Some("pressed"), "background"),
+            "#c8ced3"
+        );
+        assert_eq!(property(&theme, "button", None, "foreground"), "#dfebf5");
+        assert_eq!(property(&theme, "text_box", None, "foreground"), "#000000");
+    }
+
+    #[test]
+    fn test_inline_style() {
+        let theme = Theme::from_config(ThemeConfig::from(THEME));
+
+        let mut selector = Selector::new("button");
+        selector.set_inline_style("background: #ff0000");
+        let mut properties = theme.properties(&selector).unwrap();
+
+        // the inline background wins over the theme, other properties are read from the theme
+        assert_eq!(
+            properties.remove("background"),
+            Some(Value::String("#ff0000".to_string()))
+        );
+        assert_eq!(
+            properties.remove("foreground"),
+            Some(Value::String("#dfebf5".to_string()))
+        );
+
+        // a widget without inline style is unchanged
+        assert_eq!(property(&theme, "button", None, "background"), "#5b0f22");
+    }
+}
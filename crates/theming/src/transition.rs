@@ -0,0 +1,221 @@
+use orbtk_utils::{Brush, Color};
+
+/// Describes the value of a styled property that could be animated by a transition.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TransitionValue {
+    /// Value of a brush property like `background`.
+    Brush(Brush),
+
+    /// Value of a number property like `opacity`.
+    Number(f64),
+}
+
+impl TransitionValue {
+    /// Interpolates between the value and the given target value. `progress` is a value between `0.0` and `1.0`.
+    ///
+    /// Only solid colors and numbers are interpolated, all other values switch to the target value at the half of
+    /// the transition.
+    pub fn interpolate(&self, to: &TransitionValue, progress: f64) -> TransitionValue {
+        let progress = progress.clamp(0.0, 1.0);
+
+        match (self, to) {
+            (
+                TransitionValue::Brush(Brush::SolidColor(from)),
+                TransitionValue::Brush(Brush::SolidColor(to)),
+            ) => {
+                TransitionValue::Brush(Brush::SolidColor(Color::interpolate(*from, *to, progress)))
+            }
+            (TransitionValue::Number(from), TransitionValue::Number(to)) => {
+                TransitionValue::Number(from + (to - from) * progress)
+            }
+            _ => {
+                if progress < 0.5 {
+                    self.clone()
+                } else {
+                    to.clone()
+                }
+            }
+        }
+    }
+}
+
+/// Animates a styled property from its previous resolved value to the new resolved value.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Transition {
+    /// Key of the animated property.
+    pub key: String,
+
+    from: TransitionValue,
+    to: TransitionValue,
+
+    // duration in milliseconds
+    duration: f64,
+
+    // time in milliseconds, is set by the first call of `value`
+    start: Option<f64>,
+}
+
+impl Transition {
+    /// Creates a new transition of the property with the given key and the duration in milliseconds.
+    pub fn new(
+        key: impl Into<String>,
+        from: TransitionValue,
+        to: TransitionValue,
+        duration: u64,
+    ) -> Self {
+        Transition {
+            key: key.into(),
+            from,
+            to,
+            duration: duration as f64,
+            start: None,
+        }
+    }
+
+    /// Returns the progress of the transition at the given time in milliseconds, as value between `0.0` and `1.0`.
+    pub fn progress(&self, time: f64) -> f64 {
+        let start = self.start.unwrap_or(time);
+
+        if self.duration <= 0.0 {
+            return 1.0;
+        }
+
+        ((time - start) / self.duration).clamp(0.0, 1.0)
+    }
+
+    /// Returns the interpolated value at the given time in milliseconds. The first call starts the transition.
+    pub fn value(&mut self, time: f64) -> TransitionValue {
+        if self.start.is_none() {
+            self.start = Some(time);
+        }
+
+        self.from.interpolate(&self.to, self.progress(time))
+    }
+
+    /// Returns `true` if the transition is finished at the given time in milliseconds.
+    pub fn is_finished(&self, time: f64) -> bool {
+        self.start.is_some() && self.progress(time) >= 1.0
+    }
+}
+
+/// Contains the running transitions of a widget.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Transitions {
+    transitions: Vec<Transition>,
+}
+
+impl Transitions {
+    /// Creates a new empty list of transitions.
+    pub fn new() -> Self {
+        Transitions::default()
+    }
+
+    /// Adds a transition. A running transition of the same property is replaced.
+    pub fn push(&mut self, transition: Transition) {
+        self.transitions.retain(|t| t.key != transition.key);
+        self.transitions.push(transition);
+    }
+
+    /// Returns `true` if there are no running transitions.
+    pub fn is_empty(&self) -> bool {
+        self.transitions.is_empty()
+    }
+
+    /// Returns the current values of all running transitions at the given time in milliseconds. Finished
+    /// transitions are removed after their target value is returned.
+    pub fn tick(&mut self, time: f64) -> Vec<(String, TransitionValue)> {
+        let values = self
+            .transitions
+            .iter_mut()
+            .map(|t| (t.key.clone(), t.value(time)))
+            .collect();
+
+        self.transitions.retain(|t| !t.is_finished(time));
+
+        values
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{config::ThemeConfig, Selector, Theme};
+
+    const THEME: &str = r##"
+Theme (
+    styles: {
+        "button": (
+            properties: {
+                "background": "#000000",
+            },
+            states: [
+                (
+                    key: "hover",
+                    properties: {
+                        "background": "#ffffff",
+                    },
+                ),
+            ],
+            transitions: {
+                "background": 100,
+            },
+        ),
+    },
+)
+"##;
+
+    fn background(theme: &Theme, selector: &Selector) -> TransitionValue {
+        let background = theme
+            .properties(selector)
+            .unwrap()
+            .remove("background")
+            .unwrap()
+            .into_rust::<String>()
+            .unwrap();
+
+        TransitionValue::Brush(Brush::from(background))
+    }
+
+    #[test]
+    fn test_hover_transition() {
+        let theme = Theme::from_config(ThemeConfig::from(THEME));
+
+        let mut selector = Selector::new("button");
+        let from = background(&theme, &selector);
+        selector.push_state("hover");
+        let to = background(&theme, &selector);
+
+        let duration = theme.transition(&selector, "background").unwrap();
+        assert_eq!(duration, 100);
+        assert_eq!(theme.transition(&selector, "foreground"), None);
+
+        let mut transitions = Transitions::new();
+        transitions.push(Transition::new(
+            "background",
+            from.clone(),
+            to.clone(),
+            duration,
+        ));
+
+        assert_eq!(
+            transitions.tick(1000.0),
+            vec![("background".to_string(), from)]
+        );
+
+        // half way the hovered widget shows an interpolated color
+        assert_eq!(
+            transitions.tick(1050.0),
+            vec![(
+                "background".to_string(),
+                TransitionValue::Brush(Brush::SolidColor(Color::rgb(127, 127, 127)))
+            )]
+        );
+        assert!(!transitions.is_empty());
+
+        assert_eq!(
+            transitions.tick(1100.0),
+            vec![("background".to_string(), to)]
+        );
+        assert!(transitions.is_empty());
+    }
+}
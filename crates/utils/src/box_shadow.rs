@@ -0,0 +1,114 @@
+use crate::{Color, Expression, Point, Value};
+
+/// Describes a shadow that is drawn around the box of a widget, like the CSS `box-shadow`.
+///
+/// A shadow could be parsed from a string with the structure
+///
+/// ```text
+/// {X Offset} {Y Offset} [{Blur} [{Spread}]] {Color}
+/// ```
+///
+/// e.g. `"2 2 4 #40000000"`.
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct BoxShadow {
+    /// Offset of the shadow relative to the box.
+    pub offset: Point,
+
+    /// Blur radius of the shadow.
+    pub blur: f64,
+
+    /// Distance the shadow is expanded (positive) or shrunk (negative) around the box.
+    pub spread: f64,
+
+    /// Color of the shadow.
+    pub color: Color,
+}
+
+impl BoxShadow {
+    /// Creates a new box shadow.
+    pub fn new(offset: impl Into<Point>, blur: f64, spread: f64, color: impl Into<Color>) -> Self {
+        BoxShadow {
+            offset: offset.into(),
+            blur,
+            spread,
+            color: color.into(),
+        }
+    }
+
+    /// Returns `true` if the shadow is not visible.
+    pub fn is_transparent(&self) -> bool {
+        self.color.a() == 0
+    }
+}
+
+impl From<&str> for BoxShadow {
+    fn from(s: &str) -> BoxShadow {
+        let mut numbers = vec![];
+        let mut color = String::new();
+
+        for part in s.split_whitespace() {
+            if color.is_empty() {
+                if let Ok(number) = part.trim_end_matches("px").parse::<f64>() {
+                    numbers.push(number);
+                    continue;
+                }
+            } else {
+                color.push(' ');
+            }
+
+            color.push_str(part);
+        }
+
+        if numbers.len() < 2 || numbers.len() > 4 {
+            return BoxShadow::default();
+        }
+
+        let color = match Expression::from(color.as_str()).color() {
+            Some(color) => color,
+            None => return BoxShadow::default(),
+        };
+
+        BoxShadow {
+            offset: Point::new(numbers[0], numbers[1]),
+            blur: numbers.get(2).cloned().unwrap_or_default(),
+            spread: numbers.get(3).cloned().unwrap_or_default(),
+            color,
+        }
+    }
+}
+
+impl From<String> for BoxShadow {
+    fn from(s: String) -> BoxShadow {
+        Self::from(&s[..])
+    }
+}
+
+impl From<Value> for BoxShadow {
+    fn from(v: Value) -> Self {
+        let value = v.get::<String>();
+        BoxShadow::from(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_from_str() {
+        let shadow = BoxShadow::from("2 3 4 #40000000");
+        assert_eq!(shadow.offset, Point::new(2.0, 3.0));
+        assert!((shadow.blur - 4.0).abs() < f64::EPSILON);
+        assert!(shadow.spread.abs() < f64::EPSILON);
+        assert_eq!(shadow.color.a(), 0x40);
+
+        let shadow = BoxShadow::from("-1px 1px 2px 3px rgba(255, 0, 0, 0.5)");
+        assert_eq!(shadow.offset, Point::new(-1.0, 1.0));
+        assert!((shadow.spread - 3.0).abs() < f64::EPSILON);
+        assert_eq!(shadow.color, Color::rgb(255, 0, 0));
+
+        assert!(BoxShadow::from("2 #000000").is_transparent());
+        assert!(BoxShadow::from("2 2 unknown").is_transparent());
+        assert!(BoxShadow::from("").is_transparent());
+    }
+}
@@ -8,6 +8,7 @@ use crate::prelude::*;
 /// * gradients of colors
 /// * gradients with directions
 /// * gradients with angles
+/// * repeated images (patterns), which can be created only in code
 ///
 /// The string declaration of a `Brush` is composed combining the following
 /// syntax elements:
@@ -133,15 +134,47 @@ pub enum Brush {
 
     /// Paints an area with a gradient.
     Gradient(Gradient),
+
+    /// Paints an area with an image, which is repeated horizontally and / or vertically. The image starts at the
+    /// top left corner of the painted area.
+    Pattern {
+        image: ImageHandle,
+        repeat_x: bool,
+        repeat_y: bool,
+    },
+
+    /// Paints an area with several brushes on top of each other. The first brush is the bottom layer.
+    Stacked(Vec<Brush>),
 }
 
 impl Brush {
     pub fn is_transparent(&self) -> bool {
         match self {
             Brush::SolidColor(color) => color.a() == 0,
+            Brush::Stacked(layers) => layers.iter().all(|layer| layer.is_transparent()),
             _ => false,
         }
     }
+
+    /// Creates a brush that paints an area with the given image, repeated in both directions.
+    pub fn pattern(image: impl Into<ImageHandle>) -> Self {
+        Brush::Pattern {
+            image: image.into(),
+            repeat_x: true,
+            repeat_y: true,
+        }
+    }
+
+    /// Returns the brushes that are painted from the bottom to the top layer. Nested stacks are flattened and a
+    /// brush that is not stacked is its own single layer. Patterns of an empty image have nothing to paint and
+    /// are skipped.
+    pub fn layers(&self) -> Vec<&Brush> {
+        match self {
+            Brush::Stacked(layers) => layers.iter().flat_map(|layer| layer.layers()).collect(),
+            Brush::Pattern { image, .. } if image.is_empty() => vec![],
+            _ => vec![self],
+        }
+    }
 }
 
 impl From<Brush> for Color {
@@ -194,13 +227,60 @@ impl From<String> for Brush {
 
 impl From<Value> for Brush {
     fn from(v: Value) -> Self {
-        let value = v.get::<String>();
-        Brush::from(value)
+        match v.try_brush() {
+            Ok(brush) => brush.unwrap_or_default(),
+            Err(error) => {
+                // a typo in a theme should not silently result in a wrong color
+                eprintln!("Could not parse the brush of a theme value: {}", error);
+                Brush::default()
+            }
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    //  use crate::prelude::*;
-    // todo: tbd after brush struct is finished
+    use crate::prelude::*;
+
+    #[test]
+    fn test_pattern_is_not_transparent() {
+        let image = ImageHandle::new(1, 1, vec![0]).unwrap();
+        assert!(!Brush::pattern(image.clone()).is_transparent());
+        assert!(!Brush::Pattern {
+            image,
+            repeat_x: false,
+            repeat_y: true
+        }
+        .is_transparent());
+    }
+
+    #[test]
+    fn test_empty_pattern_has_no_layers() {
+        let empty = Brush::pattern(ImageHandle::default());
+        assert!(empty.layers().is_empty());
+
+        let red = Brush::from(Color::rgb(255, 0, 0));
+        assert_eq!(
+            Brush::Stacked(vec![empty, red.clone()]).layers(),
+            vec![&red]
+        );
+    }
+
+    #[test]
+    fn test_stacked() {
+        let red = Brush::from(Color::rgba(255, 0, 0, 128));
+        let blue = Brush::from(Color::rgba(0, 0, 255, 128));
+        let transparent = Brush::default();
+
+        assert!(Brush::Stacked(vec![]).is_transparent());
+        assert!(Brush::Stacked(vec![transparent.clone(), transparent.clone()]).is_transparent());
+        assert!(!Brush::Stacked(vec![transparent.clone(), red.clone()]).is_transparent());
+
+        let stacked = Brush::Stacked(vec![
+            red.clone(),
+            Brush::Stacked(vec![transparent.clone(), blue.clone()]),
+        ]);
+        assert_eq!(stacked.layers(), vec![&red, &transparent, &blue]);
+        assert_eq!(red.layers(), vec![&red]);
+    }
 }
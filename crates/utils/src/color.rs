@@ -28,6 +28,11 @@ impl Color {
         Self::hsla(h, s, l, 1.0)
     }
 
+    /// Create a new color from HWB(0.0-360.0, 0.0-1.0, 0.0-1.0)
+    pub fn hwb(h: f64, w: f64, b: f64) -> Self {
+        Self::hwba(h, w, b, 1.0)
+    }
+
     /// Create a new color from RGB and alpha values
     pub const fn rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
         Color {
@@ -38,8 +43,8 @@ impl Color {
     /// Create a new color from HSV(0.0-360.0, 0.0-1.0, 0.0-1.0) and alpha values(0.0-1.0)
     pub fn hsva(mut hue: f64, mut saturation: f64, mut value: f64, alpha: f64) -> Self {
         hue %= 360.0;
-        saturation = saturation.max(0.0).min(1.0);
-        value = value.max(0.0).min(1.0);
+        saturation = saturation.clamp(0.0, 1.0);
+        value = value.clamp(0.0, 1.0);
         let hh = hue / 60.0;
         let idx = hh.floor() as i32;
         let ff = hh.fract();
@@ -66,8 +71,8 @@ impl Color {
     /// Create a new color from HSL(0.0-360.0, 0.0-1.0, 0.0-1.0) and alpha values(0.0-1.0)
     pub fn hsla(mut hue: f64, mut saturation: f64, mut lightness: f64, alpha: f64) -> Self {
         hue %= 360.0;
-        saturation = saturation.max(0.0).min(1.0);
-        lightness = lightness.max(0.0).min(1.0);
+        saturation = saturation.clamp(0.0, 1.0);
+        lightness = lightness.clamp(0.0, 1.0);
         let hh = hue / 60.0;
         let idx = hh.floor() as i32;
         let chroma = (1.0 - ((2.0 * lightness) - 1.0).abs()) * saturation;
@@ -93,6 +98,21 @@ impl Color {
         )
     }
 
+    /// Create a new color from HWB(0.0-360.0, 0.0-1.0, 0.0-1.0) and alpha values(0.0-1.0)
+    pub fn hwba(hue: f64, mut whiteness: f64, mut blackness: f64, alpha: f64) -> Self {
+        whiteness = whiteness.clamp(0.0, 1.0);
+        blackness = blackness.clamp(0.0, 1.0);
+
+        // whiteness and blackness add up to more than 100%, the result is a gray
+        if whiteness + blackness >= 1.0 {
+            let gray = (whiteness / (whiteness + blackness) * 255.0) as u8;
+            return Self::rgba(gray, gray, gray, (alpha * 255.0) as u8);
+        }
+
+        let value = 1.0 - blackness;
+        Self::hsva(hue, 1.0 - whiteness / value, value, alpha)
+    }
+
     /// Get the r value
     pub fn r(self) -> u8 {
         ((self.data & 0x00FF_0000) >> 16) as u8
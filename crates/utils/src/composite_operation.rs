@@ -0,0 +1,100 @@
+/// Describes how the pixels of a drawn shape are combined with the pixels that are already drawn.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum CompositeOperation {
+    /// Draws the new shape on top of the existing content. (default)
+    #[default]
+    SourceOver,
+
+    /// Draws the new shape only where it overlaps the existing content.
+    SourceIn,
+
+    /// Draws the new shape only where it does not overlap the existing content.
+    SourceOut,
+
+    /// Draws the new shape only where it overlaps the existing content, the existing content is kept.
+    SourceAtop,
+
+    /// Draws the new shape behind the existing content.
+    DestinationOver,
+
+    /// Keeps the existing content only where it overlaps the new shape.
+    DestinationIn,
+
+    /// Keeps the existing content only where it does not overlap the new shape.
+    DestinationOut,
+
+    /// Keeps the existing content only where it overlaps the new shape, the new shape is drawn behind it.
+    DestinationAtop,
+
+    /// Adds the colors of the new shape and the existing content.
+    Lighter,
+
+    /// Replaces the existing content by the new shape.
+    Copy,
+
+    /// Shapes are transparent where both overlap.
+    Xor,
+
+    /// Multiplies the colors, the result is darker.
+    Multiply,
+
+    /// Inverts, multiplies and inverts the colors again, the result is lighter.
+    Screen,
+
+    /// Multiplies dark and screens light parts of the existing content.
+    Overlay,
+
+    /// Keeps the darker color of each channel.
+    Darken,
+
+    /// Keeps the lighter color of each channel.
+    Lighten,
+
+    /// Divides the existing content by the inverted new shape.
+    ColorDodge,
+
+    /// Divides the inverted existing content by the new shape and inverts the result.
+    ColorBurn,
+
+    /// Multiplies dark and screens light parts of the new shape.
+    HardLight,
+
+    /// A softer version of `HardLight`.
+    SoftLight,
+
+    /// Subtracts the darker from the lighter color.
+    Difference,
+
+    /// Like `Difference` but with lower contrast.
+    Exclusion,
+}
+
+impl CompositeOperation {
+    /// Returns the name of the operation used by the `globalCompositeOperation` of the HTML canvas.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CompositeOperation::SourceOver => "source-over",
+            CompositeOperation::SourceIn => "source-in",
+            CompositeOperation::SourceOut => "source-out",
+            CompositeOperation::SourceAtop => "source-atop",
+            CompositeOperation::DestinationOver => "destination-over",
+            CompositeOperation::DestinationIn => "destination-in",
+            CompositeOperation::DestinationOut => "destination-out",
+            CompositeOperation::DestinationAtop => "destination-atop",
+            CompositeOperation::Lighter => "lighter",
+            CompositeOperation::Copy => "copy",
+            CompositeOperation::Xor => "xor",
+            CompositeOperation::Multiply => "multiply",
+            CompositeOperation::Screen => "screen",
+            CompositeOperation::Overlay => "overlay",
+            CompositeOperation::Darken => "darken",
+            CompositeOperation::Lighten => "lighten",
+            CompositeOperation::ColorDodge => "color-dodge",
+            CompositeOperation::ColorBurn => "color-burn",
+            CompositeOperation::HardLight => "hard-light",
+            CompositeOperation::SoftLight => "soft-light",
+            CompositeOperation::Difference => "difference",
+            CompositeOperation::Exclusion => "exclusion",
+        }
+    }
+}
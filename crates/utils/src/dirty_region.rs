@@ -0,0 +1,167 @@
+use crate::Rectangle;
+
+// if the region consists of more rectangles, they are collapsed to their bounds
+const MAX_RECTANGLES: usize = 8;
+
+/// A `DirtyRegion` collects the areas that have to be redrawn. It is backed by a small list of rectangles, a
+/// rectangle that is added is merged with an existing one if their union does not cover more area than both
+/// rectangles on their own.
+///
+/// # Examples
+/// ```rust
+/// # use orbtk_utils::{DirtyRegion, Rectangle};
+/// let mut region = DirtyRegion::new();
+/// region.add(Rectangle::new((0.0, 0.0), (10.0, 10.0)));
+///
+/// assert!(region.intersects(Rectangle::new((5.0, 5.0), (10.0, 10.0))));
+/// assert!(!region.intersects(Rectangle::new((20.0, 20.0), (10.0, 10.0))));
+/// ```
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct DirtyRegion {
+    rectangles: Vec<Rectangle>,
+}
+
+impl DirtyRegion {
+    /// Creates a new empty region.
+    pub fn new() -> Self {
+        DirtyRegion::default()
+    }
+
+    /// Returns the rectangles the region consists of.
+    pub fn rectangles(&self) -> &[Rectangle] {
+        &self.rectangles
+    }
+
+    /// Returns `true` if nothing is dirty.
+    pub fn is_empty(&self) -> bool {
+        self.rectangles.is_empty()
+    }
+
+    /// Removes all rectangles from the region.
+    pub fn clear(&mut self) {
+        self.rectangles.clear();
+    }
+
+    /// Adds the given rectangle to the region. Empty rectangles are ignored.
+    pub fn add(&mut self, rectangle: Rectangle) {
+        if rectangle.width() <= 0.0 || rectangle.height() <= 0.0 {
+            return;
+        }
+
+        let mut rectangle = rectangle;
+
+        // merging can make the rectangle worth merging with another one, so repeat until nothing changes
+        while let Some(index) = self
+            .rectangles
+            .iter()
+            .position(|other| should_merge(&rectangle, other))
+        {
+            let other = self.rectangles.swap_remove(index);
+            rectangle.join_with_rectangle(&other);
+        }
+
+        self.rectangles.push(rectangle);
+
+        if self.rectangles.len() > MAX_RECTANGLES {
+            if let Some(bounds) = self.bounds() {
+                self.rectangles = vec![bounds];
+            }
+        }
+    }
+
+    /// Checks if the given rectangle intersects with the region.
+    pub fn intersects(&self, rectangle: Rectangle) -> bool {
+        self.rectangles
+            .iter()
+            .any(|other| other.intersects(&rectangle))
+    }
+
+    /// Returns the smallest rectangle that covers the whole region, `None` if the region is empty.
+    pub fn bounds(&self) -> Option<Rectangle> {
+        let mut rectangles = self.rectangles.iter();
+        let mut bounds = *rectangles.next()?;
+
+        for rectangle in rectangles {
+            bounds.join_with_rectangle(rectangle);
+        }
+
+        Some(bounds)
+    }
+}
+
+fn area(rectangle: &Rectangle) -> f64 {
+    rectangle.width() * rectangle.height()
+}
+
+// two rectangles are merged if their union is not larger than both of them together
+fn should_merge(first: &Rectangle, second: &Rectangle) -> bool {
+    let mut union = *first;
+    union.join_with_rectangle(second);
+
+    area(&union) <= area(first) + area(second)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add() {
+        let mut region = DirtyRegion::new();
+        region.add(Rectangle::new((0.0, 0.0), (0.0, 10.0)));
+        assert!(region.is_empty());
+
+        // overlapping rectangles are merged
+        region.add(Rectangle::new((0.0, 0.0), (10.0, 10.0)));
+        region.add(Rectangle::new((5.0, 0.0), (10.0, 10.0)));
+        assert_eq!(
+            region.rectangles(),
+            &[Rectangle::new((0.0, 0.0), (15.0, 10.0))]
+        );
+
+        // distant rectangles are kept apart
+        region.add(Rectangle::new((100.0, 100.0), (10.0, 10.0)));
+        assert_eq!(region.rectangles().len(), 2);
+
+        // a rectangle that covers both of them merges them
+        region.add(Rectangle::new((0.0, 0.0), (110.0, 110.0)));
+        assert_eq!(
+            region.rectangles(),
+            &[Rectangle::new((0.0, 0.0), (110.0, 110.0))]
+        );
+    }
+
+    #[test]
+    fn test_intersects() {
+        let mut region = DirtyRegion::new();
+        assert!(!region.intersects(Rectangle::new((0.0, 0.0), (10.0, 10.0))));
+
+        region.add(Rectangle::new((0.0, 0.0), (10.0, 10.0)));
+        region.add(Rectangle::new((5.0, 5.0), (10.0, 10.0)));
+        region.add(Rectangle::new((100.0, 0.0), (10.0, 10.0)));
+
+        assert!(region.intersects(Rectangle::new((12.0, 12.0), (5.0, 5.0))));
+        assert!(region.intersects(Rectangle::new((105.0, 5.0), (50.0, 50.0))));
+
+        // inside of the bounds, but between the dirty rectangles
+        assert!(!region.intersects(Rectangle::new((50.0, 2.0), (10.0, 5.0))));
+    }
+
+    #[test]
+    fn test_bounds() {
+        let mut region = DirtyRegion::new();
+        assert_eq!(region.bounds(), None);
+
+        region.add(Rectangle::new((10.0, 10.0), (10.0, 10.0)));
+        region.add(Rectangle::new((15.0, 15.0), (10.0, 10.0)));
+        region.add(Rectangle::new((100.0, 0.0), (10.0, 10.0)));
+
+        assert_eq!(
+            region.bounds(),
+            Some(Rectangle::new((10.0, 0.0), (100.0, 25.0)))
+        );
+
+        region.clear();
+        assert_eq!(region.bounds(), None);
+    }
+}
@@ -0,0 +1,281 @@
+use crate::String16;
+
+// A recorded change of the text.
+#[derive(Clone, Debug, PartialEq)]
+enum Edit {
+    Insert { idx: usize, units: Vec<u16> },
+    Remove { idx: usize, units: Vec<u16> },
+}
+
+/// A `String16` that records its changes, so that they can be reverted with `undo` and replayed with `redo`.
+///
+/// Consecutive inserts of single characters are merged into one undo step until a whitespace is typed, so
+/// typing a word is undone as a word.
+///
+/// # Examples
+///
+/// let mut text = EditableText::from("Hello");
+/// text.push_str(" World");
+/// text.undo();
+///
+/// assert_eq!(text.as_string(), "Hello");
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct EditableText {
+    text: String16,
+    undo_stack: Vec<Edit>,
+    redo_stack: Vec<Edit>,
+    // false if the next insert must not be merged with the last undo step
+    merge: bool,
+}
+
+impl EditableText {
+    /// Creates a new empty `EditableText`.
+    pub fn new() -> Self {
+        EditableText::default()
+    }
+
+    /// Returns the current text.
+    pub fn text(&self) -> &String16 {
+        &self.text
+    }
+
+    /// Returns the length of the text, in bytes.
+    pub fn len(&self) -> usize {
+        self.text.len()
+    }
+
+    /// Returns `true` if the text has a length of zero, and `false` otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// Converts the text to a String.
+    pub fn as_string(&self) -> String {
+        self.text.as_string()
+    }
+
+    /// Inserts a string slice into the text at a byte position.
+    pub fn insert_str(&mut self, idx: usize, string: &str) {
+        let units: Vec<u16> = string.encode_utf16().collect();
+
+        if units.is_empty() {
+            return;
+        }
+
+        self.text.insert_utf16(idx, &units);
+        self.redo_stack.clear();
+
+        let single_char = string.chars().count() == 1;
+
+        if self.merge && single_char {
+            if let Some(Edit::Insert {
+                idx: last_idx,
+                units: last_units,
+            }) = self.undo_stack.last_mut()
+            {
+                let ends_word = matches!(
+                    String::from_utf16_lossy(last_units).chars().last(),
+                    Some(ch) if ch.is_whitespace()
+                );
+
+                if *last_idx + last_units.len() == idx && !ends_word {
+                    last_units.extend(units);
+                    return;
+                }
+            }
+        }
+
+        self.undo_stack.push(Edit::Insert { idx, units });
+        self.merge = single_char;
+    }
+
+    /// Appends a given string slice onto the end of the text.
+    pub fn push_str(&mut self, string: &str) {
+        self.insert_str(self.len(), string);
+    }
+
+    /// Appends a given char onto the end of the text.
+    pub fn push(&mut self, ch: char) {
+        let mut buf = [0; 4];
+        self.push_str(ch.encode_utf8(&mut buf));
+    }
+
+    /// Removes the byte at the given position from the text.
+    pub fn remove(&mut self, idx: usize) {
+        self.remove_range(idx, idx + 1);
+    }
+
+    /// Removes the bytes from `start` to `end` from the text.
+    pub fn remove_range(&mut self, start: usize, end: usize) {
+        if start >= end {
+            return;
+        }
+
+        let units = self.text.remove_range(start, end);
+        self.redo_stack.clear();
+        self.undo_stack.push(Edit::Remove { idx: start, units });
+        self.merge = false;
+    }
+
+    /// Removes all contents of the text.
+    pub fn clear(&mut self) {
+        self.remove_range(0, self.len());
+    }
+
+    /// Returns `true` if there is a change that can be undone.
+    pub fn can_undo(&self) -> bool {
+        !self.undo_stack.is_empty()
+    }
+
+    /// Returns `true` if there is an undone change that can be redone.
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+
+    /// Reverts the last change. Returns `false` if there is nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        self.merge = false;
+
+        if let Some(edit) = self.undo_stack.pop() {
+            self.revert(&edit);
+            self.redo_stack.push(edit);
+            return true;
+        }
+
+        false
+    }
+
+    /// Replays the last undone change. Returns `false` if there is nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        self.merge = false;
+
+        if let Some(edit) = self.redo_stack.pop() {
+            self.apply(&edit);
+            self.undo_stack.push(edit);
+            return true;
+        }
+
+        false
+    }
+
+    fn apply(&mut self, edit: &Edit) {
+        match edit {
+            Edit::Insert { idx, units } => self.text.insert_utf16(*idx, units),
+            Edit::Remove { idx, units } => {
+                self.text.remove_range(*idx, *idx + units.len());
+            }
+        }
+    }
+
+    fn revert(&mut self, edit: &Edit) {
+        match edit {
+            Edit::Insert { idx, units } => {
+                self.text.remove_range(*idx, *idx + units.len());
+            }
+            Edit::Remove { idx, units } => self.text.insert_utf16(*idx, units),
+        }
+    }
+}
+
+impl From<&str> for EditableText {
+    fn from(s: &str) -> Self {
+        EditableText::from(String16::from(s))
+    }
+}
+
+impl From<String> for EditableText {
+    fn from(string: String) -> Self {
+        EditableText::from(String16::from(string))
+    }
+}
+
+impl From<String16> for EditableText {
+    fn from(text: String16) -> Self {
+        EditableText {
+            text,
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_undo() {
+        let mut text = EditableText::from("Hello");
+        text.push(' ');
+
+        // typed characters are undone word by word
+        for ch in "World".chars() {
+            text.push(ch);
+        }
+        assert_eq!(text.as_string(), "Hello World");
+
+        assert!(text.undo());
+        assert_eq!(text.as_string(), "Hello ");
+        assert!(text.undo());
+        assert_eq!(text.as_string(), "Hello");
+        assert!(!text.undo());
+
+        // a pasted string is one undo step
+        text.insert_str(0, "Say ");
+        text.push('!');
+        assert!(text.undo());
+        assert!(text.undo());
+        assert_eq!(text.as_string(), "Hello");
+
+        // two-u16 encoded chars
+        text.push('𝕊');
+        text.push('𝕊');
+        assert_eq!(text.len(), 9);
+        assert!(text.undo());
+        assert_eq!(text.as_string(), "Hello");
+    }
+
+    #[test]
+    fn remove_undo() {
+        let mut text = EditableText::from("Hello");
+        text.remove(4);
+        text.remove(0);
+        assert_eq!(text.as_string(), "ell");
+
+        assert!(text.undo());
+        assert_eq!(text.as_string(), "Hell");
+        assert!(text.undo());
+        assert_eq!(text.as_string(), "Hello");
+
+        text.clear();
+        assert!(text.is_empty());
+        assert!(text.undo());
+        assert_eq!(text.as_string(), "Hello");
+    }
+
+    #[test]
+    fn redo() {
+        let mut text = EditableText::new();
+        text.push_str("Hello");
+        text.remove(0);
+
+        assert!(text.undo());
+        assert!(text.undo());
+        assert!(text.is_empty());
+
+        assert!(text.redo());
+        assert_eq!(text.as_string(), "Hello");
+
+        // a new edit clears the redo history
+        text.push('!');
+        assert!(!text.can_redo());
+        assert!(!text.redo());
+        assert_eq!(text.as_string(), "Hello!");
+
+        // typing after an undo starts a new undo step
+        assert!(text.undo());
+        text.push('?');
+        text.push('?');
+        assert!(text.undo());
+        assert_eq!(text.as_string(), "Hello");
+    }
+}
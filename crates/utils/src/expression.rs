@@ -2,7 +2,7 @@ use crate::prelude::*;
 use std::f64;
 use std::iter::Peekable;
 use std::ops::Neg;
-use std::{convert::TryFrom, str::Chars};
+use std::{convert::TryFrom, error::Error, fmt, str::CharIndices};
 
 // Describes a String declared expression either be a method, a color, a number or anything.
 /// This object represents a `expression` used to define something(currently is only use to define
@@ -17,6 +17,22 @@ pub enum Expression {
 }
 
 impl Expression {
+    /// Parses the given string to an `Expression`. Returns an error that contains the offending part of the
+    /// string and its position (in bytes) if the string is not a valid expression.
+    pub fn try_parse(s: &str) -> Result<Expression, ExpressionError> {
+        let mut chrs = s.char_indices().peekable();
+        let expression = parse_expression_with_complex(&mut chrs)?.unwrap_or_default();
+
+        if let Some((position, character)) = chrs.next() {
+            return Err(ExpressionError::UnexpectedCharacter {
+                character,
+                position,
+            });
+        }
+
+        Ok(expression)
+    }
+
     /// Try to convert `self` into a `Number`
     pub fn number(&self) -> Option<Number> {
         match self {
@@ -43,11 +59,12 @@ impl Expression {
                     };
                     if name == "rgb" || name == "rgba" {
                         if p {
-                            v = v * 100.0 / 255.0;
+                            v = v * 255.0 / 100.0;
                         } else if v <= 1.0 {
                             v = 255.0 * v.fract();
                         }
-                    } else if i != 0 && p {
+                    } else if i != 0 && (p || v > 1.0) {
+                        // saturation, lightness, whiteness, ... could be given as `50%`, `50` or `0.5`
                         v /= 100.0;
                     }
                     values[i] = v;
@@ -57,6 +74,7 @@ impl Expression {
                         "rgb" => Color::rgb(values[0] as u8, values[1] as u8, values[2] as u8),
                         "hsv" | "hsb" => Color::hsv(values[0], values[1], values[2]),
                         "hsl" => Color::hsl(values[0], values[1], values[2]),
+                        "hwb" => Color::hwb(values[0], values[1], values[2]),
                         _ => return None,
                     })
                 } else {
@@ -69,6 +87,7 @@ impl Expression {
                         ),
                         "hsva" | "hsba" => Color::hsva(values[0], values[1], values[2], values[3]),
                         "hsla" => Color::hsla(values[0], values[1], values[2], values[3]),
+                        "hwba" => Color::hwba(values[0], values[1], values[2], values[3]),
                         _ => return None,
                     })
                 }
@@ -103,6 +122,13 @@ impl Expression {
 
     pub fn relative_dir(&self) -> Option<RelativeDir> {
         match self {
+            // e.g. `to bottom right` is parsed as `to bottom` and `right`
+            Expression::Complex(exprs) if exprs.len() == 2 => match (&exprs[0], &exprs[1]) {
+                (Expression::Other(first), Expression::Other(second)) => {
+                    Expression::Other(format!("{} {}", first, second)).relative_dir()
+                }
+                _ => None,
+            },
             Expression::Other(label) => match &label[..] {
                 "to top" => Some(RelativeDir::Top),
                 "to top right" => Some(RelativeDir::TopRight),
@@ -118,6 +144,35 @@ impl Expression {
         }
     }
 
+    // returns the center of a radial gradient if the expression is a shape and / or position of a radial
+    // gradient like `circle`, `at 20% 30%` or `circle at 20px 30px`
+    fn radial_position(&self) -> Option<Option<OnPlanePos>> {
+        let exprs = match self {
+            Expression::Complex(exprs) => &exprs[..],
+            _ => std::slice::from_ref(self),
+        };
+
+        let exprs = match exprs.first() {
+            Some(Expression::Other(shape)) if shape == "circle" || shape == "ellipse" => {
+                &exprs[1..]
+            }
+            _ => exprs,
+        };
+
+        match exprs {
+            [] => Some(None),
+            [Expression::Other(at), Expression::Number(x, x_unit), Expression::Number(y, y_unit)]
+                if at == "at" =>
+            {
+                Some(Some(OnPlanePos::new(
+                    OnLinePos::try_from((*x, &x_unit[..])).ok()?,
+                    OnLinePos::try_from((*y, &y_unit[..])).ok()?,
+                )))
+            }
+            _ => None,
+        }
+    }
+
     pub fn angle(&self) -> Option<Angle> {
         match self {
             Expression::Number(num, unit) => {
@@ -136,6 +191,28 @@ impl Expression {
         }
     }
 
+    /// Resolves a length to pixels. `px` and numbers without unit are pixels, `%` and `em` are relative to
+    /// `base_px` (e.g. the font size of the widget) and `rem` is relative to `root_px` (e.g. the font size of the
+    /// window).
+    pub fn length(&self, base_px: f64, root_px: f64) -> Option<f64> {
+        match self {
+            Expression::Number(num, unit) => {
+                let num: f64 = (*num).into();
+                let length = match &unit[..] {
+                    "px" | "" => num,
+                    "%" => num * base_px / 100.0,
+                    "em" => num * base_px,
+                    "rem" => num * root_px,
+                    _ => {
+                        return None;
+                    }
+                };
+                Some(length)
+            }
+            _ => None,
+        }
+    }
+
     pub fn css_gradient(&self) -> Option<Gradient> {
         let mut displacement = OnPlanePos::new(
             OnLinePos::new(0.0, OnLinePosKind::Pixels),
@@ -188,8 +265,12 @@ impl Expression {
         let mut i = 0;
         let kind;
         if radial {
-            // TODO: Implement radial gradients
-            return None;
+            let mut pos = None;
+            if let Some(radial_pos) = args[0].radial_position() {
+                pos = radial_pos;
+                i += 1;
+            }
+            kind = GradientKind::Radial(RadialGradient { pos });
         } else {
             let mut coords = LinearGradientCoords::Angle {
                 displacement,
@@ -200,6 +281,7 @@ impl Expression {
                     direction,
                     displacement,
                 };
+                i += 1;
             } else if let Some(angle) = args[0].angle() {
                 coords = LinearGradientCoords::Angle {
                     angle,
@@ -250,9 +332,51 @@ impl Into<Number> for Expression {
     }
 }
 
-pub(crate) fn parse_expression_with_complex(chrs: &mut Peekable<Chars>) -> Option<Expression> {
+/// Describes an error that occurs while parsing an `Expression`.
+#[derive(Clone, PartialEq, Debug)]
+pub enum ExpressionError {
+    /// A method call is not closed with `)`, e.g. `rgb(0, 0, 0`.
+    UnterminatedMethod { method: String, position: usize },
+
+    /// A value starts like a number but could not be parsed as one, e.g. `1.2.3px`.
+    InvalidNumber { text: String, position: usize },
+
+    /// A character was found at a position where it is not allowed, e.g. a `)` without a method call.
+    UnexpectedCharacter { character: char, position: usize },
+}
+
+impl fmt::Display for ExpressionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ExpressionError::UnterminatedMethod { method, position } => write!(
+                f,
+                "unterminated method call `{}(` at position {}",
+                method, position
+            ),
+            ExpressionError::InvalidNumber { text, position } => {
+                write!(f, "invalid number `{}` at position {}", text, position)
+            }
+            ExpressionError::UnexpectedCharacter {
+                character,
+                position,
+            } => write!(
+                f,
+                "unexpected character `{}` at position {}",
+                character, position
+            ),
+        }
+    }
+}
+
+impl Error for ExpressionError {}
+
+type CharStream<'a> = Peekable<CharIndices<'a>>;
+
+pub(crate) fn parse_expression_with_complex(
+    chrs: &mut CharStream,
+) -> Result<Option<Expression>, ExpressionError> {
     let mut v = Vec::new();
-    while let Some(c) = chrs.peek() {
+    while let Some((_, c)) = chrs.peek() {
         let c = *c;
         if c == ',' || c == ')' {
             break;
@@ -265,11 +389,11 @@ pub(crate) fn parse_expression_with_complex(chrs: &mut Peekable<Chars>) -> Optio
         v.push(expr);
     }
     if v.is_empty() {
-        None
+        Ok(None)
     } else if v.len() == 1 {
-        Some(v[0].to_owned())
+        Ok(Some(v[0].to_owned()))
     } else {
-        Some(Expression::Complex(v))
+        Ok(Some(Expression::Complex(v)))
     }
 }
 
@@ -277,21 +401,38 @@ fn is_number_component(c: char) -> bool {
     c.is_ascii_digit() || c == '.' || c == '-'
 }
 
-fn parse_expression(chrs: &mut Peekable<Chars>) -> Option<Expression> {
+// true if the text starts like a number, a single `-` or `.` is not enough
+fn is_number_start(text: &str) -> bool {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_digit() => true,
+        Some('-') | Some('.') => matches!(chars.next(), Some(c) if c.is_ascii_digit() || c == '.'),
+        _ => false,
+    }
+}
+
+fn parse_expression(chrs: &mut CharStream) -> Result<Expression, ExpressionError> {
+    let position = chrs.peek().map(|(i, _)| *i).unwrap_or_default();
     let mut text = String::new();
     let method;
     loop {
         match chrs.peek() {
-            Some('(') => {
+            Some((i, '(')) => {
+                if text.is_empty() {
+                    return Err(ExpressionError::UnexpectedCharacter {
+                        character: '(',
+                        position: *i,
+                    });
+                }
                 chrs.next().unwrap();
                 method = true;
                 break;
             }
-            Some(c) if *c == ',' || *c == ')' || (c.is_whitespace() && text != "to") => {
+            Some((_, c)) if *c == ',' || *c == ')' || (c.is_whitespace() && text != "to") => {
                 method = false;
                 break;
             }
-            Some(c) => {
+            Some((_, c)) => {
                 text.push(*c);
                 chrs.next().unwrap();
             }
@@ -306,23 +447,31 @@ fn parse_expression(chrs: &mut Peekable<Chars>) -> Option<Expression> {
         let mut args = Vec::new();
         loop {
             match chrs.peek() {
-                Some(c) if c.is_whitespace() || *c == ',' => {
+                Some((_, c)) if c.is_whitespace() || *c == ',' => {
                     chrs.next().unwrap();
                 }
-                None | Some(')') => {
-                    let _ = chrs.next();
+                Some((_, ')')) => {
+                    chrs.next().unwrap();
                     break;
                 }
+                None => {
+                    return Err(ExpressionError::UnterminatedMethod {
+                        method: text,
+                        position,
+                    });
+                }
                 _ => {
-                    args.push(parse_expression_with_complex(chrs)?);
+                    if let Some(arg) = parse_expression_with_complex(chrs)? {
+                        args.push(arg);
+                    }
                 }
             }
         }
-        Some(Expression::Method(text, args))
+        Ok(Expression::Method(text, args))
     } else {
         if text.starts_with('#') {
-            return Some(Expression::Color(Color::from(text)));
-        } else if text.starts_with(is_number_component) {
+            return Ok(Expression::Color(Color::from(text)));
+        } else if is_number_start(&text) {
             if let Some(mut ofs) = text.rfind(is_number_component) {
                 ofs += 1; // Moves from before last position digit to after last digit position
                 if text[..ofs]
@@ -330,20 +479,22 @@ fn parse_expression(chrs: &mut Peekable<Chars>) -> Option<Expression> {
                     .is_some()
                 {
                     if let Ok(v) = lexical_core::parse(text[..ofs].as_bytes()) {
-                        return Some(Expression::Number(Number::Float(v), text[ofs..].to_owned()));
+                        return Ok(Expression::Number(Number::Float(v), text[ofs..].to_owned()));
                     }
                 } else if let Ok(v) = lexical_core::parse(text[..ofs].as_bytes()) {
-                    return Some(Expression::Number(Number::Real(v), text[ofs..].to_owned()));
+                    return Ok(Expression::Number(Number::Real(v), text[ofs..].to_owned()));
                 }
             }
+
+            return Err(ExpressionError::InvalidNumber { text, position });
         }
-        Some(Expression::Other(text))
+        Ok(Expression::Other(text))
     }
 }
 
 impl From<&str> for Expression {
     fn from(s: &str) -> Expression {
-        parse_expression_with_complex(&mut s.chars().peekable()).unwrap_or_default()
+        Expression::try_parse(s).unwrap_or_default()
     }
 }
 
@@ -507,3 +658,219 @@ impl Neg for OnLinePos {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn color(s: &str) -> Color {
+        Expression::from(s).color().unwrap()
+    }
+
+    fn assert_color(s: &str, r: u8, g: u8, b: u8, a: u8) {
+        let color = color(s);
+        assert_eq!(
+            (color.r(), color.g(), color.b(), color.a()),
+            (r, g, b, a),
+            "{}",
+            s
+        );
+    }
+
+    #[test]
+    fn rgb() {
+        assert_color("rgb(255, 128, 0)", 255, 128, 0, 255);
+        assert_color("rgb(100%, 50%, 0%)", 255, 127, 0, 255);
+        assert_color("rgba(255, 128, 0, 0.5)", 255, 128, 0, 127);
+        assert_color("rgba(255, 128, 0, 50%)", 255, 128, 0, 127);
+    }
+
+    #[test]
+    fn hsv() {
+        assert_color("hsv(120, 100%, 100%)", 0, 255, 0, 255);
+        assert_color("hsb(120, 1, 1)", 0, 255, 0, 255);
+        assert_color("hsva(120, 100%, 100%, 0.5)", 0, 255, 0, 127);
+        assert_color("hsba(120, 1, 1, 50%)", 0, 255, 0, 127);
+    }
+
+    #[test]
+    fn hsl() {
+        assert_color("hsl(120, 50%, 50%)", 63, 191, 63, 255);
+        assert_color("hsl(120, 0.5, 0.5)", 63, 191, 63, 255);
+        assert_color("hsl(120, 50, 50)", 63, 191, 63, 255);
+        assert_color("hsla(120, 50%, 50%, 0.5)", 63, 191, 63, 127);
+        assert_color("hsla(120, 0.5, 0.5, 50%)", 63, 191, 63, 127);
+    }
+
+    #[test]
+    fn hwb() {
+        assert_color("hwb(0, 0%, 0%)", 255, 0, 0, 255);
+        assert_color("hwb(120, 0.2, 0.2)", 51, 204, 51, 255);
+        assert_color("hwb(120, 20%, 20%)", 51, 204, 51, 255);
+        assert_color("hwb(120, 60%, 60%)", 127, 127, 127, 255);
+        assert_color("hwba(120, 20%, 20%, 0.5)", 51, 204, 51, 127);
+        assert_color("hwba(0, 0, 0, 50%)", 255, 0, 0, 127);
+        assert_eq!(Expression::from("hwb(120, 20%)").color(), None);
+    }
+
+    fn parse_gradient(s: &str) -> Gradient {
+        match Expression::from(s).brush() {
+            Some(Brush::Gradient(gradient)) => gradient,
+            brush => panic!("{} is not a gradient: {:?}", s, brush),
+        }
+    }
+
+    #[test]
+    fn linear_gradient_angle() {
+        let gradient = parse_gradient("linear-gradient(45deg, #f00, #00f)");
+        assert_eq!(
+            gradient.kind,
+            GradientKind::Linear(LinearGradientCoords::from_angle(Angle::from_degrees(45.0)))
+        );
+        assert_eq!(
+            gradient.stops,
+            vec![
+                GradientStop::from(Color::rgb(255, 0, 0)),
+                GradientStop::from(Color::rgb(0, 0, 255))
+            ]
+        );
+        assert!(!gradient.repeat);
+
+        let gradient = parse_gradient("repeating-linear-gradient(0.25turn, red, blue)");
+        assert_eq!(
+            gradient.kind,
+            GradientKind::Linear(LinearGradientCoords::from_angle(Angle::from_turn(0.25)))
+        );
+        assert_eq!(gradient.stops.len(), 2);
+        assert!(gradient.repeat);
+    }
+
+    #[test]
+    fn linear_gradient_direction() {
+        let gradient = parse_gradient("linear-gradient(to right, red, blue)");
+        assert_eq!(
+            gradient.kind,
+            GradientKind::Linear(LinearGradientCoords::Direction {
+                direction: RelativeDir::Right,
+                displacement: OnPlanePos::default(),
+            })
+        );
+        assert_eq!(gradient.stops.len(), 2);
+
+        let gradient = parse_gradient("linear-gradient(to bottom right, red, blue)");
+        assert_eq!(
+            gradient.kind,
+            GradientKind::Linear(LinearGradientCoords::Direction {
+                direction: RelativeDir::BottomRight,
+                displacement: OnPlanePos::default(),
+            })
+        );
+    }
+
+    #[test]
+    fn gradient_stops() {
+        let gradient = parse_gradient("linear-gradient(to top, #f00 0%, #0f0 30%, #00f 100px)");
+        assert_eq!(
+            gradient.stops,
+            vec![
+                GradientStop::new(
+                    Some(OnLinePos::new(0.0, OnLinePosKind::Percentage)),
+                    Color::rgb(255, 0, 0)
+                ),
+                GradientStop::new(
+                    Some(OnLinePos::new(30.0, OnLinePosKind::Percentage)),
+                    Color::rgb(0, 255, 0)
+                ),
+                GradientStop::new(
+                    Some(OnLinePos::new(100.0, OnLinePosKind::Pixels)),
+                    Color::rgb(0, 0, 255)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn radial_gradient() {
+        let gradient = parse_gradient("radial-gradient(red, blue)");
+        assert_eq!(
+            gradient.kind,
+            GradientKind::Radial(RadialGradient::default())
+        );
+        assert_eq!(gradient.stops.len(), 2);
+
+        let gradient = parse_gradient("radial-gradient(circle, red 10%, blue)");
+        assert_eq!(
+            gradient.kind,
+            GradientKind::Radial(RadialGradient::default())
+        );
+        assert_eq!(gradient.stops.len(), 2);
+
+        let pos = OnPlanePos::new(
+            OnLinePos::new(20.0, OnLinePosKind::Percentage),
+            OnLinePos::new(30.0, OnLinePosKind::Pixels),
+        );
+        let gradient = parse_gradient("repeating-radial-gradient(circle at 20% 30px, red, blue)");
+        assert_eq!(gradient.kind, GradientKind::Radial(RadialGradient::at(pos)));
+        assert!(gradient.repeat);
+
+        let gradient = parse_gradient("radial-gradient(at 20% 30px, red, blue)");
+        assert_eq!(gradient.kind, GradientKind::Radial(RadialGradient::at(pos)));
+
+        assert_eq!(Expression::from("radial-gradient(circle)").brush(), None);
+    }
+
+    #[test]
+    fn try_parse() {
+        assert_eq!(
+            Expression::try_parse("rgb(10, 20, 30)"),
+            Ok(Expression::Method(
+                "rgb".to_string(),
+                vec![
+                    Expression::Number(Number::Real(10), String::new()),
+                    Expression::Number(Number::Real(20), String::new()),
+                    Expression::Number(Number::Real(30), String::new()),
+                ]
+            ))
+        );
+        assert_eq!(Expression::try_parse(""), Ok(Expression::default()));
+
+        assert_eq!(
+            Expression::try_parse("linear-gradient(red, rgb(10, 20, 30)"),
+            Err(ExpressionError::UnterminatedMethod {
+                method: "linear-gradient".to_string(),
+                position: 0
+            })
+        );
+        assert_eq!(
+            Expression::try_parse("rgb(10, 20.5.3, 30)"),
+            Err(ExpressionError::InvalidNumber {
+                text: "20.5.3".to_string(),
+                position: 8
+            })
+        );
+        assert_eq!(
+            Expression::try_parse("red)"),
+            Err(ExpressionError::UnexpectedCharacter {
+                character: ')',
+                position: 3
+            })
+        );
+
+        // the infallible conversion falls back to the default expression
+        assert_eq!(Expression::from("rgb(10"), Expression::default());
+    }
+
+    #[test]
+    fn length() {
+        let length = |s: &str| Expression::from(s).length(16.0, 12.0);
+
+        assert_eq!(length("24px"), Some(24.0));
+        assert_eq!(length("24"), Some(24.0));
+        assert_eq!(length("1.5"), Some(1.5));
+        assert_eq!(length("50%"), Some(8.0));
+        assert_eq!(length("1.5em"), Some(24.0));
+        assert_eq!(length("2rem"), Some(24.0));
+        assert_eq!(length("2deg"), None);
+        assert_eq!(length("red"), None);
+    }
+}
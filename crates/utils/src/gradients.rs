@@ -50,7 +50,12 @@ impl Default for GradientStop {
 #[derive(Copy, Clone, PartialEq, Debug)]
 pub enum LinearGradientCoords {
     /// Defines the linear gradient by point A to point B.
-    Ends { start: Point, end: Point },
+    Ends {
+        start: Point,
+        end: Point,
+        // Defines a displacement of both ends.
+        displacement: OnPlanePos,
+    },
     /// Defines the linear gradient using an angle and a displacement from the center of the target figure.
     Angle {
         angle: Angle,
@@ -70,6 +75,7 @@ impl LinearGradientCoords {
         LinearGradientCoords::Ends {
             start: start.into(),
             end: end.into(),
+            displacement: OnPlanePos::default(),
         }
     }
 
@@ -81,17 +87,30 @@ impl LinearGradientCoords {
         }
     }
 
-    /// Sets the displacement if `self` is defined by its angle, otherwise, this does nothing.
+    /// Sets the displacement of the gradient.
     pub fn with_displacement(&mut self, displacement: impl Into<OnPlanePos>) -> Self {
-        if let LinearGradientCoords::Angle {
-            angle: _,
-            displacement: disp,
-        } = self
-        {
-            *disp = displacement.into();
+        match self {
+            LinearGradientCoords::Ends {
+                displacement: disp, ..
+            }
+            | LinearGradientCoords::Angle {
+                displacement: disp, ..
+            }
+            | LinearGradientCoords::Direction {
+                displacement: disp, ..
+            } => *disp = displacement.into(),
         }
         *self
     }
+
+    /// Returns the displacement of the gradient.
+    pub fn displacement(&self) -> OnPlanePos {
+        match self {
+            LinearGradientCoords::Ends { displacement, .. }
+            | LinearGradientCoords::Angle { displacement, .. }
+            | LinearGradientCoords::Direction { displacement, .. } => *displacement,
+        }
+    }
 }
 
 impl Default for LinearGradientCoords {
@@ -130,10 +149,27 @@ impl Default for Gradient {
     }
 }
 
+/// Describes a colorful radial gradient. The gradient is a circle that reaches the farthest corner of the
+/// target shape.
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct RadialGradient {
+    /// The center of the gradient, if `None` it is the center of the target shape.
+    pub pos: Option<OnPlanePos>,
+}
+
+impl RadialGradient {
+    /// Creates a `RadialGradient` with its center at the given position.
+    pub fn at(pos: impl Into<OnPlanePos>) -> RadialGradient {
+        RadialGradient {
+            pos: Some(pos.into()),
+        }
+    }
+}
+
 #[derive(Copy, Clone, PartialEq, Debug)]
 pub enum GradientKind {
     Linear(LinearGradientCoords),
-    // Radial(RadialGradient),
+    Radial(RadialGradient),
 }
 
 impl Default for GradientKind {
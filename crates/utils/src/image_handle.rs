@@ -0,0 +1,96 @@
+use std::{fmt, sync::Arc};
+
+/// Shares the pixels of an image between types like `Brush`, that doesn't know the image types of the
+/// render backends, and the render backends itself.
+///
+/// The pixels are stored as `u32` values in the ARGB format used by the images of the render backends. A handle
+/// is cheap to clone, all clones share the same pixels. The render backends provide conversions from their
+/// image types into a handle.
+#[derive(Clone, Default, PartialEq)]
+pub struct ImageHandle {
+    width: u32,
+    height: u32,
+    data: Arc<Vec<u32>>,
+}
+
+impl ImageHandle {
+    /// Creates a new image handle from the given pixels. Returns an error if the count of pixels does not match
+    /// the given size or if the size is too large to be addressed.
+    pub fn new(width: u32, height: u32, data: Vec<u32>) -> Result<Self, String> {
+        let len = (width as usize)
+            .checked_mul(height as usize)
+            .ok_or_else(|| format!("ImageHandle: a {}x{} image is too large", width, height))?;
+
+        if data.len() != len {
+            return Err(format!(
+                "ImageHandle: expected {} pixels for a {}x{} image, found {}",
+                len,
+                width,
+                height,
+                data.len()
+            ));
+        }
+
+        Ok(ImageHandle {
+            width,
+            height,
+            data: Arc::new(data),
+        })
+    }
+
+    /// Gets the width of the image.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Gets the height of the image.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Gets the pixels of the image.
+    pub fn data(&self) -> &[u32] {
+        &self.data
+    }
+
+    /// Returns `true` if the image has no pixels.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+}
+
+impl fmt::Debug for ImageHandle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "ImageHandle ( width: {}, height: {})",
+            self.width, self.height
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let handle = ImageHandle::new(2, 2, vec![0xff_ff_ff_ff; 4]).unwrap();
+        assert_eq!(handle.width(), 2);
+        assert_eq!(handle.height(), 2);
+        assert_eq!(handle.data().len(), 4);
+        assert!(!handle.is_empty());
+
+        assert!(ImageHandle::new(2, 2, vec![0; 3]).is_err());
+        assert!(ImageHandle::new(u32::MAX, u32::MAX, vec![]).is_err());
+        assert!(ImageHandle::default().is_empty());
+    }
+
+    #[test]
+    fn test_clone_shares_pixels() {
+        let handle = ImageHandle::new(1, 1, vec![0xff_00_00_00]).unwrap();
+        let clone = handle.clone();
+        assert_eq!(handle, clone);
+        assert_eq!(handle.data().as_ptr(), clone.data().as_ptr());
+    }
+}
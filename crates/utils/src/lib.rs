@@ -1,13 +1,20 @@
 pub use self::alignment::*;
 pub use self::angle::*;
 pub use self::border::*;
+pub use self::box_shadow::*;
 pub use self::brush::*;
 pub use self::color::*;
+pub use self::composite_operation::*;
 pub use self::constraint::*;
+pub use self::dirty_region::*;
 pub use self::dirty_size::*;
+pub use self::editable_text::*;
 pub use self::expression::*;
 pub use self::filter::*;
 pub use self::gradients::*;
+pub use self::image_handle::*;
+pub use self::line_cap::*;
+pub use self::line_join::*;
 pub use self::number::*;
 pub use self::orientation::*;
 pub use self::point::*;
@@ -19,19 +26,28 @@ pub use self::string16::*;
 pub use self::text_alignment::*;
 pub use self::text_baseline::*;
 pub use self::thickness::*;
+pub use self::transform::*;
 pub use self::value::*;
 pub use self::visibility::*;
 
 mod alignment;
 mod angle;
 mod border;
+mod box_shadow;
 mod brush;
 mod color;
+mod composite_operation;
 mod constraint;
+mod dirty_region;
 mod dirty_size;
+mod editable_text;
 mod expression;
 mod filter;
 mod gradients;
+mod image_handle;
+mod line_cap;
+mod line_join;
+pub mod motion;
 mod number;
 mod orientation;
 mod point;
@@ -45,5 +61,6 @@ mod string16;
 mod text_alignment;
 mod text_baseline;
 mod thickness;
+mod transform;
 mod value;
 mod visibility;
@@ -0,0 +1,13 @@
+/// Describes the shape used to draw the end points of lines.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum LineCap {
+    /// The ends of lines are squared off at the endpoints. (default)
+    #[default]
+    Butt,
+
+    /// The ends of lines are rounded.
+    Round,
+
+    /// The ends of lines are squared off by adding a box with an equal width and half the height of the line's thickness.
+    Square,
+}
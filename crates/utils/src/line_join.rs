@@ -0,0 +1,13 @@
+/// Describes the shape used to join two line segments where they meet.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum LineJoin {
+    /// Connected segments are joined by extending their outside edges to connect at a single point. (default)
+    #[default]
+    Miter,
+
+    /// Rounds off the corners of a shape.
+    Round,
+
+    /// Fills an additional triangular area between the common endpoint of connected segments.
+    Bevel,
+}
@@ -0,0 +1,46 @@
+//! This module contains the central reduced motion setting that is consulted by animations.
+
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
+
+static REDUCED_MOTION: AtomicBool = AtomicBool::new(false);
+
+/// Enables or disables reduced motion for the whole application. If it is enabled animations are skipped.
+pub fn set_reduced_motion(reduced_motion: bool) {
+    REDUCED_MOTION.store(reduced_motion, Ordering::Relaxed);
+}
+
+/// Returns `true` if reduced motion is enabled.
+pub fn is_reduced_motion() -> bool {
+    REDUCED_MOTION.load(Ordering::Relaxed)
+}
+
+/// Returns the duration an animation should use instead of the `requested` duration. If reduced motion is
+/// enabled the duration is zero and the animation jumps to its end, otherwise the requested duration is returned.
+pub fn resolve_duration(requested: Duration) -> Duration {
+    if is_reduced_motion() {
+        return Duration::from_millis(0);
+    }
+
+    requested
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_duration() {
+        let requested = Duration::from_millis(200);
+        assert_eq!(resolve_duration(requested), requested);
+
+        set_reduced_motion(true);
+        assert!(is_reduced_motion());
+        assert_eq!(resolve_duration(requested), Duration::from_millis(0));
+
+        set_reduced_motion(false);
+        assert_eq!(resolve_duration(requested), requested);
+    }
+}
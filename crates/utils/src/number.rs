@@ -1,11 +1,55 @@
-use std::ops::Neg;
+use std::{
+    cmp::Ordering,
+    error::Error,
+    fmt,
+    ops::{Add, Div, Mul, Neg, Sub},
+    str::FromStr,
+};
 
+/// A `Number` is either an integer (`Real`) or a floating point number (`Float`). Arithmetic operations keep a
+/// `Real` as long as both operands are `Real` and the result is exact, otherwise the result is a `Float`.
 #[derive(Debug, PartialOrd, PartialEq, Copy, Clone)]
 pub enum Number {
     Real(i64),
     Float(f64),
 }
 
+impl Number {
+    /// Returns the smaller of both numbers, the values are compared independent of their kind.
+    pub fn min(self, other: Number) -> Number {
+        if compare(&other, &self) == Ordering::Less {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the larger of both numbers, the values are compared independent of their kind.
+    pub fn max(self, other: Number) -> Number {
+        if compare(&other, &self) == Ordering::Greater {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Restricts the number to the given range.
+    pub fn clamp(self, min: Number, max: Number) -> Number {
+        self.max(min).min(max)
+    }
+}
+
+// compares the values of both numbers, a `NaN` is treated as equal to everything
+fn compare(first: &Number, second: &Number) -> Ordering {
+    match (first, second) {
+        (Number::Real(first), Number::Real(second)) => first.cmp(second),
+        _ => {
+            let (first, second): (f64, f64) = ((*first).into(), (*second).into());
+            first.partial_cmp(&second).unwrap_or(Ordering::Equal)
+        }
+    }
+}
+
 impl Default for Number {
     fn default() -> Self {
         Self::Real(0)
@@ -23,6 +67,80 @@ impl Neg for Number {
     }
 }
 
+/// The error that is returned if a string cannot be parsed to a `Number`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseNumberError {
+    text: String,
+}
+
+impl ParseNumberError {
+    /// Returns the text that could not be parsed.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+impl fmt::Display for ParseNumberError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "`{}` is not a valid number", self.text)
+    }
+}
+
+impl Error for ParseNumberError {}
+
+impl FromStr for Number {
+    type Err = ParseNumberError;
+
+    /// Parses integers to `Real` and numbers with a decimal point or an exponent to `Float`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let text = s.trim();
+        let error = || ParseNumberError {
+            text: s.to_string(),
+        };
+
+        if text.contains(&['.', 'e', 'E'][..]) {
+            lexical_core::parse(text.as_bytes())
+                .map(Number::Float)
+                .map_err(|_| error())
+        } else {
+            lexical_core::parse(text.as_bytes())
+                .map(Number::Real)
+                .map_err(|_| error())
+        }
+    }
+}
+
+// implements an operation that keeps `Real` if the integer operation returns an exact result
+macro_rules! impl_op {
+    ($trait:ident, $method:ident, $real:expr) => {
+        impl $trait for Number {
+            type Output = Number;
+
+            fn $method(self, rhs: Number) -> Self::Output {
+                if let (Number::Real(lhs), Number::Real(rhs)) = (self, rhs) {
+                    if let Some(result) = $real(lhs, rhs) {
+                        return Number::Real(result);
+                    }
+                }
+
+                let (lhs, rhs): (f64, f64) = (self.into(), rhs.into());
+                Number::Float(lhs.$method(rhs))
+            }
+        }
+    };
+}
+
+impl_op!(Add, add, i64::checked_add);
+impl_op!(Sub, sub, i64::checked_sub);
+impl_op!(Mul, mul, i64::checked_mul);
+impl_op!(Div, div, |lhs: i64, rhs: i64| {
+    if rhs != 0 && lhs.checked_rem(rhs) == Some(0) {
+        lhs.checked_div(rhs)
+    } else {
+        None
+    }
+});
+
 macro_rules! impl_float {
     ($t:ty) => {
         impl From<$t> for Number {
@@ -72,3 +190,70 @@ impl_real!(u32);
 impl_real!(i32);
 impl_real!(u64);
 impl_real!(i64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!(Number::Real(10) + Number::Real(5), Number::Real(15));
+        assert_eq!(Number::Real(10) - Number::Real(15), Number::Real(-5));
+        assert_eq!(Number::Real(10) * Number::Real(5), Number::Real(50));
+        assert_eq!(Number::Real(10) / Number::Real(5), Number::Real(2));
+
+        // inexact results are promoted
+        assert_eq!(Number::Real(10) / Number::Real(4), Number::Float(2.5));
+        assert_eq!(
+            Number::Real(i64::MAX) + Number::Real(1),
+            Number::Float(i64::MAX as f64 + 1.0)
+        );
+        assert_eq!(
+            Number::Real(1) / Number::Real(0),
+            Number::Float(f64::INFINITY)
+        );
+
+        // a float operand is promoted
+        assert_eq!(Number::Real(10) + Number::Float(0.5), Number::Float(10.5));
+        assert_eq!(Number::Float(1.5) * Number::Real(2), Number::Float(3.0));
+    }
+
+    #[test]
+    fn test_min_max_clamp() {
+        assert_eq!(Number::Real(3).min(Number::Float(2.5)), Number::Float(2.5));
+        assert_eq!(Number::Real(3).max(Number::Float(2.5)), Number::Real(3));
+        assert_eq!(
+            Number::Float(-1.0).max(Number::Real(-2)),
+            Number::Float(-1.0)
+        );
+
+        assert_eq!(
+            Number::Real(15).clamp(Number::Real(0), Number::Real(10)),
+            Number::Real(10)
+        );
+        assert_eq!(
+            Number::Real(5).clamp(Number::Real(0), Number::Float(10.0)),
+            Number::Real(5)
+        );
+        assert_eq!(
+            Number::Float(-0.5).clamp(Number::Real(0), Number::Real(10)),
+            Number::Real(0)
+        );
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("42".parse(), Ok(Number::Real(42)));
+        assert_eq!("-42".parse(), Ok(Number::Real(-42)));
+        assert_eq!("4.2".parse(), Ok(Number::Float(4.2)));
+        assert_eq!("4e2".parse(), Ok(Number::Float(400.0)));
+        assert_eq!(" 7 ".parse(), Ok(Number::Real(7)));
+
+        let error = "4.2.1".parse::<Number>().unwrap_err();
+        assert_eq!(error.text(), "4.2.1");
+        assert_eq!(error.to_string(), "`4.2.1` is not a valid number");
+
+        assert!("".parse::<Number>().is_err());
+        assert!("12px".parse::<Number>().is_err());
+    }
+}
@@ -1,5 +1,8 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use unicode_segmentation::UnicodeSegmentation;
+
+const NEW_LINE: u16 = b'\n' as u16;
 
 /// A UTF-16 encoded, growable string.
 ///
@@ -51,6 +54,11 @@ impl String16 {
         }
     }
 
+    /// Inserts the given UTF-16 units into this `String16` at a byte position.
+    pub fn insert_utf16(&mut self, idx: usize, units: &[u16]) {
+        self.utf16.splice(idx..idx, units.iter().cloned());
+    }
+
     /// Appends a given char onto the end of this `String16`.
     pub fn push(&mut self, ch: char) {
         let mut buf = [0; 2];
@@ -65,6 +73,11 @@ impl String16 {
         self.utf16.remove(idx);
     }
 
+    /// Removes the bytes from `start` to `end` from this `String16` and returns them.
+    pub fn remove_range(&mut self, start: usize, end: usize) -> Vec<u16> {
+        self.utf16.drain(start..end).collect()
+    }
+
     /// Returns `true` if this `String16` has a length of zero, and `false` otherwise.
     pub fn is_empty(&self) -> bool {
         self.utf16.is_empty()
@@ -85,6 +98,82 @@ impl String16 {
         self.utf16.get(start..end).map(String::from_utf16_lossy)
     }
 
+    /// Returns the number of lines of this `String16`. Lines are separated by `\n`, an empty string has one line.
+    pub fn line_count(&self) -> usize {
+        self.utf16.iter().filter(|u| **u == NEW_LINE).count() + 1
+    }
+
+    /// Returns the line with the given index without its `\n`, or an empty string if there is no such line.
+    pub fn line(&self, idx: usize) -> String {
+        if idx >= self.line_count() {
+            return String::default();
+        }
+
+        let start = self.line_to_char(idx);
+        let end = self.utf16[start..]
+            .iter()
+            .position(|u| *u == NEW_LINE)
+            .map_or(self.len(), |end| start + end);
+
+        String::from_utf16_lossy(&self.utf16[start..end])
+    }
+
+    /// Returns the index of the line that contains the given byte position.
+    pub fn char_to_line(&self, char_idx: usize) -> usize {
+        self.utf16[..char_idx.min(self.len())]
+            .iter()
+            .filter(|u| **u == NEW_LINE)
+            .count()
+    }
+
+    /// Returns the byte position of the start of the given line. Returns the length of this `String16` if there
+    /// is no such line.
+    pub fn line_to_char(&self, line_idx: usize) -> usize {
+        if line_idx == 0 {
+            return 0;
+        }
+
+        self.utf16
+            .iter()
+            .enumerate()
+            .filter(|(_, u)| **u == NEW_LINE)
+            .nth(line_idx - 1)
+            .map_or(self.len(), |(idx, _)| idx + 1)
+    }
+
+    /// Returns the byte position of the grapheme cluster boundary before the given position. Moving the caret
+    /// to this position moves it by one visible character, also if the character consists of several chars
+    /// like a flag or an emoji sequence.
+    pub fn prev_grapheme_boundary(&self, char_idx: usize) -> usize {
+        self.grapheme_boundaries()
+            .into_iter()
+            .rev()
+            .find(|boundary| *boundary < char_idx)
+            .unwrap_or(0)
+    }
+
+    /// Returns the byte position of the grapheme cluster boundary after the given position.
+    pub fn next_grapheme_boundary(&self, char_idx: usize) -> usize {
+        self.grapheme_boundaries()
+            .into_iter()
+            .find(|boundary| *boundary > char_idx)
+            .unwrap_or_else(|| self.len())
+    }
+
+    // returns the byte positions of the starts of all grapheme clusters and the end of the string
+    fn grapheme_boundaries(&self) -> Vec<usize> {
+        let mut boundaries = vec![];
+        let mut idx = 0;
+
+        for grapheme in self.as_string().graphemes(true) {
+            boundaries.push(idx);
+            idx += grapheme.encode_utf16().count();
+        }
+
+        boundaries.push(idx);
+        boundaries
+    }
+
     /// Converts the `String16` value to a String.
     pub fn as_string(&self) -> String {
         String::from_utf16_lossy(&self.utf16)
@@ -152,4 +241,64 @@ mod tests {
         string16.push('𝕊');
         assert_eq!(string16, String16::from("Bar𝕊"));
     }
+
+    #[test]
+    fn lines() {
+        let string16 = String16::from("Übung\n\nWorld𝕊\nEnd");
+        assert_eq!(string16.line_count(), 4);
+        assert_eq!(string16.line(0), "Übung");
+        assert_eq!(string16.line(1), "");
+        assert_eq!(string16.line(2), "World𝕊");
+        assert_eq!(string16.line(3), "End");
+        assert_eq!(string16.line(4), "");
+
+        assert_eq!(string16.line_to_char(0), 0);
+        assert_eq!(string16.line_to_char(1), 6);
+        assert_eq!(string16.line_to_char(2), 7);
+        assert_eq!(string16.line_to_char(3), 15);
+        assert_eq!(string16.line_to_char(4), string16.len());
+
+        // the new line belongs to the line it ends
+        assert_eq!(string16.char_to_line(0), 0);
+        assert_eq!(string16.char_to_line(5), 0);
+        assert_eq!(string16.char_to_line(6), 1);
+        assert_eq!(string16.char_to_line(7), 2);
+        assert_eq!(string16.char_to_line(string16.len()), 3);
+
+        // the results agree with the lines of the string
+        for text in &["", "\n", "a\n", "\nb", "a\r\nb\n\nc"] {
+            let string16 = String16::from(*text);
+            let lines: Vec<&str> = text.split('\n').collect();
+
+            assert_eq!(string16.line_count(), lines.len());
+
+            for (idx, line) in lines.iter().enumerate() {
+                assert_eq!(string16.line(idx), *line);
+                assert_eq!(string16.char_to_line(string16.line_to_char(idx)), idx);
+            }
+        }
+    }
+
+    #[test]
+    fn grapheme_boundaries() {
+        // family emoji ZWJ sequence (8 bytes), flag (4 bytes) and e with combining acute accent (2 bytes)
+        let string16 = String16::from(
+            "a\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{1F1E9}\u{1F1EA}e\u{301}",
+        );
+        assert_eq!(string16.len(), 15);
+
+        assert_eq!(string16.next_grapheme_boundary(0), 1);
+        assert_eq!(string16.next_grapheme_boundary(1), 9);
+        assert_eq!(string16.next_grapheme_boundary(4), 9);
+        assert_eq!(string16.next_grapheme_boundary(9), 13);
+        assert_eq!(string16.next_grapheme_boundary(13), 15);
+        assert_eq!(string16.next_grapheme_boundary(15), 15);
+
+        assert_eq!(string16.prev_grapheme_boundary(15), 13);
+        assert_eq!(string16.prev_grapheme_boundary(13), 9);
+        assert_eq!(string16.prev_grapheme_boundary(11), 9);
+        assert_eq!(string16.prev_grapheme_boundary(9), 1);
+        assert_eq!(string16.prev_grapheme_boundary(1), 0);
+        assert_eq!(string16.prev_grapheme_boundary(0), 0);
+    }
 }
@@ -1,4 +1,5 @@
 /// Text baseline being used when drawing text
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum TextBaseline {
     /// Text baseline is top of the em square
     Top,
@@ -0,0 +1,125 @@
+use crate::Point;
+
+/// A `Transform` is a 2D affine transformation. It uses the same components as the `set_transform` method of the
+/// render context: a point (x, y) is transformed to
+/// (`h_scaling * x + v_skewing * y + h_moving`, `h_skewing * x + v_scaling * y + v_moving`).
+///
+/// # Examples
+/// ```rust
+/// # use orbtk_utils::{Point, Transform};
+/// let transform = Transform::scale(2.0, 2.0);
+///
+/// assert_eq!(transform.apply(Point::new(10.0, 20.0)), Point::new(20.0, 40.0));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Transform {
+    pub h_scaling: f64,
+    pub h_skewing: f64,
+    pub v_skewing: f64,
+    pub v_scaling: f64,
+    pub h_moving: f64,
+    pub v_moving: f64,
+}
+
+impl Transform {
+    /// Creates a new transform from the given components.
+    pub fn new(
+        h_scaling: f64,
+        h_skewing: f64,
+        v_skewing: f64,
+        v_scaling: f64,
+        h_moving: f64,
+        v_moving: f64,
+    ) -> Self {
+        Transform {
+            h_scaling,
+            h_skewing,
+            v_skewing,
+            v_scaling,
+            h_moving,
+            v_moving,
+        }
+    }
+
+    /// Creates a transform that does not change points.
+    pub fn identity() -> Self {
+        Transform::new(1.0, 0.0, 0.0, 1.0, 0.0, 0.0)
+    }
+
+    /// Creates a scaling transform.
+    pub fn scale(x: f64, y: f64) -> Self {
+        Transform::new(x, 0.0, 0.0, y, 0.0, 0.0)
+    }
+
+    /// Creates a translation transform.
+    pub fn translate(x: f64, y: f64) -> Self {
+        Transform::new(1.0, 0.0, 0.0, 1.0, x, y)
+    }
+
+    /// Returns a transform that applies this transform first and `other` afterwards.
+    pub fn then(&self, other: &Transform) -> Transform {
+        Transform::new(
+            other.h_scaling * self.h_scaling + other.v_skewing * self.h_skewing,
+            other.h_skewing * self.h_scaling + other.v_scaling * self.h_skewing,
+            other.h_scaling * self.v_skewing + other.v_skewing * self.v_scaling,
+            other.h_skewing * self.v_skewing + other.v_scaling * self.v_scaling,
+            other.h_scaling * self.h_moving + other.v_skewing * self.v_moving + other.h_moving,
+            other.h_skewing * self.h_moving + other.v_scaling * self.v_moving + other.v_moving,
+        )
+    }
+
+    /// Returns the inverse transform, `None` if the transform cannot be inverted (e.g. a scale of zero).
+    pub fn inverse(&self) -> Option<Transform> {
+        let determinant = self.h_scaling * self.v_scaling - self.h_skewing * self.v_skewing;
+
+        if determinant.abs() < f64::EPSILON {
+            return None;
+        }
+
+        Some(Transform::new(
+            self.v_scaling / determinant,
+            -self.h_skewing / determinant,
+            -self.v_skewing / determinant,
+            self.h_scaling / determinant,
+            (self.v_skewing * self.v_moving - self.v_scaling * self.h_moving) / determinant,
+            (self.h_skewing * self.h_moving - self.h_scaling * self.v_moving) / determinant,
+        ))
+    }
+
+    /// Applies the transform to the given point.
+    pub fn apply(&self, point: impl Into<Point>) -> Point {
+        let point = point.into();
+
+        Point::new(
+            self.h_scaling * point.x() + self.v_skewing * point.y() + self.h_moving,
+            self.h_skewing * point.x() + self.v_scaling * point.y() + self.v_moving,
+        )
+    }
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Transform::identity()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply() {
+        let transform = Transform::scale(2.0, 3.0).then(&Transform::translate(10.0, 20.0));
+        assert_eq!(transform.apply((5.0, 5.0)), Point::new(20.0, 35.0));
+        assert_eq!(Transform::default().apply((5.0, 5.0)), Point::new(5.0, 5.0));
+    }
+
+    #[test]
+    fn test_inverse() {
+        let transform = Transform::scale(2.0, 4.0).then(&Transform::translate(10.0, 20.0));
+        let inverse = transform.inverse().unwrap();
+
+        assert_eq!(inverse.apply(transform.apply((7.0, 3.0))), Point::new(7.0, 3.0));
+        assert_eq!(Transform::scale(0.0, 1.0).inverse(), None);
+    }
+}
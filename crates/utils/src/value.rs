@@ -1,4 +1,7 @@
 use serde::de::DeserializeOwned;
+
+use crate::{Brush, Color, Expression, ExpressionError};
+
 /// Wraps a ron value and is used to support conversion to different types.
 pub struct Value(pub ron::Value);
 
@@ -14,6 +17,35 @@ impl Value {
 
         T::default()
     }
+
+    /// Parses a string value as color expression, e.g. `"#ff0000"`, `"red"` or `"hsl(0, 100%, 50%)"`. Returns
+    /// `None` if the value is not a string or not a color.
+    pub fn color(&self) -> Option<Color> {
+        self.expression()?.color()
+    }
+
+    /// Parses a string value as brush expression, which includes colors and gradients. Returns `None` if the
+    /// value is not a string or not a brush.
+    pub fn brush(&self) -> Option<Brush> {
+        self.expression()?.brush()
+    }
+
+    /// Parses a string value as brush expression like `brush`, but returns the error if the value is not a valid
+    /// expression, e.g. a method call that is not closed. `Ok(None)` means the value is not a string or not a
+    /// brush.
+    pub fn try_brush(&self) -> Result<Option<Brush>, ExpressionError> {
+        match &self.0 {
+            ron::Value::String(value) => Ok(Expression::try_parse(value)?.brush()),
+            _ => Ok(None),
+        }
+    }
+
+    fn expression(&self) -> Option<Expression> {
+        match &self.0 {
+            ron::Value::String(value) => Expression::try_parse(value).ok(),
+            _ => None,
+        }
+    }
 }
 
 impl From<ron::Value> for Value {
@@ -39,3 +71,87 @@ impl Into<f32> for Value {
         self.get::<f32>()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GradientStop;
+
+    fn value(s: &str) -> Value {
+        Value(ron::Value::String(s.to_string()))
+    }
+
+    #[test]
+    fn test_color() {
+        for s in &[
+            "#ff0000",
+            "red",
+            "rgba(255, 0, 0, 0.5)",
+            "hsl(120, 100%, 50%)",
+            "hsv(240, 100%, 100%)",
+        ] {
+            assert_eq!(value(s).color().map(Brush::from), Some(Brush::from(*s)));
+        }
+
+        assert_eq!(value("10px").color(), None);
+        assert_eq!(Value(ron::Value::Bool(true)).color(), None);
+    }
+
+    #[test]
+    fn test_brush() {
+        for s in &[
+            "#ff0000",
+            "linear-gradient(45deg, red, blue)",
+            "radial-gradient(circle at 20% 30%, red, blue)",
+        ] {
+            assert_eq!(value(s).brush(), Some(Brush::from(*s)));
+        }
+
+        assert_eq!(value("rgb(10").brush(), None);
+    }
+
+    #[test]
+    fn test_try_brush() {
+        assert_eq!(
+            value("#ff0000").try_brush(),
+            Ok(Some(Brush::from("#ff0000")))
+        );
+        assert_eq!(value("10px").try_brush(), Ok(None));
+        assert_eq!(Value(ron::Value::Bool(true)).try_brush(), Ok(None));
+
+        // a malformed theme value is reported
+        assert_eq!(
+            value("rgb(10").try_brush(),
+            Err(ExpressionError::UnterminatedMethod {
+                method: "rgb".to_string(),
+                position: 0
+            })
+        );
+        assert_eq!(
+            value("rgb(10, 20.5.3, 30)").try_brush(),
+            Err(ExpressionError::InvalidNumber {
+                text: "20.5.3".to_string(),
+                position: 8
+            })
+        );
+    }
+
+    #[test]
+    fn test_gradient() {
+        let expected = vec![
+            GradientStop::new(None, Color::from("#ff0000")),
+            GradientStop::new(None, Color::from("#0000ff")),
+        ];
+
+        match value("linear-gradient(to right, #ff0000, #0000ff)").brush() {
+            Some(Brush::Gradient(gradient)) => assert_eq!(gradient.stops, expected),
+            brush => panic!("expected a gradient, got {:?}", brush),
+        }
+
+        // the conversion that is used for theme properties
+        match Brush::from(value("linear-gradient(to right, #ff0000, #0000ff)")) {
+            Brush::Gradient(gradient) => assert_eq!(gradient.stops, expected),
+            brush => panic!("expected a gradient, got {:?}", brush),
+        }
+    }
+}
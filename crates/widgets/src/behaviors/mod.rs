@@ -4,7 +4,11 @@
 pub use self::mouse_behavior::*;
 pub use self::selection_behavior::*;
 pub use self::text_behavior::*;
+pub use self::window_drag_behavior::*;
+pub use self::window_resize_behavior::*;
 
 mod mouse_behavior;
 mod selection_behavior;
 mod text_behavior;
+mod window_drag_behavior;
+mod window_resize_behavior;
@@ -0,0 +1,84 @@
+use crate::{
+    api::prelude::*,
+    proc_macros::*,
+    shell::prelude::{MouseButton, WindowRequest},
+};
+
+#[derive(Debug, Copy, Clone)]
+enum Action {
+    Drag,
+}
+
+/// The `WindowDragBehaviorState` handles the `WindowDragBehavior` widget.
+#[derive(Default, AsAny)]
+pub struct WindowDragBehaviorState;
+
+impl State for WindowDragBehaviorState {
+    fn messages(
+        &mut self,
+        mut messages: MessageReader,
+        _registry: &mut Registry,
+        ctx: &mut Context,
+    ) {
+        for message in messages.read::<Action>() {
+            match message {
+                Action::Drag => ctx.send_window_request(WindowRequest::DragWindow),
+            }
+        }
+    }
+}
+
+widget!(
+    /// The `WindowDragBehavior` widget is used to move the window by dragging its content with the
+    /// left mouse button e.g. a custom title bar of a borderless window.
+    ///
+    /// **style:** `window-drag-behavior`
+    WindowDragBehavior<WindowDragBehaviorState>: MouseHandler
+);
+
+impl Template for WindowDragBehavior {
+    fn template(self, id: Entity, _: &mut BuildContext) -> Self {
+        self.name("WindowDragBehavior")
+            .on_mouse_down(move |ctx, m| {
+                if m.button == MouseButton::Left {
+                    ctx.send_message(Action::Drag, id);
+                }
+                false
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{mock_shell::MockShell, Window};
+
+    #[test]
+    fn test_drag_window() {
+        // the drag region is a title bar at the top of the window
+        let mut shell = MockShell::new(|ctx| {
+            Window::new()
+                .borderless(true)
+                .child(
+                    WindowDragBehavior::new()
+                        .height(20.0)
+                        .v_align("start")
+                        .build(ctx),
+                )
+                .build(ctx)
+        });
+
+        // pressing outside of the drag region does not move the window
+        shell.mouse_down(50.0, 50.0);
+        assert!(!shell.is_dragging());
+        shell.mouse_up(50.0, 50.0);
+        assert!(shell.requests().is_empty());
+
+        shell.mouse_down(50.0, 10.0);
+        assert_eq!(shell.requests(), &[WindowRequest::DragWindow]);
+        assert!(shell.is_dragging());
+
+        shell.mouse_up(50.0, 10.0);
+        assert!(!shell.is_dragging());
+    }
+}
@@ -0,0 +1,124 @@
+use crate::{
+    api::prelude::*,
+    proc_macros::*,
+    shell::prelude::{MouseButton, ResizeDirection, WindowRequest},
+};
+
+#[derive(Debug, Copy, Clone)]
+enum Action {
+    Resize(Point),
+}
+
+/// The `WindowResizeBehaviorState` handles the `WindowResizeBehavior` widget.
+#[derive(Default, AsAny)]
+pub struct WindowResizeBehaviorState;
+
+impl State for WindowResizeBehaviorState {
+    fn messages(
+        &mut self,
+        mut messages: MessageReader,
+        _registry: &mut Registry,
+        ctx: &mut Context,
+    ) {
+        for message in messages.read::<Action>() {
+            match message {
+                Action::Resize(position) => {
+                    let direction = {
+                        let widget = ctx.widget();
+                        let bounds = Rectangle::new(
+                            WindowResizeBehavior::position_clone(&widget),
+                            WindowResizeBehavior::bounds_clone(&widget).size(),
+                        );
+
+                        resize_direction(
+                            position,
+                            bounds,
+                            WindowResizeBehavior::resize_border_clone(&widget),
+                        )
+                    };
+
+                    if let Some(direction) = direction {
+                        ctx.send_window_request(WindowRequest::DragResizeWindow(direction));
+                    }
+                }
+            }
+        }
+    }
+}
+
+// Returns the resize direction of the edge or corner region of bounds that contains the position.
+fn resize_direction(position: Point, bounds: Rectangle, border: f64) -> Option<ResizeDirection> {
+    if !bounds.contains(position) {
+        return None;
+    }
+
+    let west = position.x() < bounds.x() + border;
+    let east = position.x() >= bounds.x() + bounds.width() - border;
+    let north = position.y() < bounds.y() + border;
+    let south = position.y() >= bounds.y() + bounds.height() - border;
+
+    match (north, east, south, west) {
+        (true, true, _, _) => Some(ResizeDirection::NorthEast),
+        (true, _, _, true) => Some(ResizeDirection::NorthWest),
+        (_, true, true, _) => Some(ResizeDirection::SouthEast),
+        (_, _, true, true) => Some(ResizeDirection::SouthWest),
+        (true, _, _, _) => Some(ResizeDirection::North),
+        (_, true, _, _) => Some(ResizeDirection::East),
+        (_, _, true, _) => Some(ResizeDirection::South),
+        (_, _, _, true) => Some(ResizeDirection::West),
+        _ => None,
+    }
+}
+
+widget!(
+    /// The `WindowResizeBehavior` widget is used to resize a (borderless) window by dragging the
+    /// edges of its content with the left mouse button.
+    ///
+    /// **style:** `window-resize-behavior`
+    WindowResizeBehavior<WindowResizeBehaviorState>: MouseHandler {
+        /// Sets or shares the width of the edge regions that resize the window.
+        resize_border: f64
+    }
+);
+
+impl Template for WindowResizeBehavior {
+    fn template(self, id: Entity, _: &mut BuildContext) -> Self {
+        self.name("WindowResizeBehavior")
+            .resize_border(4.0)
+            .on_mouse_down(move |ctx, m| {
+                if m.button == MouseButton::Left {
+                    ctx.send_message(Action::Resize(m.position), id);
+                }
+                false
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resize_direction() {
+        let bounds = Rectangle::new((10.0, 10.0), (100.0, 50.0));
+
+        assert_eq!(
+            resize_direction(Point::new(108.0, 58.0), bounds, 4.0),
+            Some(ResizeDirection::SouthEast)
+        );
+        assert_eq!(
+            resize_direction(Point::new(10.0, 10.0), bounds, 4.0),
+            Some(ResizeDirection::NorthWest)
+        );
+        assert_eq!(
+            resize_direction(Point::new(50.0, 11.0), bounds, 4.0),
+            Some(ResizeDirection::North)
+        );
+        assert_eq!(
+            resize_direction(Point::new(12.0, 30.0), bounds, 4.0),
+            Some(ResizeDirection::West)
+        );
+        assert_eq!(resize_direction(Point::new(50.0, 30.0), bounds, 4.0), None);
+        assert_eq!(resize_direction(Point::new(0.0, 0.0), bounds, 4.0), None);
+    }
+}
@@ -129,3 +129,155 @@ impl Template for Button {
             )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use super::*;
+    use crate::{mock_shell::MockShell, Container, Window};
+
+    widget!(
+        // an empty widget that only handles the mouse
+        Overlay: MouseHandler
+    );
+
+    impl Template for Overlay {}
+
+    // creates a window with a button in its upper left corner that counts its clicks
+    fn button_window(event_filter: EventFilter, clicks: Rc<Cell<usize>>) -> MockShell {
+        MockShell::with_event_filter(event_filter, move |ctx| {
+            let clicks = clicks.clone();
+
+            Window::new()
+                .child(
+                    Button::new()
+                        .h_align("start")
+                        .v_align("start")
+                        .on_click(move |_, _| {
+                            clicks.set(clicks.get() + 1);
+                            true
+                        })
+                        .build(ctx),
+                )
+                .build(ctx)
+        })
+    }
+
+    #[test]
+    fn test_filtered_click() {
+        let clicks = Rc::new(Cell::new(0));
+        let mut shell = button_window(EventFilter::new(), clicks.clone());
+        shell.click(12.0, 12.0);
+        assert_eq!(clicks.get(), 1);
+
+        // the filter consumes the mouse events, so the click never reaches the handler of the button
+        let event_filter = EventFilter::new();
+        event_filter.add(|event| {
+            if event.is_type::<MouseDownEvent>() || event.is_type::<MouseUpEvent>() {
+                return FilterAction::Consume;
+            }
+
+            FilterAction::Pass
+        });
+
+        let clicks = Rc::new(Cell::new(0));
+        let mut shell = button_window(event_filter, clicks.clone());
+        shell.click(12.0, 12.0);
+        assert_eq!(clicks.get(), 0);
+    }
+
+    #[test]
+    fn test_transformed_click() {
+        let clicks = Rc::new(Cell::new(0));
+        let counter = clicks.clone();
+
+        // the container draws the button with twice its size
+        let mut shell = MockShell::new(move |ctx| {
+            let clicks = counter.clone();
+
+            Window::new()
+                .width(200.0)
+                .height(200.0)
+                .child(
+                    Container::new()
+                        .render_transform(Transform::scale(2.0, 2.0))
+                        .child(
+                            Button::new()
+                                .width(40.0)
+                                .height(40.0)
+                                .h_align("start")
+                                .v_align("start")
+                                .on_click(move |_, _| {
+                                    clicks.set(clicks.get() + 1);
+                                    true
+                                })
+                                .build(ctx),
+                        )
+                        .build(ctx),
+                )
+                .build(ctx)
+        });
+
+        // (70, 70) is (35, 35) inside of the button
+        shell.click(70.0, 70.0);
+        assert_eq!(clicks.get(), 1);
+
+        // (90, 90) is (45, 45) outside of the button
+        shell.click(90.0, 90.0);
+        assert_eq!(clicks.get(), 1);
+    }
+
+    #[test]
+    fn test_click_through_overlay() {
+        // creates a window with an overlay over the whole window in front of a button, both count their clicks
+        let overlay_window = |hit_testable: bool| {
+            let button_clicks = Rc::new(Cell::new(0));
+            let overlay_clicks = Rc::new(Cell::new(0));
+            let (button_counter, overlay_counter) = (button_clicks.clone(), overlay_clicks.clone());
+
+            let shell = MockShell::new(move |ctx| {
+                let (button_clicks, overlay_clicks) =
+                    (button_counter.clone(), overlay_counter.clone());
+
+                Window::new()
+                    .width(200.0)
+                    .height(200.0)
+                    .child(
+                        Button::new()
+                            .h_align("start")
+                            .v_align("start")
+                            .on_click(move |_, _| {
+                                button_clicks.set(button_clicks.get() + 1);
+                                true
+                            })
+                            .build(ctx),
+                    )
+                    .child(
+                        Overlay::new()
+                            .hit_testable(hit_testable)
+                            .on_click(move |_, _| {
+                                overlay_clicks.set(overlay_clicks.get() + 1);
+                                false
+                            })
+                            .build(ctx),
+                    )
+                    .build(ctx)
+            });
+
+            (shell, button_clicks, overlay_clicks)
+        };
+
+        // the click passes through the overlay to the button behind it
+        let (mut shell, button_clicks, overlay_clicks) = overlay_window(false);
+        shell.click(12.0, 12.0);
+        assert_eq!(button_clicks.get(), 1);
+        assert_eq!(overlay_clicks.get(), 0);
+
+        // the click is delivered to every hit widget with a handler until one of them handles it
+        let (mut shell, button_clicks, overlay_clicks) = overlay_window(true);
+        shell.click(12.0, 12.0);
+        assert_eq!(button_clicks.get(), 1);
+        assert_eq!(overlay_clicks.get(), 1);
+    }
+}
@@ -2,9 +2,39 @@ use crate::{api::prelude::*, proc_macros::*};
 
 widget!(
     /// Canvas is used to render 3D graphics.
+    ///
+    /// Its children are placed at the absolute positions given by their `left`, `top`, `right` and `bottom`
+    /// attached properties, there is no automatic flow.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// Canvas::new()
+    ///     .child(
+    ///         Button::new()
+    ///             .attach(Canvas::left(30.0))
+    ///             .attach(Canvas::top(40.0))
+    ///             .build(ctx),
+    ///     )
+    ///     .build(ctx)
+    /// ```
     Canvas {
         /// Sets or shares the render pipeline.
         render_pipeline: DefaultRenderPipeline
+
+        attached_properties: {
+            /// Attach the distance between the left edge of the canvas and the widget.
+            left: f64,
+
+            /// Attach the distance between the top edge of the canvas and the widget.
+            top: f64,
+
+            /// Attach the distance between the right edge of the canvas and the widget.
+            right: f64,
+
+            /// Attach the distance between the bottom edge of the canvas and the widget.
+            bottom: f64
+        }
     }
 );
 
@@ -16,4 +46,42 @@ impl Template for Canvas {
     fn render_object(&self) -> Box<dyn RenderObject> {
         PipelineRenderObject.into()
     }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        CanvasLayout::new().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{mock_shell::MockShell, Container, Window};
+
+    #[test]
+    fn test_child_position() {
+        let mut shell = MockShell::new(|ctx| {
+            Window::new()
+                .width(200.0)
+                .height(200.0)
+                .child(
+                    Canvas::new()
+                        .child(
+                            Container::new()
+                                .id("child")
+                                .width(50.0)
+                                .height(20.0)
+                                .attach(Canvas::left(30.0))
+                                .attach(Canvas::top(40.0))
+                                .build(ctx),
+                        )
+                        .build(ctx),
+                )
+                .build(ctx)
+        });
+
+        // the child keeps its size at the given position
+        let bounds = shell.get::<Rectangle>("child", "bounds");
+        assert_eq!(bounds.position(), Point::new(30.0, 40.0));
+        assert_eq!(bounds.size(), Size::new(50.0, 20.0));
+    }
 }
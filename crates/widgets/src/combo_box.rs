@@ -446,6 +446,7 @@ impl Template for ComboBox {
                     .view_port_bounds(("bounds", scroll_viewer))
                     .scroll_padding(("padding", scroll_viewer))
                     .mode(scroll_viewer)
+                    .scroll_bar_visibility(scroll_viewer)
                     .opacity(id)
                     .build(ctx),
             )
@@ -15,8 +15,20 @@ widget!(
         /// Sets or shares the border brush property.
         border_brush: Brush,
 
+        /// Sets or shares the box shadow property.
+        box_shadow: BoxShadow,
+
+        /// Sets or shares the backdrop blur property. It blurs the already rendered content behind the
+        /// widget by the given radius, `0.0` disables the blur. The blur is calculated on each redraw,
+        /// which is expensive for large widgets.
+        backdrop_blur: f64,
+
         /// Sets or shares the padding property.
-        padding: Thickness
+        padding: Thickness,
+
+        /// Sets or shares the max width of the content. If the container is wider, the content is limited to
+        /// this width and centered like a reading column. `0.0` does not limit the content.
+        max_content_width: f64
     }
 );
 
@@ -38,3 +50,37 @@ impl Template for Container {
         PaddingLayout::new().into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{mock_shell::MockShell, Window};
+
+    #[test]
+    fn test_max_content_width() {
+        let mut shell = MockShell::new(|ctx| {
+            Window::new()
+                .width(1000.0)
+                .height(200.0)
+                .child(
+                    Container::new()
+                        .padding(10.0)
+                        .max_content_width(600.0)
+                        .child(
+                            Container::new()
+                                .id("content")
+                                .child(Container::new().width(800.0).build(ctx))
+                                .build(ctx),
+                        )
+                        .build(ctx),
+                )
+                .build(ctx)
+        });
+
+        // the content is limited to 600 and centered with 200 on each side
+        let bounds = shell.get::<Rectangle>("content", "bounds");
+        assert!((bounds.x() - 200.0).abs() < f64::EPSILON);
+        assert!((bounds.width() - 600.0).abs() < f64::EPSILON);
+        assert!((1000.0 - bounds.x() - bounds.width() - 200.0).abs() < f64::EPSILON);
+    }
+}
@@ -0,0 +1,44 @@
+use crate::{api::prelude::*, proc_macros::*};
+
+widget!(
+    /// The `DockPanel` docks its children to its edges in the order they are added. Each child takes the space
+    /// it needs at the edge given by its `dock` attached property from the remaining area, the last child fills
+    /// the area that is left in the center.
+    ///
+    /// **style:** `dock_panel`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// DockPanel::new()
+    ///     .child(Toolbar::new().attach(DockPanel::dock("top")).build(ctx))
+    ///     .child(StatusBar::new().attach(DockPanel::dock("bottom")).build(ctx))
+    ///     .child(TextBox::new().build(ctx))
+    ///     .build(ctx)
+    /// ```
+    DockPanel {
+        /// Sets or shares the background property.
+        background: Brush
+
+        attached_properties: {
+            /// Attach the edge the widget is docked to.
+            dock: Dock
+        }
+    }
+);
+
+impl Template for DockPanel {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("DockPanel")
+            .style("dock_panel")
+            .background("transparent")
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        RectangleRenderObject.into()
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        DockLayout::new().into()
+    }
+}
@@ -15,7 +15,10 @@ widget!(
         rows: Blocks,
 
         /// Sets or shares the border radius property.
-        border_radius: f64
+        border_radius: f64,
+
+        /// Sets or shares the flow direction, right to left places the first column at the right edge.
+        flow_direction: FlowDirection
 
         attached_properties: {
             /// Attach a column position to a widget.
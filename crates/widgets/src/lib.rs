@@ -16,12 +16,14 @@ pub use self::check_box::*;
 pub use self::combo_box::*;
 pub use self::container::*;
 pub use self::cursor::*;
+pub use self::dock_panel::*;
 pub use self::font_icon_block::*;
 pub use self::grid::*;
 pub use self::image_widget::*;
 pub use self::items_widget::*;
 pub use self::list_view::*;
 pub use self::master_detail::*;
+pub use self::menu::*;
 pub use self::numeric_box::*;
 pub use self::pager::*;
 pub use self::password_box::*;
@@ -31,12 +33,17 @@ pub use self::scroll_bar::*;
 pub use self::scroll_indicator::*;
 pub use self::scroll_viewer::*;
 pub use self::slider::*;
+pub use self::split_view::*;
 pub use self::stack::*;
+pub use self::status_bar::*;
 pub use self::switch::*;
 pub use self::tab_widget::*;
 pub use self::text_block::*;
 pub use self::text_box::*;
 pub use self::toggle_button::*;
+pub use self::toolbar::*;
+pub use self::tree_view::*;
+pub use self::uniform_grid::*;
 pub use self::window::*;
 
 pub mod behaviors;
@@ -46,12 +53,16 @@ mod check_box;
 mod combo_box;
 mod container;
 mod cursor;
+mod dock_panel;
 mod font_icon_block;
 mod grid;
 mod image_widget;
 mod items_widget;
 mod list_view;
 mod master_detail;
+mod menu;
+#[cfg(test)]
+mod mock_shell;
 mod numeric_box;
 mod pager;
 mod password_box;
@@ -61,10 +72,15 @@ mod scroll_bar;
 mod scroll_indicator;
 mod scroll_viewer;
 mod slider;
+mod split_view;
 mod stack;
+mod status_bar;
 mod switch;
 mod tab_widget;
 mod text_block;
 mod text_box;
 mod toggle_button;
+mod toolbar;
+mod tree_view;
+mod uniform_grid;
 mod window;
@@ -1,68 +1,179 @@
-use std::{
-    cell::Cell,
-    collections::{HashMap, HashSet},
-};
+use std::{cell::Cell, collections::HashSet, ops::Range};
 
 use super::behaviors::MouseBehavior;
-use crate::{api::prelude::*, prelude::*, proc_macros::*, theme_default::prelude::*};
+use crate::{
+    api::prelude::*, prelude::*, proc_macros::*, shell::prelude::WindowRequest,
+    theme_default::prelude::*,
+};
 
 static ITEMS_PANEL: &str = "items_panel";
+static SCROLL_VIEWER: &str = "scroll_viewer";
 
 /// The `ListViewState` generates the list box items and handles the selected indices.
 #[derive(Default, AsAny)]
 pub struct ListViewState {
     builder: WidgetBuildContext,
     count: usize,
-    selected_entities: RefCell<HashSet<Entity>>,
+    // materialized items with their index
+    items: Vec<(usize, Entity)>,
+    range: Range<usize>,
     items_panel: Entity,
+    scroll_viewer: Entity,
+    scroll_to: Option<usize>,
+    select: Option<usize>,
 }
 
 impl ListViewState {
-    fn generate_items(&mut self, ctx: &mut Context) {
+    /// Scrolls the item with the given index into view.
+    pub fn scroll_to(&mut self, index: usize) {
+        self.scroll_to = Some(index);
+    }
+
+    /// Selects the item with the given index, also if it is not materialized.
+    pub fn select(&mut self, index: usize) {
+        self.select = Some(index);
+    }
+
+    // returns the range of the items that should be materialized
+    fn range(&self, ctx: &mut Context) -> Range<usize> {
+        let count = ctx.widget().clone_or_default::<usize>("count");
+
+        if !*ctx.widget().get::<bool>("virtualized") {
+            return 0..count;
+        }
+
+        let item_height = *ctx.widget().get::<f64>("item_height");
+        let scroll_viewer = ctx.get_widget(self.scroll_viewer);
+
+        visible_range(
+            scroll_viewer.get::<Thickness>("padding").top(),
+            scroll_viewer.get::<Rectangle>("bounds").height(),
+            item_height,
+            count,
+        )
+    }
+
+    fn scroll_into_view(&mut self, index: usize, ctx: &mut Context) {
         let count = ctx.widget().clone_or_default::<usize>("count");
+        let item_height = *ctx.widget().get::<f64>("item_height");
+        let mut scroll_viewer = ctx.get_widget(self.scroll_viewer);
+        let view_height = scroll_viewer.get::<Rectangle>("bounds").height();
+        let mut padding = *scroll_viewer.get::<Thickness>("padding");
+
+        let offset = scroll_offset_to(index, padding.top(), view_height, item_height);
+        padding.set_top(
+            offset
+                .max(view_height - count as f64 * item_height)
+                .min(0.0),
+        );
+        scroll_viewer.set("padding", padding);
+    }
+
+    fn select_index(&mut self, index: usize, ctx: &mut Context) {
         let entity = ctx.entity();
+        let selection_mode = *ctx.widget().get::<SelectionMode>("selection_mode");
+
+        if !select_index(
+            &mut ctx
+                .widget()
+                .get_mut::<SelectedIndices>("selected_indices")
+                .0,
+            selection_mode,
+            index,
+        ) {
+            return;
+        }
 
-        if count != self.count || *ctx.widget().get::<bool>("request_update") {
-            ctx.widget().set("request_update", false);
-            if let Some(builder) = &self.builder {
-                ctx.clear_children_of(self.items_panel);
-
-                for i in 0..count {
-                    let item = {
-                        let build_context = &mut ctx.build_context();
-                        let child = builder(build_context, i);
-                        let item = ListViewItem::new().parent(entity.0).build(build_context);
-
-                        let mouse_behavior =
-                            MouseBehavior::new().target(item.0).build(build_context);
-                        build_context.register_shared_property::<Selector>(
-                            "selector",
-                            mouse_behavior,
-                            item,
-                        );
-                        build_context.register_shared_property::<bool>(
-                            "pressed",
-                            mouse_behavior,
-                            item,
-                        );
-                        build_context.append_child(item, mouse_behavior);
-
-                        build_context.register_shared_property::<Brush>("foreground", child, item);
-                        build_context.register_shared_property::<f32>("opacity", item, entity);
-                        build_context.register_shared_property::<f32>("opacity", child, entity);
-                        build_context.register_shared_property::<f64>("font_size", child, item);
-                        build_context.register_shared_property::<f64>("font", child, item);
-                        build_context.append_child(self.items_panel, item);
-                        build_context.append_child(mouse_behavior, child);
-
-                        item
-                    };
-                    ctx.get_widget(item).update_widget(entity, false, false);
-                }
+        let selected_indices: Vec<usize> = ctx
+            .widget()
+            .get::<SelectedIndices>("selected_indices")
+            .0
+            .iter()
+            .copied()
+            .collect();
+
+        ctx.event_adapter()
+            .push_event_direct(entity, SelectionChangedEvent(entity, selected_indices));
+    }
+
+    // generates the items of the current range, returns `true` if the items are regenerated
+    fn generate_items(&mut self, ctx: &mut Context) -> bool {
+        let count = ctx.widget().clone_or_default::<usize>("count");
+        let virtualized = *ctx.widget().get::<bool>("virtualized");
+        let item_height = *ctx.widget().get::<f64>("item_height");
+        let entity = ctx.entity();
+        let range = self.range(ctx);
+
+        if count == self.count
+            && !*ctx.widget().get::<bool>("request_update")
+            && range == self.range
+        {
+            return false;
+        }
+
+        ctx.widget().set("request_update", false);
+
+        if let Some(builder) = &self.builder {
+            ctx.clear_children_of(self.items_panel);
+            self.items.clear();
+
+            // spacers replaces the items that are not materialized
+            if virtualized {
+                let build_context = &mut ctx.build_context();
+                let spacer = Container::new()
+                    .height(range.start as f64 * item_height)
+                    .build(build_context);
+                build_context.append_child(self.items_panel, spacer);
             }
 
-            self.count = count;
+            for i in range.clone() {
+                let item = {
+                    let build_context = &mut ctx.build_context();
+                    let child = builder(build_context, i);
+                    let mut item = ListViewItem::new().parent(entity.0).index(i);
+
+                    if virtualized {
+                        item = item.height(item_height);
+                    }
+
+                    let item = item.build(build_context);
+
+                    let mouse_behavior = MouseBehavior::new().target(item.0).build(build_context);
+                    build_context.register_shared_property::<Selector>(
+                        "selector",
+                        mouse_behavior,
+                        item,
+                    );
+                    build_context.register_shared_property::<bool>("pressed", mouse_behavior, item);
+                    build_context.append_child(item, mouse_behavior);
+
+                    build_context.register_shared_property::<Brush>("foreground", child, item);
+                    build_context.register_shared_property::<f32>("opacity", item, entity);
+                    build_context.register_shared_property::<f32>("opacity", child, entity);
+                    build_context.register_shared_property::<f64>("font_size", child, item);
+                    build_context.register_shared_property::<f64>("font", child, item);
+                    build_context.append_child(self.items_panel, item);
+                    build_context.append_child(mouse_behavior, child);
+
+                    item
+                };
+                ctx.get_widget(item).update_widget(entity, false, false);
+                self.items.push((i, item));
+            }
+
+            if virtualized {
+                let build_context = &mut ctx.build_context();
+                let spacer = Container::new()
+                    .height((count - range.end) as f64 * item_height)
+                    .build(build_context);
+                build_context.append_child(self.items_panel, spacer);
+            }
         }
+
+        self.count = count;
+        self.range = range;
+
+        true
     }
 }
 
@@ -71,29 +182,51 @@ impl State for ListViewState {
         self.items_panel = ctx
             .entity_of_child(ITEMS_PANEL)
             .expect("ListViewState.init: ItemsPanel child could not be found.");
+        self.scroll_viewer = ctx
+            .entity_of_child(SCROLL_VIEWER)
+            .expect("ListViewState.init: ScrollViewer child could not be found.");
 
         self.generate_items(ctx);
     }
 
     fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if let Some(index) = self.scroll_to.take() {
+            self.scroll_into_view(index, ctx);
+        }
+
+        if let Some(index) = self.select.take() {
+            self.select_index(index, ctx);
+        }
+
         self.generate_items(ctx);
     }
 
     fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
-        for index in ctx
+        // the visible range depends on the size of the scroll viewer that is known after layout
+        if *ctx.widget().get::<bool>("virtualized") && self.generate_items(ctx) {
+            ctx.send_window_request(WindowRequest::Redraw);
+        }
+
+        let selected_indices = ctx
             .widget()
-            .get::<SelectedEntities>("selected_entities")
+            .get::<SelectedIndices>("selected_indices")
             .0
-            .clone()
-            .symmetric_difference(&*self.selected_entities.borrow())
-        {
-            let mut widget = ctx.get_widget(*index);
+            .clone();
+        let mut selected_entities = HashSet::new();
+
+        for (index, item) in &self.items {
+            let selected = selected_indices.contains(index);
+
+            if selected {
+                selected_entities.insert(*item);
+            }
+
+            let mut widget = ctx.get_widget(*item);
 
-            if !widget.has::<bool>("selected") {
+            if !widget.has::<bool>("selected") || *widget.get::<bool>("selected") == selected {
                 continue;
             }
 
-            let selected = !widget.get::<bool>("selected");
             widget.set("selected", selected);
 
             if selected {
@@ -109,11 +242,10 @@ impl State for ListViewState {
             widget.update(false);
         }
 
-        *self.selected_entities.borrow_mut() = ctx
-            .widget()
-            .get::<SelectedEntities>("selected_entities")
-            .0
-            .clone();
+        if ctx.widget().get::<SelectedEntities>("selected_entities").0 != selected_entities {
+            ctx.widget()
+                .set("selected_entities", SelectedEntities(selected_entities));
+        }
     }
 }
 
@@ -139,7 +271,7 @@ impl State for ListViewItemState {
         let selected = *ctx.widget().get::<bool>("selected");
 
         let entity = ctx.entity();
-        let index = ctx.index_as_child(entity).unwrap();
+        let index = *ctx.widget().get::<usize>("index");
 
         let parent_entity: Entity = (*ctx.widget().get::<u32>("parent")).into();
 
@@ -240,6 +372,9 @@ widget!(
         /// Sets or shares the parent id.
         parent: u32,
 
+        /// Sets or shares the index of the item in the list.
+        index: usize,
+
         /// Indicates if the widget is hovered by the mouse cursor.
         hover: bool
     }
@@ -313,13 +448,23 @@ widget!(
         selection_mode: SelectionMode,
 
         /// Sets or shares the selected indices.
-        selected_indices: SelectedIndices,
+        selected_indices: SelectedIndices as selected_index,
 
         /// Sets or shares the list of selected indices.
-        selected_entities: SelectedEntities,
+        selected_entities: SelectedEntities as selected_entity,
 
         /// Use this flag to force the redrawing of the items.
-        request_update: bool
+        request_update: bool,
+
+        /// Sets or shares the virtualized flag. If it is set only the visible items are materialized. Virtualization
+        /// expects items with the same height and a vertical orientation.
+        virtualized: bool,
+
+        /// Sets or shares the height of an item, that is used by virtualization.
+        item_height: f64,
+
+        /// Sets or shares the scroll padding of the items panel.
+        scroll_padding: Thickness
     }
 );
 
@@ -343,6 +488,7 @@ impl Template for ListView {
             .build(ctx);
 
         let scroll_viewer = ScrollViewer::new()
+            .id(SCROLL_VIEWER)
             .mode(("disabled", "auto"))
             .child(items_panel)
             .build(ctx);
@@ -358,6 +504,9 @@ impl Template for ListView {
             .selected_indices(HashSet::new())
             .selected_entities(HashSet::new())
             .orientation("vertical")
+            .item_height(24.0)
+            // the list view is updated if the scroll viewer scrolls, to update the materialized items
+            .scroll_padding(("padding", scroll_viewer))
             .child(
                 Container::new()
                     .background(id)
@@ -374,6 +523,7 @@ impl Template for ListView {
                             .view_port_bounds(("bounds", scroll_viewer))
                             .scroll_padding(("padding", scroll_viewer))
                             .mode(scroll_viewer)
+                            .scroll_bar_visibility(scroll_viewer)
                             .opacity(id)
                             .build(ctx),
                     )
@@ -381,3 +531,137 @@ impl Template for ListView {
             )
     }
 }
+
+// --- Helpers --
+
+// returns the range of the items that are visible in the view port with the given scroll offset
+fn visible_range(offset: f64, view_height: f64, item_height: f64, count: usize) -> Range<usize> {
+    if item_height <= 0.0 {
+        return 0..count;
+    }
+
+    let start = ((-offset / item_height).floor().max(0.0) as usize).min(count);
+    let end = (((view_height - offset) / item_height).ceil().max(0.0) as usize + 1).min(count);
+
+    start..end.max(start)
+}
+
+// returns the scroll offset that brings the item with the given index into the view port
+fn scroll_offset_to(index: usize, offset: f64, view_height: f64, item_height: f64) -> f64 {
+    let top = index as f64 * item_height;
+    let bottom = top + item_height;
+
+    if top < -offset {
+        return -top;
+    }
+
+    if bottom > view_height - offset {
+        return view_height - bottom;
+    }
+
+    offset
+}
+
+// selects the given index depending on the selection mode, returns `true` if the selection is changed
+fn select_index(
+    selected_indices: &mut HashSet<usize>,
+    selection_mode: SelectionMode,
+    index: usize,
+) -> bool {
+    if selection_mode == SelectionMode::None || selected_indices.contains(&index) {
+        return false;
+    }
+
+    if selection_mode == SelectionMode::Single {
+        selected_indices.clear();
+    }
+
+    selected_indices.insert(index)
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{mock_shell::MockShell, shell::prelude::Key};
+
+    #[test]
+    fn test_virtualization() {
+        let mut shell = MockShell::new(|ctx| {
+            Window::new()
+                .width(300.0)
+                .height(300.0)
+                .child(
+                    ListView::new()
+                        .id("list_view")
+                        .width(200.0)
+                        .height(244.0)
+                        .h_align("start")
+                        .v_align("start")
+                        .virtualized(true)
+                        .count(1000)
+                        .items_builder(|ctx, index| {
+                            TextBlock::new()
+                                .id(format!("item_{}", index))
+                                .text(format!("Item {}", index))
+                                .build(ctx)
+                        })
+                        .build(ctx),
+                )
+                .build(ctx)
+        });
+
+        // the visible range is known after the first layout
+        shell.run();
+
+        // only the visible items of a 1000 items list are materialized, spacers replace the other items
+        assert_eq!(shell.widget(ITEMS_PANEL).children_count(), Some(11 + 2));
+        assert!(shell.contains("item_10"));
+        assert!(!shell.contains("item_11"));
+
+        // the click selects the first item and focuses the scroll viewer
+        shell.click(50.0, 12.0);
+        let selected_indices = shell.get::<SelectedIndices>("list_view", "selected_indices");
+        assert!(selected_indices.0.contains(&0));
+
+        // the last items are materialized at the end of the list
+        shell.key_press(Key::End);
+        shell.run();
+        assert!(shell.contains("item_999"));
+        assert!(shell.contains("item_990"));
+        assert!(!shell.contains("item_0"));
+        assert_eq!(shell.widget(ITEMS_PANEL).children_count(), Some(10 + 2));
+
+        // the selection is kept for items that are not materialized
+        let selected_indices = shell.get::<SelectedIndices>("list_view", "selected_indices");
+        assert!(selected_indices.0.contains(&0));
+    }
+
+    #[test]
+    fn test_add_selected_index() {
+        let mut selected_indices = HashSet::new();
+        selected_indices.insert(1);
+
+        let list_view = ListView::new()
+            .selected_indices(selected_indices)
+            .add_selected_index(3);
+
+        match list_view.selected_indices {
+            Some(PropertySource::Value(selected_indices)) => {
+                assert!(selected_indices.0.contains(&1));
+                assert!(selected_indices.0.contains(&3));
+            }
+            _ => panic!("Expected a value source."),
+        }
+
+        let list_view = ListView::new()
+            .add_selected_index(3)
+            .remove_selected_index(&3);
+
+        match list_view.selected_indices {
+            Some(PropertySource::Value(selected_indices)) => assert!(selected_indices.0.is_empty()),
+            _ => panic!("Expected a value source."),
+        }
+    }
+}
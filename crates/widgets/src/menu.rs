@@ -0,0 +1,980 @@
+use std::fmt;
+
+use super::behaviors::MouseBehavior;
+use crate::{
+    api::prelude::*, prelude::*, proc_macros::*, shell::prelude::*, theme_default::prelude::*,
+};
+
+// --- KEYS --
+
+static ITEMS_PANEL: &str = "items_panel";
+
+// --- KEYS --
+
+/// Describes the key combination that selects a menu item without opening its menu, e.g. `Ctrl+Shift+S`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Accelerator {
+    text: String,
+    modifiers: Modifiers,
+    key: Key,
+}
+
+impl Accelerator {
+    /// Creates an accelerator from its text, e.g. `Ctrl+O`. The modifiers `Ctrl`, `Shift`, `Alt` and `Super` are
+    /// separated by `+` from the key.
+    pub fn new(text: impl Into<String>) -> Self {
+        let text = text.into();
+        let mut modifiers = Modifiers::default();
+        let mut key = Key::Unknown;
+
+        for part in text.split('+').map(|part| part.trim()) {
+            match part.to_lowercase().as_str() {
+                "ctrl" | "control" => modifiers.insert(Modifiers::CTRL),
+                "shift" => modifiers.insert(Modifiers::SHIFT),
+                "alt" => modifiers.insert(Modifiers::ALT),
+                "super" | "cmd" | "meta" => modifiers.insert(Modifiers::SUPER),
+                "enter" => key = Key::Enter,
+                "escape" | "esc" => key = Key::Escape,
+                "tab" => key = Key::Tab,
+                "space" => key = Key::Space,
+                "delete" | "del" => key = Key::Delete,
+                "backspace" => key = Key::Backspace,
+                "home" => key = Key::Home,
+                "end" => key = Key::End,
+                "pageup" | "pgup" => key = Key::PageUp,
+                "pagedown" | "pgdn" => key = Key::PageDown,
+                "up" => key = Key::Up,
+                "down" => key = Key::Down,
+                "left" => key = Key::Left,
+                "right" => key = Key::Right,
+                part => {
+                    let mut chars = part.chars();
+                    if let (Some(c), None) = (chars.next(), chars.next()) {
+                        key = Key::from(c);
+                    }
+                }
+            }
+        }
+
+        Accelerator {
+            text,
+            modifiers,
+            key,
+        }
+    }
+
+    /// Gets the text of the accelerator.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Checks if the given key event triggers the accelerator. Letters are compared case-insensitive.
+    pub fn matches(&self, event: &KeyEvent) -> bool {
+        let same_key = event.key == self.key
+            || (key_char(event.key).is_some() && key_char(event.key) == key_char(self.key));
+
+        self.key != Key::Unknown && event.modifiers == self.modifiers && same_key
+    }
+}
+
+impl fmt::Display for Accelerator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+impl From<&str> for Accelerator {
+    fn from(text: &str) -> Self {
+        Accelerator::new(text)
+    }
+}
+
+/// Describes an entry of a `Menu`.
+///
+/// The text of an entry could contain a mnemonic, the letter after a `&` could be pressed to select the entry
+/// if its menu is open. Use `&&` to show a `&`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MenuItem {
+    /// Fires the `MenuActionEvent` with its action name if it is selected.
+    Action {
+        text: String,
+        action: String,
+        accelerator: Option<Accelerator>,
+    },
+
+    /// Opens a submenu if it is selected.
+    Submenu(Menu),
+
+    /// Draws a line between two groups of items.
+    Separator,
+}
+
+impl MenuItem {
+    /// Creates an item with the given text that fires the given action.
+    pub fn new(text: impl Into<String>, action: impl Into<String>) -> Self {
+        MenuItem::Action {
+            text: text.into(),
+            action: action.into(),
+            accelerator: None,
+        }
+    }
+
+    /// Sets the key combination that selects the item. Only items with an action could have an accelerator.
+    pub fn accelerator(self, accelerator: impl Into<Accelerator>) -> Self {
+        match self {
+            MenuItem::Action { text, action, .. } => MenuItem::Action {
+                text,
+                action,
+                accelerator: Some(accelerator.into()),
+            },
+            item => item,
+        }
+    }
+
+    /// Gets the text of the item including its mnemonic marker.
+    pub fn text(&self) -> &str {
+        match self {
+            MenuItem::Action { text, .. } => text,
+            MenuItem::Submenu(menu) => &menu.text,
+            MenuItem::Separator => "",
+        }
+    }
+
+    /// Returns `true` if the item is a separator.
+    pub fn is_separator(&self) -> bool {
+        *self == MenuItem::Separator
+    }
+}
+
+/// Describes a drop-down menu with its items. A `Menu` is shown as top-level menu of a `MenuBar` or as submenu
+/// of another menu.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Menu {
+    /// The text of the menu including its mnemonic marker, e.g. `&File`.
+    pub text: String,
+
+    /// The items of the menu.
+    pub items: Vec<MenuItem>,
+}
+
+impl Menu {
+    /// Creates an empty menu with the given text.
+    pub fn new(text: impl Into<String>) -> Self {
+        Menu {
+            text: text.into(),
+            items: vec![],
+        }
+    }
+
+    /// Adds an item.
+    pub fn item(mut self, item: MenuItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    /// Adds a separator.
+    pub fn separator(self) -> Self {
+        self.item(MenuItem::Separator)
+    }
+
+    /// Adds a submenu.
+    pub fn submenu(self, menu: Menu) -> Self {
+        self.item(MenuItem::Submenu(menu))
+    }
+}
+
+// describes the opened drop-downs of a menu bar and the highlighted item of the innermost drop-down
+#[derive(Clone, Debug, Default, PartialEq)]
+struct MenuNavigation {
+    // index of the opened top-level menu followed by the indices of the items with an opened submenu
+    open: Vec<usize>,
+    highlighted: Option<usize>,
+    // action of the last selected item
+    fired: Option<String>,
+}
+
+impl MenuNavigation {
+    fn is_open(&self) -> bool {
+        !self.open.is_empty()
+    }
+
+    fn close(&mut self) {
+        self.open.clear();
+        self.highlighted = None;
+    }
+
+    // opens the top-level menu with the given index, on keyboard navigation the first item is highlighted
+    fn open_menu(&mut self, menus: &[Menu], index: usize, highlight_first: bool) {
+        if index >= menus.len() {
+            return;
+        }
+
+        self.open = vec![index];
+        self.highlighted = if highlight_first {
+            next_item(&menus[index].items, None, true)
+        } else {
+            None
+        };
+    }
+
+    // returns the opened menus from the top-level menu to the innermost submenu
+    fn open_menus<'a>(&self, menus: &'a [Menu]) -> Vec<&'a Menu> {
+        let mut open_menus = vec![];
+
+        let mut current = match self.open.first().and_then(|index| menus.get(*index)) {
+            Some(menu) => menu,
+            None => return open_menus,
+        };
+        open_menus.push(current);
+
+        for index in &self.open[1..] {
+            match current.items.get(*index) {
+                Some(MenuItem::Submenu(menu)) => {
+                    current = menu;
+                    open_menus.push(menu);
+                }
+                _ => break,
+            }
+        }
+
+        open_menus
+    }
+
+    // highlights an item of the drop-down on the given level, the submenus of deeper levels are closed
+    fn highlight(&mut self, menus: &[Menu], level: usize, index: usize) {
+        let open_menus = self.open_menus(menus);
+
+        if let Some(item) = open_menus.get(level).and_then(|menu| menu.items.get(index)) {
+            if item.is_separator() {
+                return;
+            }
+
+            self.open.truncate(level + 1);
+            self.highlighted = Some(index);
+        }
+    }
+
+    // selects an item of the drop-down on the given level, opens its submenu or fires its action
+    fn activate(&mut self, menus: &[Menu], level: usize, index: usize) {
+        let open_menus = self.open_menus(menus);
+
+        match open_menus.get(level).and_then(|menu| menu.items.get(index)) {
+            Some(MenuItem::Submenu(menu)) => {
+                self.open.truncate(level + 1);
+                self.open.push(index);
+                self.highlighted = next_item(&menu.items, None, true);
+            }
+            Some(MenuItem::Action { action, .. }) => {
+                self.fired = Some(action.clone());
+                self.close();
+            }
+            _ => {}
+        }
+    }
+
+    // handles the keyboard navigation and the accelerators, returns `true` if the key is handled
+    fn key_down(&mut self, menus: &[Menu], event: &KeyEvent) -> bool {
+        if let Some(action) = menus
+            .iter()
+            .find_map(|menu| find_accelerator(&menu.items, event))
+        {
+            self.fired = Some(action);
+            self.close();
+            return true;
+        }
+
+        if !self.is_open() {
+            // Alt + mnemonic opens a top-level menu
+            if !event.modifiers.alt() || event.modifiers.ctrl() {
+                return false;
+            }
+
+            if let Some(index) =
+                find_mnemonic(menus.iter().map(|menu| menu.text.as_str()), event.key)
+            {
+                self.open_menu(menus, index, true);
+                return true;
+            }
+
+            return false;
+        }
+
+        let level = self.open.len() - 1;
+        let items = match self.open_menus(menus).last().copied() {
+            Some(menu) => &menu.items,
+            None => return false,
+        };
+
+        match event.key {
+            Key::Escape => self.close_level(),
+            Key::Down => self.highlighted = next_item(items, self.highlighted, true),
+            Key::Up => self.highlighted = next_item(items, self.highlighted, false),
+            Key::Right => match self.highlighted.map(|index| &items[index]) {
+                Some(MenuItem::Submenu(_)) => {
+                    self.activate(menus, level, self.highlighted.unwrap())
+                }
+                _ => self.open_menu(menus, (self.open[0] + 1) % menus.len(), true),
+            },
+            Key::Left => {
+                if level > 0 {
+                    self.close_level();
+                } else {
+                    self.open_menu(menus, (self.open[0] + menus.len() - 1) % menus.len(), true);
+                }
+            }
+            Key::Enter | Key::Space => {
+                if let Some(index) = self.highlighted {
+                    self.activate(menus, level, index);
+                }
+            }
+            key => {
+                if let Some(index) = find_mnemonic(items.iter().map(|item| item.text()), key) {
+                    self.activate(menus, level, index);
+                }
+            }
+        }
+
+        // all keys are consumed while a menu is open
+        true
+    }
+
+    // closes the innermost submenu or the menu if no submenu is open
+    fn close_level(&mut self) {
+        if self.open.len() > 1 {
+            self.highlighted = self.open.pop();
+        } else {
+            self.close();
+        }
+    }
+}
+
+/// The `MenuBarState` handles the opening of the drop-downs, the keyboard navigation and the accelerators of
+/// a `MenuBar`.
+#[derive(Default, AsAny)]
+pub struct MenuBarState {
+    menus: Vec<Menu>,
+    navigation: MenuNavigation,
+    // opened menus of the shown drop-downs
+    shown: Vec<usize>,
+    headers: Vec<Entity>,
+    // shown drop-downs with the generated entity of each item
+    drop_downs: Vec<(Entity, Vec<Entity>)>,
+    mouse_up: Option<Point>,
+    items_panel: Entity,
+}
+
+impl MenuBarState {
+    /// Closes all opened menus.
+    pub fn close(&mut self) {
+        self.navigation.close();
+    }
+
+    fn click_header(&mut self, index: usize) {
+        if self.navigation.open.first() == Some(&index) {
+            self.navigation.close();
+        } else {
+            self.navigation.open_menu(&self.menus, index, false);
+        }
+    }
+
+    // switches between the top-level menus by hovering their headers while a menu is open
+    fn enter_header(&mut self, index: usize) {
+        if self.navigation.is_open() && self.navigation.open[0] != index {
+            self.navigation.open_menu(&self.menus, index, false);
+        }
+    }
+
+    fn click_item(&mut self, level: usize, index: usize) {
+        self.navigation.activate(&self.menus, level, index);
+    }
+
+    fn enter_item(&mut self, level: usize, index: usize) {
+        self.navigation.highlight(&self.menus, level, index);
+    }
+
+    fn key_down(&mut self, event: &KeyEvent) -> bool {
+        self.navigation.key_down(&self.menus, event)
+    }
+
+    fn mouse_up(&mut self, position: Point) {
+        if self.navigation.is_open() {
+            self.mouse_up = Some(position);
+        }
+    }
+
+    fn generate_headers(&mut self, ctx: &mut Context) {
+        let entity = ctx.entity();
+
+        ctx.clear_children_of(self.items_panel);
+        self.headers.clear();
+
+        for (index, menu) in self.menus.iter().enumerate() {
+            let header = {
+                let build_context = &mut ctx.build_context();
+                let text = TextBlock::new()
+                    .v_align("center")
+                    .text(mnemonic(&menu.text).0)
+                    .build(build_context);
+
+                let header = MenuBarItem::new()
+                    .on_click(move |states, _| {
+                        states.get_mut::<MenuBarState>(entity).click_header(index);
+                        false
+                    })
+                    .on_enter(move |states, _| {
+                        states.get_mut::<MenuBarState>(entity).enter_header(index);
+                    })
+                    .build(build_context);
+
+                build_item(build_context, entity, header, text);
+                build_context.append_child(self.items_panel, header);
+
+                header
+            };
+            ctx.get_widget(header).update_widget(entity, false, false);
+            self.headers.push(header);
+        }
+    }
+
+    fn generate_drop_downs(&mut self, ctx: &mut Context) {
+        for (drop_down, _) in self.drop_downs.drain(..) {
+            let _ = ctx.remove_child_from_overlay(drop_down);
+        }
+
+        let entity = ctx.entity();
+
+        for (level, menu) in self.navigation.open_menus(&self.menus).iter().enumerate() {
+            // top-level menus are opened below their header, submenus beside their item
+            let position = if level == 0 {
+                let header = ctx.get_widget(self.headers[self.navigation.open[0]]);
+                let position = header.clone::<Point>("position");
+                let bounds = header.clone::<Rectangle>("bounds");
+                Point::new(position.x(), position.y() + bounds.height())
+            } else {
+                let item =
+                    ctx.get_widget(self.drop_downs[level - 1].1[self.navigation.open[level]]);
+                let position = item.clone::<Point>("position");
+                let bounds = item.clone::<Rectangle>("bounds");
+                Point::new(position.x() + bounds.width(), position.y())
+            };
+
+            let build_context = &mut ctx.build_context();
+            let items_panel = Stack::new().build(build_context);
+            let mut items = vec![];
+
+            for (index, item) in menu.items.iter().enumerate() {
+                if item.is_separator() {
+                    let separator = Container::new()
+                        .style("popup")
+                        .height(1.0)
+                        .margin((4.0, 2.0, 4.0, 2.0))
+                        .build(build_context);
+                    build_context.append_child(items_panel, separator);
+                    items.push(separator);
+                    continue;
+                }
+
+                let (accelerator, submenu) = match item {
+                    MenuItem::Action { accelerator, .. } => (
+                        accelerator
+                            .as_ref()
+                            .map(|accelerator| accelerator.to_string())
+                            .unwrap_or_default(),
+                        false,
+                    ),
+                    _ => (String::default(), true),
+                };
+
+                let content = Grid::new()
+                    .columns("*, 24, auto, 16")
+                    .child(
+                        TextBlock::new()
+                            .v_align("center")
+                            .text(mnemonic(item.text()).0)
+                            .build(build_context),
+                    )
+                    .child(
+                        TextBlock::new()
+                            .attach(Grid::column(2))
+                            .v_align("center")
+                            .text(accelerator)
+                            .build(build_context),
+                    )
+                    .child(
+                        FontIconBlock::new()
+                            .attach(Grid::column(3))
+                            .v_align("center")
+                            .h_align("end")
+                            .icon(material_icons_font::MD_KEYBOARD_ARROW_RIGHT)
+                            .icon_font("MaterialIcons-Regular")
+                            .icon_size(fonts::ICON_FONT_SIZE_12)
+                            .visibility(if submenu { "visible" } else { "hidden" })
+                            .build(build_context),
+                    )
+                    .build(build_context);
+
+                let menu_item = MenuBarItem::new()
+                    .on_click(move |states, _| {
+                        states
+                            .get_mut::<MenuBarState>(entity)
+                            .click_item(level, index);
+                        false
+                    })
+                    .on_enter(move |states, _| {
+                        states
+                            .get_mut::<MenuBarState>(entity)
+                            .enter_item(level, index);
+                    })
+                    .build(build_context);
+
+                build_item(build_context, entity, menu_item, content);
+                build_context.append_child(items_panel, menu_item);
+                items.push(menu_item);
+            }
+
+            let drop_down = Container::new()
+                .style("popup")
+                .h_align("start")
+                .v_align("start")
+                .min_width(160.0)
+                .padding(2.0)
+                .margin((position.x(), position.y(), 0.0, 0.0))
+                .child(items_panel)
+                .build(build_context);
+            let _ = build_context.append_child_to_overlay(drop_down);
+
+            self.drop_downs.push((drop_down, items));
+        }
+
+        for (drop_down, items) in &self.drop_downs {
+            ctx.get_widget(*drop_down)
+                .update_widget(entity, false, false);
+
+            for item in items {
+                ctx.get_widget(*item).update_widget(entity, false, false);
+            }
+        }
+
+        self.shown = self.navigation.open.clone();
+    }
+
+    // updates the highlighted state of the headers and the items of the drop-downs
+    fn update_highlight(&self, ctx: &mut Context) {
+        for (index, header) in self.headers.iter().enumerate() {
+            set_selected(ctx, *header, self.navigation.open.first() == Some(&index));
+        }
+
+        let depth = self.drop_downs.len();
+
+        for (level, (_, items)) in self.drop_downs.iter().enumerate() {
+            for (index, item) in items.iter().enumerate() {
+                // items with an opened submenu keep their highlight
+                let selected = if level + 1 < depth {
+                    self.navigation.open[level + 1] == index
+                } else {
+                    self.navigation.highlighted == Some(index)
+                };
+
+                set_selected(ctx, *item, selected);
+            }
+        }
+    }
+
+    // checks if the given position is inside of the menu bar or one of its drop-downs
+    fn contains(&self, ctx: &mut Context, position: Point) -> bool {
+        if check_mouse_condition(position, &ctx.widget()) {
+            return true;
+        }
+
+        self.drop_downs
+            .iter()
+            .any(|(drop_down, _)| check_mouse_condition(position, &ctx.get_widget(*drop_down)))
+    }
+}
+
+impl State for MenuBarState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.items_panel = ctx
+            .entity_of_child(ITEMS_PANEL)
+            .expect("MenuBarState.init: ItemsPanel child could not be found.");
+
+        self.generate_headers(ctx);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let entity = ctx.entity();
+
+        // a click outside of the menus closes them
+        if let Some(position) = self.mouse_up.take() {
+            if !self.contains(ctx, position) {
+                self.navigation.close();
+            }
+        }
+
+        if let Some(action) = self.navigation.fired.take() {
+            ctx.event_adapter()
+                .push_event_direct(entity, MenuActionEvent(entity, action));
+        }
+
+        if self.shown != self.navigation.open {
+            self.generate_drop_downs(ctx);
+        }
+
+        self.update_highlight(ctx);
+    }
+
+    fn cleanup(&mut self, _: &mut Registry, ctx: &mut Context) {
+        for (drop_down, _) in self.drop_downs.drain(..) {
+            let _ = ctx.remove_child_from_overlay(drop_down);
+        }
+    }
+}
+
+widget!(
+    /// The `MenuBarItem` shows the header of a top-level menu or an item of a drop-down of the `MenuBar`.
+    ///
+    /// **style:** `list_view_item`
+    MenuBarItem: MouseHandler {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: f64,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the foreground property.
+        foreground: Brush,
+
+        /// Sets or share the font size property.
+        font_size: f64,
+
+        /// Sets or shares the font property.
+        font: String,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the pressed property.
+        pressed: bool,
+
+        /// Sets or shares the selected property. It is set if the item is highlighted.
+        selected: bool
+    }
+);
+
+impl Template for MenuBarItem {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("MenuBarItem")
+            .style("list_view_item")
+            .height(24.0)
+            .selected(false)
+            .pressed(false)
+            .padding(0.0)
+            .background("transparent")
+            .border_radius(0.0)
+            .border_width(0.0)
+            .border_brush("transparent")
+            .foreground(colors::LINK_WATER_COLOR)
+            .font_size(32.0)
+            .font("Roboto-Regular")
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        RectangleRenderObject.into()
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        PaddingLayout::new().into()
+    }
+}
+
+widget!(
+    /// The `MenuBar` shows the top-level menus of an application. A click on a menu opens its drop-down with the
+    /// items of the menu, items could open submenus. If an item is selected, the `MenuActionEvent` with the
+    /// action name of the item is fired.
+    ///
+    /// The menus could be navigated by keyboard: `Alt` and the mnemonic of a menu opens it, the arrow keys move
+    /// through the items and menus, `Enter` selects an item and `Escape` closes the innermost menu. The
+    /// accelerators of the items are handled while the menu bar is shown.
+    ///
+    /// **style:** `menu_bar`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// MenuBar::new()
+    ///     .menu(
+    ///         Menu::new("&File")
+    ///             .item(MenuItem::new("&Open", "open").accelerator("Ctrl+O"))
+    ///             .submenu(Menu::new("&Recent").item(MenuItem::new("readme.md", "open_recent")))
+    ///             .separator()
+    ///             .item(MenuItem::new("&Quit", "quit").accelerator("Ctrl+Q")),
+    ///     )
+    ///     .on_menu_action(|_, _, action| println!("Action: {}", action))
+    ///     .build(ctx)
+    /// ```
+    MenuBar<MenuBarState>: KeyDownHandler, MouseHandler, MenuActionHandler {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness
+    }
+);
+
+impl MenuBar {
+    /// Adds a top-level menu.
+    pub fn menu(mut self, menu: Menu) -> Self {
+        self.state_mut().menus.push(menu);
+        self
+    }
+}
+
+impl Template for MenuBar {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MenuBar")
+            .style("menu_bar")
+            .v_align("start")
+            .background(colors::BRIGHT_GRAY_COLOR)
+            .border_width(0.0)
+            .border_brush("transparent")
+            .padding(2.0)
+            .child(
+                Container::new()
+                    .background(id)
+                    .border_width(id)
+                    .border_brush(id)
+                    .padding(id)
+                    .child(
+                        Stack::new()
+                            .id(ITEMS_PANEL)
+                            .orientation("horizontal")
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+            .on_key_down(move |states, event| states.get_mut::<MenuBarState>(id).key_down(&event))
+            .on_global_mouse_up(move |states, mouse| {
+                states.get_mut::<MenuBarState>(id).mouse_up(mouse.position)
+            })
+    }
+}
+
+// --- Helpers --
+
+// adds the mouse behavior and the content to a generated header or item
+fn build_item(build_context: &mut BuildContext, menu_bar: Entity, item: Entity, content: Entity) {
+    let mouse_behavior = MouseBehavior::new().target(item.0).build(build_context);
+    build_context.register_shared_property::<Selector>("selector", mouse_behavior, item);
+    build_context.register_shared_property::<bool>("pressed", mouse_behavior, item);
+    build_context.append_child(item, mouse_behavior);
+
+    build_context.register_shared_property::<f32>("opacity", item, menu_bar);
+    build_context.append_child(mouse_behavior, content);
+}
+
+// sets the selected state of a generated header or item, separators are skipped
+fn set_selected(ctx: &mut Context, entity: Entity, selected: bool) {
+    let mut widget = ctx.get_widget(entity);
+
+    if widget.try_clone::<bool>("selected") != Some(!selected) {
+        return;
+    }
+
+    widget.set("selected", selected);
+
+    if selected {
+        widget
+            .get_mut::<Selector>("selector")
+            .push_state("selected");
+    } else {
+        widget
+            .get_mut::<Selector>("selector")
+            .remove_state("selected");
+    }
+
+    widget.update(false);
+}
+
+// returns the text without its mnemonic marker and the lower case mnemonic
+fn mnemonic(text: &str) -> (String, Option<char>) {
+    let mut display = String::new();
+    let mut mnemonic = None;
+    let mut chars = text.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '&' {
+            display.push(c);
+            continue;
+        }
+
+        if let Some(next) = chars.next() {
+            if next != '&' && mnemonic.is_none() {
+                mnemonic = Some(next.to_ascii_lowercase());
+            }
+            display.push(next);
+        }
+    }
+
+    (display, mnemonic)
+}
+
+// returns the index of the first text with the mnemonic of the given key
+fn find_mnemonic<'a>(mut texts: impl Iterator<Item = &'a str>, key: Key) -> Option<usize> {
+    let c = key_char(key)?;
+    texts.position(|text| mnemonic(text).1 == Some(c))
+}
+
+// returns the action of the first item, also of the submenus, with an accelerator that matches the key event
+fn find_accelerator(items: &[MenuItem], event: &KeyEvent) -> Option<String> {
+    items.iter().find_map(|item| match item {
+        MenuItem::Action {
+            action,
+            accelerator: Some(accelerator),
+            ..
+        } if accelerator.matches(event) => Some(action.clone()),
+        MenuItem::Submenu(menu) => find_accelerator(&menu.items, event),
+        _ => None,
+    })
+}
+
+// returns the lower case character of a key, e.g. `a` for `Key::A(true)`
+fn key_char(key: Key) -> Option<char> {
+    <&'static str>::from(key)
+        .chars()
+        .next()
+        .map(|c| c.to_ascii_lowercase())
+}
+
+// returns the next item in the given direction that is not a separator, starting at the beginning or the end
+// if no item is highlighted
+fn next_item(items: &[MenuItem], current: Option<usize>, forward: bool) -> Option<usize> {
+    if items.is_empty() {
+        return None;
+    }
+
+    let len = items.len();
+    let mut index = current.unwrap_or(if forward { len - 1 } else { 0 });
+
+    for _ in 0..len {
+        index = if forward {
+            (index + 1) % len
+        } else {
+            (index + len - 1) % len
+        };
+
+        if !items[index].is_separator() {
+            return Some(index);
+        }
+    }
+
+    None
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn menus() -> Vec<Menu> {
+        vec![
+            Menu::new("&File")
+                .item(MenuItem::new("&Open", "open").accelerator("Ctrl+O"))
+                .separator()
+                .submenu(
+                    Menu::new("&Recent")
+                        .item(MenuItem::new("&first.txt", "open_first"))
+                        .item(MenuItem::new("&second.txt", "open_second")),
+                )
+                .item(MenuItem::new("&Quit", "quit")),
+            Menu::new("&Edit").item(MenuItem::new("&Copy", "copy").accelerator("Ctrl+C")),
+        ]
+    }
+
+    fn key(key: Key, modifiers: Modifiers) -> KeyEvent {
+        KeyEvent {
+            state: ButtonState::Down,
+            key,
+            text: String::default(),
+            repeat: false,
+            modifiers,
+        }
+    }
+
+    #[test]
+    fn test_menu_navigation() {
+        let menus = menus();
+        let mut navigation = MenuNavigation::default();
+
+        // Alt + f opens the file menu with its first item highlighted
+        assert!(!navigation.key_down(&menus, &key(Key::X(false), Modifiers::ALT)));
+        assert!(navigation.key_down(&menus, &key(Key::F(false), Modifiers::ALT)));
+        assert_eq!(navigation.open, vec![0]);
+        assert_eq!(navigation.highlighted, Some(0));
+
+        // the separator is skipped, right opens the submenu
+        navigation.key_down(&menus, &key(Key::Down, Modifiers::NONE));
+        assert_eq!(navigation.highlighted, Some(2));
+        navigation.key_down(&menus, &key(Key::Right, Modifiers::NONE));
+        assert_eq!(navigation.open, vec![0, 2]);
+        assert_eq!(navigation.highlighted, Some(0));
+        assert_eq!(navigation.open_menus(&menus)[1].text, "&Recent");
+
+        // left closes the submenu, right again and select the second item
+        navigation.key_down(&menus, &key(Key::Left, Modifiers::NONE));
+        assert_eq!(navigation.open, vec![0]);
+        assert_eq!(navigation.highlighted, Some(2));
+        navigation.key_down(&menus, &key(Key::Right, Modifiers::NONE));
+        navigation.key_down(&menus, &key(Key::Down, Modifiers::NONE));
+        assert!(navigation.fired.is_none());
+        navigation.key_down(&menus, &key(Key::Enter, Modifiers::NONE));
+        assert_eq!(navigation.fired.take(), Some("open_second".to_string()));
+        assert!(!navigation.is_open());
+
+        // selection by mouse and mnemonic
+        navigation.open_menu(&menus, 0, false);
+        assert_eq!(navigation.highlighted, None);
+        navigation.highlight(&menus, 0, 1);
+        assert_eq!(navigation.highlighted, None);
+        navigation.activate(&menus, 0, 2);
+        navigation.key_down(&menus, &key(Key::F(false), Modifiers::NONE));
+        assert_eq!(navigation.fired.take(), Some("open_first".to_string()));
+
+        // escape closes the innermost menu
+        navigation.open_menu(&menus, 1, true);
+        navigation.key_down(&menus, &key(Key::Escape, Modifiers::NONE));
+        assert!(!navigation.is_open());
+    }
+
+    #[test]
+    fn test_accelerator() {
+        let menus = menus();
+        let mut navigation = MenuNavigation::default();
+
+        let accelerator = Accelerator::new("Ctrl+Shift+S");
+        assert!(accelerator.matches(&key(Key::S(true), Modifiers::CTRL | Modifiers::SHIFT)));
+        assert!(!accelerator.matches(&key(Key::S(false), Modifiers::CTRL)));
+        assert_eq!(accelerator.to_string(), "Ctrl+Shift+S");
+
+        assert!(navigation.key_down(&menus, &key(Key::C(false), Modifiers::CTRL)));
+        assert_eq!(navigation.fired.take(), Some("copy".to_string()));
+        assert!(!navigation.key_down(&menus, &key(Key::C(false), Modifiers::NONE)));
+
+        assert_eq!(
+            mnemonic("Save && &Quit"),
+            ("Save & Quit".to_string(), Some('q'))
+        );
+    }
+}
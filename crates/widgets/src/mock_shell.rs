@@ -0,0 +1,233 @@
+//! This module contains a shell that runs a window in tests without opening a platform window.
+
+use std::sync::mpsc;
+
+use crate::{
+    api::prelude::*,
+    render::RenderContext2D,
+    shell::prelude::{
+        ButtonState, ColorScheme, Key, KeyEvent, Modifiers, MouseButton, MouseEvent, ShellRequest,
+        WindowAdapter as _, WindowRequest,
+    },
+    theme_default::prelude::*,
+};
+
+/// Runs a window like the platform shells do. The requests the window sends to the shell are recorded and
+/// applied to the state of the mocked platform window.
+pub struct MockShell {
+    adapter: WindowAdapter,
+    render_context: RenderContext2D,
+    theme: Theme,
+    request_receiver: mpsc::Receiver<WindowRequest>,
+    _shell_receiver: mpsc::Receiver<ShellRequest<WindowAdapter>>,
+    requests: Vec<WindowRequest>,
+    open: bool,
+    always_on_top: bool,
+    dragging: bool,
+}
+
+impl MockShell {
+    /// Creates the window with the given function and runs its first update.
+    pub fn new<F: Fn(&mut BuildContext) -> Entity + 'static>(create_fn: F) -> Self {
+        MockShell::with_event_filter(EventFilter::new(), create_fn)
+    }
+
+    /// Creates the window with the given function and passes all of its events through the given filter.
+    pub fn with_event_filter<F: Fn(&mut BuildContext) -> Entity + 'static>(
+        event_filter: EventFilter,
+        create_fn: F,
+    ) -> Self {
+        MockShell::create(theme_default(), event_filter, None, create_fn)
+    }
+
+    /// Creates the window with the given function, it starts with the light theme and switches between the
+    /// themes if the color scheme of the system changes.
+    pub fn with_color_scheme_themes<F: Fn(&mut BuildContext) -> Entity + 'static>(
+        light: Theme,
+        dark: Theme,
+        create_fn: F,
+    ) -> Self {
+        MockShell::create(
+            light.clone(),
+            EventFilter::new(),
+            Some(ColorSchemeThemes::new(light, dark)),
+            create_fn,
+        )
+    }
+
+    fn create<F: Fn(&mut BuildContext) -> Entity + 'static>(
+        theme: Theme,
+        event_filter: EventFilter,
+        color_scheme_themes: Option<ColorSchemeThemes>,
+        create_fn: F,
+    ) -> Self {
+        let (shell_sender, shell_receiver) = mpsc::channel();
+        let (adapter, settings, request_receiver) = create_window(
+            "",
+            theme.clone(),
+            shell_sender,
+            create_fn,
+            None,
+            event_filter,
+            color_scheme_themes,
+        );
+
+        let mut shell = MockShell {
+            adapter,
+            render_context: RenderContext2D::new(settings.size.0, settings.size.1),
+            theme,
+            request_receiver,
+            _shell_receiver: shell_receiver,
+            requests: vec![],
+            open: true,
+            always_on_top: settings.always_on_top,
+            dragging: false,
+        };
+
+        shell.run();
+        shell
+    }
+
+    /// Runs an update of the window and handles the requests it sent to the shell.
+    pub fn run(&mut self) {
+        self.adapter.run(&mut self.render_context);
+
+        let requests: Vec<WindowRequest> = self.request_receiver.try_iter().collect();
+
+        for request in requests {
+            match request {
+                // the mocked window is updated after each event anyway
+                WindowRequest::Redraw => continue,
+                WindowRequest::Close => self.open = false,
+                WindowRequest::DragWindow => self.dragging = true,
+                WindowRequest::SetAlwaysOnTop(always_on_top) => self.always_on_top = always_on_top,
+                _ => {}
+            }
+
+            self.requests.push(request);
+        }
+    }
+
+    /// Moves the mouse to the given position and presses the left mouse button.
+    pub fn mouse_down(&mut self, x: f64, y: f64) {
+        self.adapter.mouse(x, y);
+        self.mouse_event(x, y, ButtonState::Down);
+    }
+
+    /// Releases the left mouse button at the given position, a drag of the window ends.
+    pub fn mouse_up(&mut self, x: f64, y: f64) {
+        self.dragging = false;
+        self.mouse_event(x, y, ButtonState::Up);
+    }
+
+    /// Clicks with the left mouse button at the given position.
+    pub fn click(&mut self, x: f64, y: f64) {
+        self.mouse_down(x, y);
+        self.mouse_up(x, y);
+    }
+
+    /// Presses and releases the given key.
+    pub fn key_press(&mut self, key: Key) {
+        for state in [ButtonState::Down, ButtonState::Up].iter() {
+            self.adapter.key_event(KeyEvent {
+                state: *state,
+                key,
+                text: String::new(),
+                repeat: false,
+                modifiers: Modifiers::default(),
+            });
+            self.run();
+        }
+    }
+
+    /// Changes the light / dark preference of the system.
+    pub fn color_scheme_changed(&mut self, color_scheme: ColorScheme) {
+        self.adapter.color_scheme_changed(color_scheme);
+        self.run();
+    }
+
+    /// Requests to close the window like the close button of its title bar does.
+    pub fn quit(&mut self) {
+        self.adapter.quit_event();
+        self.run();
+    }
+
+    /// Returns the requests the window sent to the shell without `WindowRequest::Redraw`.
+    pub fn requests(&self) -> &[WindowRequest] {
+        &self.requests
+    }
+
+    /// Returns `true` if the window is not closed.
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Returns `true` if the window is shown on top of other windows.
+    pub fn is_always_on_top(&self) -> bool {
+        self.always_on_top
+    }
+
+    /// Returns `true` if the window is moved with the mouse.
+    pub fn is_dragging(&self) -> bool {
+        self.dragging
+    }
+
+    /// Returns `true` if the window contains a widget with the given id.
+    pub fn contains(&mut self, id: &str) -> bool {
+        self.find(id).is_some()
+    }
+
+    /// Returns the entity of the widget with the given id. Panics if there is no widget with the id.
+    pub fn entity(&mut self, id: &str) -> Entity {
+        self.find(id)
+            .unwrap_or_else(|| panic!("MockShell::entity: Could not find widget with id: {}.", id))
+    }
+
+    /// Returns the widget with the given id. Panics if there is no widget with the id.
+    pub fn widget(&mut self, id: &str) -> WidgetContainer<'_> {
+        let entity = self.entity(id);
+        WidgetContainer::new(
+            entity,
+            self.adapter.entity_component_manager(),
+            &self.theme,
+            None,
+        )
+    }
+
+    /// Returns the property with the given key of the widget with the given id. Panics if the widget or the
+    /// property does not exist.
+    pub fn get<P: Component + Clone>(&mut self, id: &str, key: &str) -> P {
+        let entity = self.entity(id);
+
+        self.adapter
+            .entity_component_manager()
+            .component_store()
+            .get::<P>(key, entity)
+            .unwrap()
+            .clone()
+    }
+
+    fn find(&mut self, id: &str) -> Option<Entity> {
+        let ecm = &*self.adapter.entity_component_manager();
+        let root = ecm.entity_store().root();
+
+        ecm.entity_store()
+            .start_node(root)
+            .into_iter()
+            .find(|entity| {
+                ecm.component_store()
+                    .get::<String>("id", *entity)
+                    .map_or(false, |widget_id| widget_id == id)
+            })
+    }
+
+    fn mouse_event(&mut self, x: f64, y: f64, state: ButtonState) {
+        self.adapter.mouse_event(MouseEvent {
+            position: Point::new(x, y),
+            button: MouseButton::Left,
+            state,
+            modifiers: Modifiers::default(),
+        });
+        self.run();
+    }
+}
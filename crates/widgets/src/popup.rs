@@ -60,6 +60,11 @@ widget!(
         /// Sets or shares the border brush property.
         border_brush: Brush,
 
+        /// Sets or shares the backdrop blur property. It blurs the already rendered content behind the
+        /// widget by the given radius, `0.0` disables the blur. The blur is calculated on each redraw,
+        /// which is expensive for large widgets.
+        backdrop_blur: f64,
+
         /// Sets or shares the padding property.
         padding: Thickness,
 
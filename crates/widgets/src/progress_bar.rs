@@ -10,16 +10,15 @@ static ID_INDICATOR: &str = "PGBAR_INDICATOR";
 
 // --- KEYS --
 
-#[derive(Default, AsAny)]
+#[derive(Default, State)]
 struct BarState {
+    #[child(ID_INDICATOR)]
     indicator: Entity,
 }
 
 impl State for BarState {
     fn init(&mut self, registry: &mut Registry, ctx: &mut Context) {
-        self.indicator = ctx
-            .entity_of_child(ID_INDICATOR)
-            .expect("BarState.init(): Child could not be found!");
+        self.init_children(ctx);
         self.update_post_layout(registry, ctx);
     }
 
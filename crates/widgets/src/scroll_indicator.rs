@@ -1,30 +1,163 @@
-use crate::{api::prelude::*, prelude::*, proc_macros::*};
+use std::time::Duration;
+
+use crate::{api::prelude::*, prelude::*, proc_macros::*, shell::prelude::*};
 
 // --- KEYS --
+static ID_SCROLL_BARS: &str = "scroll_bars";
 static ID_SCROLL_BAR_HORIZONTAL: &str = "scroll_bar_horizontal";
 static ID_SCROLL_BAR_VERTICAL: &str = "scroll_bar_vertical";
 // --- KEYS --
 
+// time in milliseconds overlay scroll bars stay visible after they were scrolled or hovered
+const OVERLAY_HOLD: f64 = 800.0;
+
+// duration in milliseconds of the fade out of overlay scroll bars
+const OVERLAY_FADE_DURATION: u64 = 300;
+
+/// Used to trigger actions on the `ScrollIndicator` widget.
+pub enum ScrollIndicatorAction {
+    /// Shows overlay scroll bars, e.g. if the mouse is moved over the scroll indicator.
+    Show,
+
+    /// Updates the opacity of the fading overlay scroll bars.
+    Animate,
+}
+
+// fades overlay scroll bars out after they were not scrolled or hovered for a while
+#[derive(Default)]
+struct OverlayFade {
+    // time in milliseconds the scroll bars were shown the last time
+    shown: Option<f64>,
+    fade_out: Option<Transition>,
+}
+
+impl OverlayFade {
+    // shows the scroll bars at the given time in milliseconds
+    fn show(&mut self, time: f64) {
+        self.shown = Some(time);
+        self.fade_out = None;
+    }
+
+    // returns `true` if the scroll bars are visible or fade out
+    fn is_visible(&self) -> bool {
+        self.shown.is_some()
+    }
+
+    // returns the opacity of the scroll bars at the given time in milliseconds
+    fn opacity(&mut self, time: f64) -> f64 {
+        let shown = match self.shown {
+            Some(shown) => shown,
+            None => return 0.0,
+        };
+
+        if time - shown < OVERLAY_HOLD {
+            return 1.0;
+        }
+
+        let fade_out = self.fade_out.get_or_insert_with(|| {
+            Transition::new(
+                "opacity",
+                TransitionValue::Number(1.0),
+                TransitionValue::Number(0.0),
+                motion::resolve_duration(Duration::from_millis(OVERLAY_FADE_DURATION)).as_millis()
+                    as u64,
+            )
+        });
+
+        let opacity = match fade_out.value(time) {
+            TransitionValue::Number(opacity) => opacity,
+            _ => 0.0,
+        };
+
+        if fade_out.is_finished(time) {
+            self.shown = None;
+            self.fade_out = None;
+        }
+
+        opacity
+    }
+}
+
 /// The `ScrollIndicatorState` handles the `ScrollIndicator` widget.
 #[derive(Default, AsAny)]
 pub struct ScrollIndicatorState {
+    scroll_bars: Entity,
     horizontal_scroll_bar: Entity,
     vertical_scroll_bar: Entity,
+    scroll_padding: Thickness,
+    overlay: bool,
+    overlay_fade: OverlayFade,
+}
+
+impl ScrollIndicatorState {
+    // fades overlay scroll bars in after scrolling and out if they are idle, the scroll bars of the other
+    // visibilities are opaque
+    fn update_opacity(&mut self, ctx: &mut Context) {
+        let scroll_padding = *ScrollIndicator::scroll_padding_ref(&ctx.widget());
+        let overlay = *ScrollIndicator::scroll_bar_visibility_ref(&ctx.widget())
+            == ScrollBarVisibility::Overlay;
+        let time = now();
+        self.overlay = overlay;
+
+        if scroll_padding != self.scroll_padding {
+            self.scroll_padding = scroll_padding;
+            self.overlay_fade.show(time);
+        }
+
+        let opacity = if overlay {
+            self.overlay_fade.opacity(time) as f32
+        } else {
+            1.0
+        };
+
+        let mut scroll_bars = ctx.get_widget(self.scroll_bars);
+
+        if (*scroll_bars.get::<f32>("opacity") - opacity).abs() > f32::EPSILON {
+            scroll_bars.set("opacity", opacity);
+        }
+
+        if overlay && self.overlay_fade.is_visible() {
+            ctx.send_message(ScrollIndicatorAction::Animate, ctx.entity());
+            ctx.send_window_request(WindowRequest::Redraw);
+        }
+    }
 }
 
 impl State for ScrollIndicatorState {
     fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.scroll_bars = ctx
+            .entity_of_child(ID_SCROLL_BARS)
+            .expect("ScrollIndicatorState.init: scroll_bars child could not be found.");
         self.horizontal_scroll_bar = ctx
             .entity_of_child(ID_SCROLL_BAR_HORIZONTAL)
             .expect("ScrollIndicatorState.init: scroll_bar_horizontal child could not be found.");
         self.vertical_scroll_bar = ctx
             .entity_of_child(ID_SCROLL_BAR_HORIZONTAL)
             .expect("ScrollIndicatorState.init: scroll_bar_vertical child could not be found.");
+        self.scroll_padding = *ScrollIndicator::scroll_padding_ref(&ctx.widget());
+    }
+
+    fn messages(
+        &mut self,
+        mut messages: MessageReader,
+        _registry: &mut Registry,
+        ctx: &mut Context,
+    ) {
+        for message in messages.read::<ScrollIndicatorAction>() {
+            match message {
+                ScrollIndicatorAction::Show => {
+                    self.overlay_fade.show(now());
+                    ctx.widget().set("dirty", true);
+                }
+                ScrollIndicatorAction::Animate => ctx.widget().set("dirty", true),
+            }
+        }
     }
 
     fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
-        let mode = *ctx.widget().get::<ScrollViewerMode>("mode");
+        self.update_opacity(ctx);
 
+        let mode = *ctx.widget().get::<ScrollViewerMode>("mode");
         if mode.vertical != ScrollMode::Auto && mode.horizontal != ScrollMode::Auto {
             return;
         }
@@ -34,9 +167,12 @@ impl State for ScrollIndicatorState {
         let view_port_size = ctx.widget().get::<Rectangle>("view_port_bounds").size();
         let padding = *ctx.widget().get::<Thickness>("padding");
         let scroll_padding = *ctx.widget().get::<Thickness>("scroll_padding");
+        let always = *ScrollIndicator::scroll_bar_visibility_ref(&ctx.widget())
+            == ScrollBarVisibility::Always;
 
         // adjust vertical scroll bar
-        if mode.vertical != ScrollMode::Disabled && content_size.height() > view_port_size.height()
+        if mode.vertical != ScrollMode::Disabled
+            && (always || content_size.height() > view_port_size.height())
         {
             let mut scroll_bar = ctx.get_widget(self.vertical_scroll_bar);
 
@@ -61,7 +197,8 @@ impl State for ScrollIndicatorState {
         }
 
         // adjust horizontal scroll bar
-        if mode.horizontal != ScrollMode::Disabled && content_size.width() > view_port_size.width()
+        if mode.horizontal != ScrollMode::Disabled
+            && (always || content_size.width() > view_port_size.width())
         {
             let mut scroll_bar = ctx.get_widget(self.horizontal_scroll_bar);
 
@@ -89,10 +226,16 @@ impl State for ScrollIndicatorState {
 
 widget!(
     /// The `ScrollIndicator` widget contains two scroll bars.
-    ScrollIndicator<ScrollIndicatorState> {
+    ///
+    /// With the `overlay` scroll bar visibility the scroll bars are shown while the content is scrolled or the
+    /// mouse is moved over the indicator and fade out afterwards.
+    ScrollIndicator<ScrollIndicatorState>: MouseHandler {
         /// Shares the mode of the `ScrollViewer`.
         mode: ScrollViewerMode,
 
+        /// Sets or shares the scroll bar visibility of the `ScrollViewer`.
+        scroll_bar_visibility: ScrollBarVisibility,
+
         /// Shares the padding of the `ScrollViewer`.
         scroll_padding: Thickness,
 
@@ -113,8 +256,16 @@ impl Template for ScrollIndicator {
             .v_align("stretch")
             .h_align("stretch")
             .padding(0.0)
+            .scroll_bar_visibility(ScrollBarVisibility::default())
+            .on_mouse_move(move |states, _| {
+                if states.get::<ScrollIndicatorState>(id).overlay {
+                    states.send_message(ScrollIndicatorAction::Show, id);
+                }
+                false
+            })
             .child(
                 Grid::new()
+                    .id(ID_SCROLL_BARS)
                     .child(
                         ScrollBar::new()
                             .id(ID_SCROLL_BAR_HORIZONTAL)
@@ -148,7 +299,8 @@ impl Template for ScrollIndicator {
 // --- Helpers --
 
 fn scroll_bar_size(size: f64, content_size: f64, view_port_size: f64, padding: f64) -> f64 {
-    (size * view_port_size / content_size) - padding
+    // content that fits into the view port is covered by the whole scroll bar
+    (size * view_port_size.min(content_size) / content_size) - padding
 }
 
 fn offset(size: f64, content_size: f64, offset: f64) -> f64 {
@@ -159,7 +311,10 @@ fn offset(size: f64, content_size: f64, offset: f64) -> f64 {
 
 #[cfg(test)]
 mod tests {
+    use std::thread;
+
     use super::*;
+    use crate::mock_shell::MockShell;
 
     #[test]
     fn test_scroll_bar_size() {
@@ -182,4 +337,71 @@ mod tests {
 
         assert!((offset(size, content_size, offset_in) - 2.).abs() < f64::EPSILON);
     }
+
+    #[test]
+    fn test_scroll_bar_size_always() {
+        // the scroll bar of content that fits is as large as the scroll indicator
+        assert!((scroll_bar_size(50., 40., 80., 8.) - 42.).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_overlay_fade() {
+        // the scroll indicator is placed beside the scroll viewer, so the mouse does not show its scroll bars
+        let mut shell = MockShell::new(|ctx| {
+            let content = Container::new().height(500.0).v_align("start").build(ctx);
+            let scroll_viewer = ScrollViewer::new()
+                .width(100.0)
+                .height(100.0)
+                .h_align("start")
+                .v_align("start")
+                .scroll_bar_visibility(ScrollBarVisibility::Overlay)
+                .child(content)
+                .build(ctx);
+
+            Window::new()
+                .width(200.0)
+                .height(200.0)
+                .child(scroll_viewer)
+                .child(
+                    ScrollIndicator::new()
+                        .width(100.0)
+                        .height(100.0)
+                        .h_align("end")
+                        .v_align("end")
+                        .content_bounds(("bounds", content))
+                        .view_port_bounds(("bounds", scroll_viewer))
+                        .scroll_padding(("padding", scroll_viewer))
+                        .mode(scroll_viewer)
+                        .scroll_bar_visibility(scroll_viewer)
+                        .build(ctx),
+                )
+                .build(ctx)
+        });
+
+        let opacity = |shell: &mut MockShell| shell.get::<f32>(ID_SCROLL_BARS, "opacity");
+        let sleep = |millis: u64| thread::sleep(Duration::from_millis(millis));
+
+        // the scroll bars are hidden until the content is scrolled
+        shell.click(50.0, 50.0);
+        assert!(opacity(&mut shell).abs() < f32::EPSILON);
+
+        shell.key_press(Key::PageDown);
+        assert!((opacity(&mut shell) - 1.0).abs() < f32::EPSILON);
+
+        // and fade out if they are idle
+        sleep(OVERLAY_HOLD as u64);
+        shell.run();
+        sleep(OVERLAY_FADE_DURATION / 2);
+        shell.run();
+        let fading = opacity(&mut shell);
+        assert!(fading > 0.0 && fading < 1.0);
+
+        sleep(OVERLAY_FADE_DURATION);
+        shell.run();
+        assert!(opacity(&mut shell).abs() < f32::EPSILON);
+
+        // scrolling shows them again
+        shell.key_press(Key::PageDown);
+        assert!((opacity(&mut shell) - 1.0).abs() < f32::EPSILON);
+    }
 }
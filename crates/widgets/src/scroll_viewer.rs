@@ -1,56 +1,133 @@
-use crate::{api::prelude::*, proc_macros::*};
+use crate::{api::prelude::*, proc_macros::*, shell::prelude::*};
+
+// scroll distance of the arrow keys
+const KEY_STEP: f64 = 32.0;
 
 /// The `ScrollViewerState` handles the `ScrollViewer` widget.
 #[derive(Default, AsAny)]
 pub struct ScrollViewerState {
     delta: Option<Point>,
+    key: Option<Key>,
+    focus: bool,
+    window: Entity,
 }
 
 impl ScrollViewerState {
     fn scroll(&mut self, delta: Point) {
         self.delta = Some(delta);
     }
+
+    fn key_down(&mut self, key: Key) {
+        self.key = Some(key);
+    }
+
+    fn focus(&mut self) {
+        self.focus = true;
+    }
+
+    // requests the keyboard focus from the window
+    fn request_focus(&self, ctx: &mut Context) {
+        if !*ScrollViewer::focused_ref(&ctx.widget()) {
+            ctx.event_adapter()
+                .push_event_direct(self.window, FocusEvent::RequestFocus(ctx.entity()));
+        }
+    }
+
+    // scrolls the first child by the given delta, the offset is clamped to the size of the child
+    fn scroll_by(&self, delta: Point, ctx: &mut Context) {
+        let mode = *ctx.widget().get::<ScrollViewerMode>("mode");
+
+        if mode.vertical != ScrollMode::Auto && mode.horizontal != ScrollMode::Auto {
+            return;
+        }
+
+        let size = ctx.widget().get::<Rectangle>("bounds").size();
+        let mut padding = *ctx.widget().get::<Thickness>("padding");
+
+        if let Some(child) = &mut ctx.try_child_from_index(0) {
+            let child_size = child.get::<Rectangle>("bounds").size();
+
+            if mode.vertical == ScrollMode::Auto && can_scroll(size.height(), child_size.height()) {
+                padding.set_top(offset(
+                    size.height(),
+                    child_size.height(),
+                    padding.top(),
+                    delta.y(),
+                ));
+            }
+
+            if mode.horizontal == ScrollMode::Auto && can_scroll(size.width(), child_size.width()) {
+                padding.set_left(offset(
+                    size.width(),
+                    child_size.width(),
+                    padding.left(),
+                    delta.x(),
+                ));
+            }
+        } else {
+            return;
+        }
+
+        ctx.widget().set("padding", padding);
+    }
 }
 
 impl State for ScrollViewerState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.window = ctx.entity_of_window();
+    }
+
     fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
-        if let Some(delta) = self.delta {
-            self.delta = None;
-            let mode = *ctx.widget().get::<ScrollViewerMode>("mode");
+        if self.focus {
+            self.focus = false;
+            self.request_focus(ctx);
+        }
 
-            if mode.vertical != ScrollMode::Auto && mode.horizontal != ScrollMode::Auto {
+        if let Some(delta) = self.delta.take() {
+            let speed = *ctx.widget().get::<f64>("speed");
+            self.scroll_by(delta * speed, ctx);
+        }
+
+        if let Some(key) = self.key.take() {
+            if !*ScrollViewer::focused_ref(&ctx.widget()) {
                 return;
             }
 
             let size = ctx.widget().get::<Rectangle>("bounds").size();
-            let speed = *ctx.widget().get::<f64>("speed");
-            let mut padding = *ctx.widget().get::<Thickness>("padding");
-
-            if let Some(child) = &mut ctx.try_child_from_index(0) {
-                let child_size = child.get::<Rectangle>("bounds").size();
-
-                if mode.vertical == ScrollMode::Auto && child_size.height() > size.height() {
-                    padding.set_top(offset(
-                        size.height(),
-                        child_size.height(),
-                        padding.top(),
-                        delta.y() * speed,
-                    ));
-                }
-
-                if mode.horizontal == ScrollMode::Auto && child_size.width() > size.width() {
-                    padding.set_left(offset(
-                        size.width(),
-                        child_size.width(),
-                        padding.left(),
-                        delta.x() * speed,
-                    ));
-                }
-            } else {
-                return;
+
+            if let Some(delta) = key_delta(key, size) {
+                self.scroll_by(delta, ctx);
             }
+        }
+    }
+
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let content_size = match ctx.try_child_from_index(0) {
+            Some(child) => child.get::<Rectangle>("bounds").size(),
+            None => return,
+        };
 
-            ctx.widget().set("padding", padding);
+        let mut size = ctx.widget().get::<Rectangle>("bounds").size();
+
+        if *ScrollViewer::auto_size_to_content_ref(&ctx.widget()) {
+            let mut constraint = ScrollViewer::constraint_clone(&ctx.widget());
+            size = fit_size(
+                content_size,
+                Size::new(constraint.max_width(), constraint.max_height()),
+            );
+
+            if constraint.size() != (size.width(), size.height()) {
+                constraint.set_size(size.width(), size.height());
+                ScrollViewer::constraint_set(&mut ctx.widget(), constraint);
+            }
+        }
+
+        // the offset is clamped to the size of the child, e.g. after it was restored with `set_scroll_offset`
+        let padding = ScrollViewer::padding_clone(&ctx.widget());
+        let clamped_padding = clamp_padding(padding, size, content_size);
+
+        if clamped_padding != padding {
+            ScrollViewer::padding_set(&mut ctx.widget(), clamped_padding);
         }
     }
 }
@@ -58,7 +135,11 @@ impl State for ScrollViewerState {
 widget!(
     /// The `ScrollViewer` is used to scroll its child vertical and or horizontal.
     /// Only the first child of the scroll viewer can be scrolled.
-    ScrollViewer<ScrollViewerState>: MouseHandler {
+    ///
+    /// A scroll viewer that is pressed with the mouse requests the keyboard focus. A focused scroll viewer is
+    /// also scrolled by the keyboard: `PageUp` and `PageDown` scroll by the height of the scroll viewer, `Home`
+    /// and `End` scroll to the top and to the bottom and the arrow keys scroll by a small step.
+    ScrollViewer<ScrollViewerState>: KeyDownHandler, MouseHandler {
         /// Sets or shares the scroll mode property.
         mode: ScrollViewerMode,
 
@@ -66,10 +147,35 @@ widget!(
         speed: f64,
 
         /// Sets or shares padding, that is used to scroll the first child.
-        padding: Thickness
+        padding: Thickness,
+
+        /// Sets or shares the focused property.
+        focused: bool,
+
+        /// Sets or shares the scroll bar visibility. It is shared by the scroll indicator of the scroll viewer.
+        scroll_bar_visibility: ScrollBarVisibility,
+
+        /// Sets or shares the auto size to content property. If it is `true` the scroll viewer takes the size of
+        /// its content until it reaches its max width and max height, larger content is scrolled.
+        auto_size_to_content: bool
     }
 );
 
+impl ScrollViewer {
+    /// Returns the current scroll offset of the given scroll viewer. Store it to restore the scroll position
+    /// with `set_scroll_offset` e.g. after navigating back to a view. Panics if it is the wrong widget type.
+    pub fn scroll_offset(widget: &WidgetContainer) -> Point {
+        scroll_offset(ScrollViewer::padding_clone(widget))
+    }
+
+    /// Scrolls the given scroll viewer to the given offset. The offset is clamped to the size of the scrolled child
+    /// after the next layout. Panics if it is the wrong widget type.
+    pub fn set_scroll_offset(widget: &mut WidgetContainer, offset: impl Into<Point>) {
+        let padding = scroll_padding(ScrollViewer::padding_clone(widget), offset.into());
+        ScrollViewer::padding_set(widget, padding);
+    }
+}
+
 impl Template for ScrollViewer {
     fn template(self, id: Entity, _: &mut BuildContext) -> Self {
         self.name("ScrollViewer")
@@ -77,10 +183,21 @@ impl Template for ScrollViewer {
             .speed(8)
             .clip(true)
             .mode(ScrollViewerMode::default())
+            .auto_size_to_content(false)
+            .focused(false)
+            .scroll_bar_visibility(ScrollBarVisibility::default())
             .on_scroll(move |states, p| {
                 states.get_mut::<ScrollViewerState>(id).scroll(p);
                 false
             })
+            .on_mouse_down(move |states, _| {
+                states.get_mut::<ScrollViewerState>(id).focus();
+                false
+            })
+            .on_key_down(move |states, event| {
+                states.get_mut::<ScrollViewerState>(id).key_down(event.key);
+                false
+            })
     }
 
     fn layout(&self) -> Box<dyn Layout> {
@@ -90,15 +207,70 @@ impl Template for ScrollViewer {
 
 // --- Helpers --
 
+// the child can only be scrolled if it does not fit into the scroll viewer
+fn can_scroll(size: f64, child_size: f64) -> bool {
+    child_size > size
+}
+
+// the size of a scroll viewer that is sized to its content, it grows with the content up to the max size
+fn fit_size(content_size: Size, max_size: Size) -> Size {
+    Size::new(
+        content_size.width().min(max_size.width()),
+        content_size.height().min(max_size.height()),
+    )
+}
+
+// the scroll delta of the given key, `Home` and `End` scroll as far as possible
+fn key_delta(key: Key, size: Size) -> Option<Point> {
+    match key {
+        Key::PageUp => Some(Point::new(0.0, size.height())),
+        Key::PageDown => Some(Point::new(0.0, -size.height())),
+        Key::Home => Some(Point::new(0.0, f64::INFINITY)),
+        Key::End => Some(Point::new(0.0, f64::NEG_INFINITY)),
+        Key::Up => Some(Point::new(0.0, KEY_STEP)),
+        Key::Down => Some(Point::new(0.0, -KEY_STEP)),
+        Key::Left => Some(Point::new(KEY_STEP, 0.0)),
+        Key::Right => Some(Point::new(-KEY_STEP, 0.0)),
+        _ => None,
+    }
+}
+
 fn offset(size: f64, child_size: f64, current_offset: f64, delta: f64) -> f64 {
     (current_offset + delta).min(0.).max(size - child_size)
 }
 
+// clamps the scroll padding to the size of the child, content that fits into the scroll viewer is not scrolled
+fn clamp_padding(mut padding: Thickness, size: Size, child_size: Size) -> Thickness {
+    let clamp = |size, child_size, current_offset| {
+        if can_scroll(size, child_size) {
+            offset(size, child_size, current_offset, 0.0)
+        } else {
+            0.0
+        }
+    };
+
+    padding.set_left(clamp(size.width(), child_size.width(), padding.left()));
+    padding.set_top(clamp(size.height(), child_size.height(), padding.top()));
+    padding
+}
+
+// the child is scrolled by a negative padding
+fn scroll_offset(padding: Thickness) -> Point {
+    Point::new(-padding.left(), -padding.top())
+}
+
+fn scroll_padding(mut padding: Thickness, offset: Point) -> Thickness {
+    padding.set_left(-offset.x().max(0.0));
+    padding.set_top(-offset.y().max(0.0));
+    padding
+}
+
 // --- Helpers --
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{mock_shell::MockShell, Container, Window};
 
     #[test]
     fn test_offset() {
@@ -109,4 +281,148 @@ mod tests {
         assert!((offset(width, child_width, 0., -200.) + 100.).abs() < f64::EPSILON);
         assert!((offset(width, child_width, 0., 200.) + 0.).abs() < f64::EPSILON);
     }
+
+    // creates a window with a scroll viewer that is sized to its content of the given size
+    fn auto_size_window(content_width: f64, content_height: f64) -> MockShell {
+        let mut shell = MockShell::new(move |ctx| {
+            Window::new()
+                .width(300.0)
+                .height(300.0)
+                .child(
+                    ScrollViewer::new()
+                        .id("scroll_viewer")
+                        .auto_size_to_content(true)
+                        .max_width(200.0)
+                        .max_height(100.0)
+                        .h_align("start")
+                        .v_align("start")
+                        .child(
+                            Container::new()
+                                .width(content_width)
+                                .height(content_height)
+                                .h_align("start")
+                                .v_align("start")
+                                .build(ctx),
+                        )
+                        .build(ctx),
+                )
+                .build(ctx)
+        });
+
+        // the size of the scroll viewer is adjusted after the first layout
+        shell.run();
+        shell
+    }
+
+    #[test]
+    fn test_auto_size_to_content() {
+        // small content does not reserve scroll space
+        let mut shell = auto_size_window(150.0, 40.0);
+        let bounds = shell.get::<Rectangle>("scroll_viewer", "bounds");
+        assert_eq!(bounds.size(), Size::new(150.0, 40.0));
+
+        shell.click(20.0, 20.0);
+        shell.key_press(Key::End);
+        assert_eq!(
+            shell.get::<Thickness>("scroll_viewer", "padding"),
+            Thickness::default()
+        );
+
+        // large content is clamped to the max size and scrolled
+        let mut shell = auto_size_window(150.0, 400.0);
+        let bounds = shell.get::<Rectangle>("scroll_viewer", "bounds");
+        assert_eq!(bounds.size(), Size::new(150.0, 100.0));
+
+        shell.click(20.0, 20.0);
+        shell.key_press(Key::End);
+        let padding = shell.get::<Thickness>("scroll_viewer", "padding");
+        assert!((padding.top() + 300.0).abs() < f64::EPSILON);
+        assert!(padding.left().abs() < f64::EPSILON);
+    }
+
+    // creates a window with a scroll viewer of 100 x 100 that scrolls a child of 100 x 500
+    fn scroll_window() -> MockShell {
+        MockShell::new(|ctx| {
+            Window::new()
+                .width(200.0)
+                .height(200.0)
+                .child(
+                    ScrollViewer::new()
+                        .id("scroll_viewer")
+                        .width(100.0)
+                        .height(100.0)
+                        .h_align("start")
+                        .v_align("start")
+                        .child(
+                            Container::new()
+                                .width(100.0)
+                                .height(500.0)
+                                .v_align("start")
+                                .build(ctx),
+                        )
+                        .build(ctx),
+                )
+                .build(ctx)
+        })
+    }
+
+    #[test]
+    fn test_key_scroll() {
+        let mut shell = scroll_window();
+
+        // the keyboard does not scroll a scroll viewer without focus
+        shell.key_press(Key::PageDown);
+        assert_eq!(
+            shell.get::<Thickness>("scroll_viewer", "padding"),
+            Thickness::default()
+        );
+
+        shell.click(50.0, 50.0);
+        assert!(shell.get::<bool>("scroll_viewer", "focused"));
+
+        // page down scrolls by the height of the scroll viewer
+        shell.key_press(Key::PageDown);
+        let padding = shell.get::<Thickness>("scroll_viewer", "padding");
+        assert!((padding.top() + 100.0).abs() < f64::EPSILON);
+
+        // end jumps to the maximum offset
+        shell.key_press(Key::End);
+        let padding = shell.get::<Thickness>("scroll_viewer", "padding");
+        assert!((padding.top() + 400.0).abs() < f64::EPSILON);
+
+        shell.key_press(Key::PageDown);
+        let padding = shell.get::<Thickness>("scroll_viewer", "padding");
+        assert!((padding.top() + 400.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_restore_scroll_offset() {
+        let scroll_offset =
+            |shell: &mut MockShell| ScrollViewer::scroll_offset(&shell.widget("scroll_viewer"));
+
+        // scroll down and save the offset
+        let mut shell = scroll_window();
+        shell.click(50.0, 50.0);
+        shell.key_press(Key::PageDown);
+        let saved = scroll_offset(&mut shell);
+        assert_eq!(saved, Point::new(0.0, 100.0));
+
+        // the content is rebuilt and the scroll viewer starts at the top
+        let mut shell = scroll_window();
+        assert_eq!(scroll_offset(&mut shell), Point::new(0.0, 0.0));
+
+        // after the restore the same part of the child is visible
+        ScrollViewer::set_scroll_offset(&mut shell.widget("scroll_viewer"), saved);
+        shell.run();
+        assert_eq!(scroll_offset(&mut shell), saved);
+
+        // the offset is clamped to the size of the child
+        ScrollViewer::set_scroll_offset(&mut shell.widget("scroll_viewer"), (0.0, 1000.0));
+        shell.run();
+        assert_eq!(scroll_offset(&mut shell), Point::new(0.0, 400.0));
+
+        ScrollViewer::set_scroll_offset(&mut shell.widget("scroll_viewer"), (-10.0, -10.0));
+        shell.run();
+        assert_eq!(scroll_offset(&mut shell), Point::new(0.0, 0.0));
+    }
 }
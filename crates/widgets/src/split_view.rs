@@ -0,0 +1,469 @@
+use super::behaviors::MouseBehavior;
+use crate::{
+    api::prelude::*, prelude::*, proc_macros::*, shell::prelude::*, theme_default::prelude::*,
+};
+
+/// The `SplitViewState` places the panes of a `SplitView` and resizes them if a splitter is dragged.
+#[derive(Default, AsAny)]
+pub struct SplitViewState {
+    panes: Vec<Entity>,
+    splitters: Vec<Entity>,
+    grid: Entity,
+    // index of the dragged splitter
+    drag: Option<usize>,
+    mouse: Option<Point>,
+    // current sizes of the panes in pixels
+    sizes: Vec<f64>,
+}
+
+impl SplitViewState {
+    /// Returns the panes of the split view.
+    pub fn panes(&self) -> &[Entity] {
+        &self.panes
+    }
+
+    fn press(&mut self, splitter: usize) {
+        self.drag = Some(splitter);
+    }
+
+    fn release(&mut self) {
+        self.drag = None;
+    }
+
+    fn mouse_move(&mut self, position: Point) {
+        if self.drag.is_some() {
+            self.mouse = Some(position);
+        }
+    }
+}
+
+impl State for SplitViewState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let horizontal = *ctx.widget().get::<Orientation>("orientation") == Orientation::Horizontal;
+
+        // panes and splitters alternate, the orientation decides if they are placed in columns or in rows
+        let mut children = vec![];
+
+        for (index, pane) in self.panes.iter().enumerate() {
+            children.push(*pane);
+
+            if let Some(splitter) = self.splitters.get(index) {
+                children.push(*splitter);
+            }
+        }
+
+        for (index, child) in children.into_iter().enumerate() {
+            let (column, row) = if horizontal { (index, 0) } else { (0, index) };
+
+            let mut child = ctx.get_widget(child);
+            child.set("column", column);
+            child.set("row", row);
+        }
+    }
+
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if self.panes.is_empty() {
+            return;
+        }
+
+        let horizontal = *ctx.widget().get::<Orientation>("orientation") == Orientation::Horizontal;
+        let bounds = ctx.widget().clone::<Rectangle>("bounds");
+        let position = ctx.widget().clone::<Point>("position");
+        let splitter_width = *ctx.widget().get::<f64>("splitter_width");
+
+        let min_sizes: Vec<f64> = self
+            .panes
+            .iter()
+            .map(|pane| {
+                let constraint = ctx.get_widget(*pane).clone::<Constraint>("constraint");
+
+                if horizontal {
+                    constraint.min_width()
+                } else {
+                    constraint.min_height()
+                }
+            })
+            .collect();
+
+        let (total, origin) = if horizontal {
+            (bounds.width(), position.x())
+        } else {
+            (bounds.height(), position.y())
+        };
+
+        let mut ratios = ctx.widget().clone::<Vec<f64>>("ratios");
+
+        if ratios.len() != self.panes.len() {
+            ratios = default_ratios(self.panes.len());
+        }
+
+        let mut sizes = pane_sizes(
+            &ratios,
+            &min_sizes,
+            total - splitter_width * self.splitters.len() as f64,
+        );
+
+        if let (Some(splitter), Some(mouse)) = (self.drag, self.mouse.take()) {
+            if *ctx.widget().get::<bool>("pressed") {
+                let offset = if horizontal { mouse.x() } else { mouse.y() } - origin;
+                sizes = drag_splitter(&sizes, &min_sizes, splitter, splitter_width, offset);
+                ratios = split_ratios(&sizes);
+            } else {
+                self.drag = None;
+            }
+        }
+
+        if ratios != *ctx.widget().get::<Vec<f64>>("ratios") {
+            ctx.widget().set("ratios", ratios);
+        }
+
+        if sizes == self.sizes {
+            return;
+        }
+
+        let blocks = blocks(&sizes, splitter_width);
+
+        if horizontal {
+            ctx.get_widget(self.grid).set("columns", blocks);
+        } else {
+            ctx.get_widget(self.grid).set("rows", blocks);
+        }
+
+        self.sizes = sizes;
+        ctx.send_window_request(WindowRequest::Redraw);
+    }
+}
+
+widget!(
+    /// The `SplitView` places its panes side by side (horizontal) or on top of each other (vertical). The panes
+    /// are separated by splitters, dragging a splitter moves space between the two panes next to it. The minimum
+    /// width (horizontal) or minimum height (vertical) of a pane is never undercut.
+    ///
+    /// The size of each pane relative to the available space is stored in the `ratios` property. Share it
+    /// to keep the layout of the split view, e.g. to save and restore it with the `Settings` service.
+    ///
+    /// **style:** `split_view`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// SplitView::new()
+    ///     .orientation("horizontal")
+    ///     .ratios(vec![0.25, 0.75])
+    ///     .pane(Container::new().min_width(120.0).build(ctx))
+    ///     .pane(Container::new().build(ctx))
+    ///     .build(ctx)
+    /// ```
+    SplitView<SplitViewState>: MouseHandler {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the orientation property.
+        orientation: Orientation,
+
+        /// Sets or shares the size of each pane relative to the space that is available for all panes.
+        ratios: Vec<f64>,
+
+        /// Sets or shares the brush of the splitters.
+        splitter_brush: Brush,
+
+        /// Sets or shares the width (horizontal) or height (vertical) of the splitters.
+        splitter_width: f64,
+
+        /// Indicates if a splitter is pressed.
+        pressed: bool
+    }
+);
+
+impl SplitView {
+    /// Adds a pane to the split view. A splitter is inserted between two panes.
+    pub fn pane(mut self, pane: Entity) -> Self {
+        self.state_mut().panes.push(pane);
+        self
+    }
+}
+
+impl Template for SplitView {
+    fn template(mut self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let panes = self.state().panes.clone();
+        let mut grid = Grid::new();
+        let mut splitters = vec![];
+
+        for (index, pane) in panes.iter().enumerate() {
+            if index > 0 {
+                let splitter = index - 1;
+
+                let splitter = MouseBehavior::new()
+                    .pressed(id)
+                    .target(id.0)
+                    .attach(Grid::column(0))
+                    .attach(Grid::row(0))
+                    .on_mouse_down(move |states, _| {
+                        states.get_mut::<SplitViewState>(id).press(splitter);
+                        false
+                    })
+                    .child(
+                        Container::new()
+                            .background(("splitter_brush", id))
+                            .build(ctx),
+                    )
+                    .build(ctx);
+
+                splitters.push(splitter);
+                grid = grid.child(splitter);
+            }
+
+            ctx.register_property("column", *pane, 0_usize);
+            ctx.register_property("row", *pane, 0_usize);
+            grid = grid.child(*pane);
+        }
+
+        let grid = grid.build(ctx);
+        self.state_mut().splitters = splitters;
+        self.state_mut().grid = grid;
+
+        self.name("SplitView")
+            .style("split_view")
+            .orientation("horizontal")
+            .ratios(default_ratios(panes.len()))
+            .background("transparent")
+            .splitter_brush(colors::BOMBAY_COLOR)
+            .splitter_width(4.0)
+            .pressed(false)
+            .child(grid)
+            .on_mouse_move(move |states, position| {
+                states.get_mut::<SplitViewState>(id).mouse_move(position);
+                false
+            })
+            .on_global_mouse_up(move |states, _| {
+                states.get_mut::<SplitViewState>(id).release();
+            })
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        RectangleRenderObject.into()
+    }
+}
+
+// --- Helpers --
+
+// divides the space equally between the given number of panes
+fn default_ratios(count: usize) -> Vec<f64> {
+    vec![1.0 / count.max(1) as f64; count]
+}
+
+// returns the sizes of the panes for the available space, panes that are smaller than their min size take the
+// missing space from the panes that are larger than their min size
+fn pane_sizes(ratios: &[f64], min_sizes: &[f64], available: f64) -> Vec<f64> {
+    let available = available.max(0.0);
+    let min_sizes = fit_min_sizes(min_sizes, available);
+    let sizes: Vec<f64> = ratios.iter().map(|ratio| ratio * available).collect();
+    let min_size = |index: usize| min_sizes.get(index).cloned().unwrap_or_default();
+
+    let (mut missing, mut surplus) = (0.0, 0.0);
+
+    for (index, size) in sizes.iter().enumerate() {
+        missing += (min_size(index) - size).max(0.0);
+        surplus += (size - min_size(index)).max(0.0);
+    }
+
+    if missing <= 0.0 {
+        return sizes;
+    }
+
+    let shrink = if surplus > 0.0 {
+        (missing / surplus).min(1.0)
+    } else {
+        0.0
+    };
+
+    sizes
+        .iter()
+        .enumerate()
+        .map(|(index, size)| {
+            let min = min_size(index);
+
+            if *size < min {
+                min
+            } else {
+                size - (size - min) * shrink
+            }
+        })
+        .collect()
+}
+
+// returns the min sizes of the panes, if the available space is too small for all of them they are shrunk
+// proportionally
+fn fit_min_sizes(min_sizes: &[f64], available: f64) -> Vec<f64> {
+    let min_total: f64 = min_sizes.iter().sum();
+
+    if min_total <= available {
+        return min_sizes.to_vec();
+    }
+
+    let scale = available.max(0.0) / min_total;
+    min_sizes.iter().map(|min_size| min_size * scale).collect()
+}
+
+// returns the sizes of the panes relative to their sum
+fn split_ratios(sizes: &[f64]) -> Vec<f64> {
+    let total: f64 = sizes.iter().sum();
+
+    if total <= 0.0 {
+        return default_ratios(sizes.len());
+    }
+
+    sizes.iter().map(|size| size / total).collect()
+}
+
+// moves the given splitter to the offset inside of the split view, only the panes next to the splitter are
+// resized and both keep their min size
+fn drag_splitter(
+    sizes: &[f64],
+    min_sizes: &[f64],
+    splitter: usize,
+    splitter_width: f64,
+    offset: f64,
+) -> Vec<f64> {
+    let mut sizes = sizes.to_vec();
+
+    if splitter + 1 >= sizes.len() {
+        return sizes;
+    }
+
+    let start = sizes[..splitter].iter().sum::<f64>() + splitter_width * splitter as f64;
+    let space = sizes[splitter] + sizes[splitter + 1];
+    let min_sizes = fit_min_sizes(
+        &[
+            min_sizes.get(splitter).cloned().unwrap_or_default(),
+            min_sizes.get(splitter + 1).cloned().unwrap_or_default(),
+        ],
+        space,
+    );
+    let (min_first, min_second) = (min_sizes[0], min_sizes[1]);
+
+    let first = (offset - splitter_width / 2.0 - start)
+        .min(space - min_second)
+        .max(min_first);
+
+    sizes[splitter] = first;
+    sizes[splitter + 1] = space - first;
+
+    sizes
+}
+
+// returns the blocks of the panes with a splitter between two panes, the last pane takes the remaining space
+fn blocks(sizes: &[f64], splitter_width: f64) -> Blocks {
+    let mut builder = Blocks::create();
+
+    for (index, size) in sizes.iter().enumerate() {
+        if index > 0 {
+            builder = builder.push(splitter_width);
+        }
+
+        if index + 1 == sizes.len() {
+            builder = builder.push("*");
+        } else {
+            builder = builder.push(*size);
+        }
+    }
+
+    builder.build()
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_shell::MockShell;
+
+    const ERROR: f64 = f64::EPSILON;
+
+    #[test]
+    fn test_drag_splitter() {
+        let sizes = pane_sizes(&default_ratios(2), &[0.0, 0.0], 204.0 - 4.0);
+        assert_eq!(sizes, vec![100.0, 100.0]);
+
+        // dragging the splitter to the right increases the width of the left pane
+        let dragged = drag_splitter(&sizes, &[50.0, 0.0], 0, 4.0, 152.0);
+        assert!((dragged[0] - 150.0).abs() < ERROR);
+        assert!((dragged[1] - 50.0).abs() < ERROR);
+
+        let ratios = split_ratios(&dragged);
+        assert!((ratios[0] - 0.75).abs() < ERROR);
+        assert!((ratios[1] - 0.25).abs() < ERROR);
+
+        // the min width of the left pane is respected
+        let dragged = drag_splitter(&sizes, &[50.0, 0.0], 0, 4.0, 10.0);
+        assert!((dragged[0] - 50.0).abs() < ERROR);
+        assert!((dragged[1] - 150.0).abs() < ERROR);
+
+        // the min width of the right pane is respected
+        let dragged = drag_splitter(&sizes, &[50.0, 30.0], 0, 4.0, 500.0);
+        assert!((dragged[0] - 170.0).abs() < ERROR);
+        assert!((dragged[1] - 30.0).abs() < ERROR);
+
+        // the min widths are shrunk proportionally if the panes do not fit, no pane gets a negative width
+        let dragged = drag_splitter(&sizes, &[150.0, 250.0], 0, 4.0, 10.0);
+        assert!((dragged[0] - 75.0).abs() < ERROR);
+        assert!((dragged[1] - 125.0).abs() < ERROR);
+
+        let dragged = drag_splitter(&sizes, &[150.0, 250.0], 0, 4.0, 500.0);
+        assert!((dragged[0] - 75.0).abs() < ERROR);
+        assert!((dragged[1] - 125.0).abs() < ERROR);
+
+        // only the panes next to the splitter are resized
+        let sizes = [100.0, 100.0, 100.0];
+        let dragged = drag_splitter(&sizes, &[0.0, 0.0, 0.0], 1, 4.0, 156.0);
+        assert_eq!(dragged, vec![100.0, 50.0, 150.0]);
+    }
+
+    #[test]
+    fn test_pane_sizes() {
+        // the missing space of the small pane is taken from the pane that is larger than its min size
+        let sizes = pane_sizes(&[0.1, 0.9], &[50.0, 0.0], 200.0);
+        assert!((sizes[0] - 50.0).abs() < ERROR);
+        assert!((sizes[1] - 150.0).abs() < ERROR);
+
+        let sizes = pane_sizes(&[0.1, 0.3, 0.6], &[50.0, 60.0, 0.0], 200.0);
+        assert!((sizes[0] - 50.0).abs() < ERROR);
+        assert!((sizes[1] - 60.0).abs() < ERROR);
+        assert!((sizes[2] - 90.0).abs() < ERROR);
+
+        // the min sizes are shrunk proportionally if they do not fit
+        let sizes = pane_sizes(&[0.5, 0.5], &[150.0, 250.0], 200.0);
+        assert!((sizes[0] - 75.0).abs() < ERROR);
+        assert!((sizes[1] - 125.0).abs() < ERROR);
+
+        assert_eq!(
+            pane_sizes(&[0.5, 0.5], &[10.0, 10.0], -20.0),
+            vec![0.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn test_initial_layout() {
+        let mut shell = MockShell::new(|ctx| {
+            Window::new()
+                .width(204.0)
+                .height(100.0)
+                .child(
+                    SplitView::new()
+                        .ratios(vec![0.1, 0.9])
+                        .pane(Container::new().id("left").min_width(50.0).build(ctx))
+                        .pane(Container::new().id("right").build(ctx))
+                        .build(ctx),
+                )
+                .build(ctx)
+        });
+
+        // the columns of the panes are set after the first layout
+        shell.run();
+
+        let left = shell.get::<Rectangle>("left", "bounds");
+        let right = shell.get::<Rectangle>("right", "bounds");
+        assert!((left.width() - 50.0).abs() < ERROR);
+        assert!((right.width() - 150.0).abs() < ERROR);
+    }
+}
@@ -9,7 +9,14 @@ widget!(
         orientation: Orientation,
 
         /// Margin between widgets in the stack.
-        spacing: f64
+        spacing: f64,
+
+        /// If set, the margins of two adjacent widgets collapse to the larger one of both instead of adding up.
+        collapse_margins: bool,
+
+        /// Sets or shares the flow direction, a horizontal stack with right to left flow direction places the first
+        /// widget at the right edge.
+        flow_direction: FlowDirection
     }
 );
 
@@ -22,3 +29,38 @@ impl Template for Stack {
         Box::new(StackLayout::new())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{mock_shell::MockShell, Container, Window};
+
+    #[test]
+    fn test_right_to_left() {
+        let mut shell = MockShell::new(|ctx| {
+            Window::new()
+                .width(200.0)
+                .height(100.0)
+                .child(
+                    Stack::new()
+                        .id("stack")
+                        .orientation("horizontal")
+                        .flow_direction(FlowDirection::RightToLeft)
+                        .h_align("start")
+                        .child(Container::new().id("first").width(20.0).build(ctx))
+                        .child(Container::new().id("second").width(30.0).build(ctx))
+                        .build(ctx),
+                )
+                .build(ctx)
+        });
+
+        // the stack takes the width of its children, the first child is placed at its right edge
+        let stack = shell.get::<Rectangle>("stack", "bounds");
+        let first = shell.get::<Rectangle>("first", "bounds");
+        let second = shell.get::<Rectangle>("second", "bounds");
+        assert!((stack.width() - 50.0).abs() < f64::EPSILON);
+        assert!((first.x() - 30.0).abs() < f64::EPSILON);
+        assert!((first.x() + first.width() - stack.width()).abs() < f64::EPSILON);
+        assert!(second.x().abs() < f64::EPSILON);
+    }
+}
@@ -0,0 +1,219 @@
+use crate::{api::prelude::*, prelude::*, proc_macros::*, theme_default::prelude::*};
+
+/// Describes the group of a segment of the `StatusBar`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StatusBarGroup {
+    /// The segment is placed at the left of the bar.
+    Start,
+
+    /// The segment is placed in the center of the bar.
+    Center,
+
+    /// The segment is placed at the right of the bar.
+    End,
+}
+
+impl StatusBarGroup {
+    // returns the column of the group inside of the bar and the alignment of the group inside of its column
+    fn placement(self) -> (usize, &'static str) {
+        match self {
+            StatusBarGroup::Start => (0, "start"),
+            StatusBarGroup::Center => (1, "center"),
+            StatusBarGroup::End => (2, "end"),
+        }
+    }
+}
+
+/// The `StatusBarState` holds the segments of a `StatusBar` until they are placed into their groups.
+#[derive(Default, AsAny)]
+pub struct StatusBarState {
+    segments: Vec<(StatusBarGroup, Entity)>,
+}
+
+impl State for StatusBarState {}
+
+widget!(
+    /// The `StatusBar` is a bar at the bottom of a window that shows segments in a start, a center and an end
+    /// group. Each segment is sized to its content, the segments of a group are divided by separators.
+    ///
+    /// **style:** `status_bar`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// StatusBar::new()
+    ///     .start(TextBlock::new().text("Ready").build(ctx))
+    ///     .center(TextBlock::new().text("main.rs").build(ctx))
+    ///     .end(TextBlock::new().text("Ln 1, Col 1").build(ctx))
+    ///     .end(TextBlock::new().text("UTF-8").build(ctx))
+    ///     .build(ctx)
+    /// ```
+    StatusBar<StatusBarState> {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the brush of the separators between the segments.
+        separator_brush: Brush,
+
+        /// Sets or shares the spacing between a segment and a separator.
+        spacing: f64
+    }
+);
+
+impl StatusBar {
+    /// Adds a segment to the given group.
+    pub fn segment(mut self, group: StatusBarGroup, segment: Entity) -> Self {
+        self.state_mut().segments.push((group, segment));
+        self
+    }
+
+    /// Adds a segment to the start group.
+    pub fn start(self, segment: Entity) -> Self {
+        self.segment(StatusBarGroup::Start, segment)
+    }
+
+    /// Adds a segment to the center group.
+    pub fn center(self, segment: Entity) -> Self {
+        self.segment(StatusBarGroup::Center, segment)
+    }
+
+    /// Adds a segment to the end group.
+    pub fn end(self, segment: Entity) -> Self {
+        self.segment(StatusBarGroup::End, segment)
+    }
+}
+
+impl Template for StatusBar {
+    fn template(mut self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let segments = std::mem::take(&mut self.state_mut().segments);
+        let mut grid = Grid::new().columns("*, auto, *");
+
+        for group in &[
+            StatusBarGroup::Start,
+            StatusBarGroup::Center,
+            StatusBarGroup::End,
+        ] {
+            let (column, h_align) = group.placement();
+            let mut stack = Stack::new()
+                .orientation("horizontal")
+                .spacing(id)
+                .h_align(h_align)
+                .attach(Grid::column(column));
+
+            for segment in group_children(&segments, *group) {
+                let child = match segment {
+                    Some(segment) => segment,
+                    None => Container::new()
+                        .width(1.0)
+                        .margin((0.0, 2.0, 0.0, 2.0))
+                        .background(("separator_brush", id))
+                        .build(ctx),
+                };
+
+                stack = stack.child(child);
+            }
+
+            grid = grid.child(stack.build(ctx));
+        }
+
+        self.name("StatusBar")
+            .style("status_bar")
+            .v_align("end")
+            .height(24.0)
+            .background(colors::BRIGHT_GRAY_COLOR)
+            .border_width(0.0)
+            .border_brush("transparent")
+            .padding((4.0, 0.0, 4.0, 0.0))
+            .separator_brush(colors::BOMBAY_COLOR)
+            .spacing(8.0)
+            .child(
+                Container::new()
+                    .background(id)
+                    .border_width(id)
+                    .border_brush(id)
+                    .padding(id)
+                    .child(grid.build(ctx))
+                    .build(ctx),
+            )
+    }
+}
+
+// --- Helpers --
+
+// returns the segments of the given group, a separator (`None`) is inserted between two segments
+fn group_children(
+    segments: &[(StatusBarGroup, Entity)],
+    group: StatusBarGroup,
+) -> Vec<Option<Entity>> {
+    let mut children = vec![];
+
+    for (_, segment) in segments.iter().filter(|(g, _)| *g == group) {
+        if !children.is_empty() {
+            children.push(None);
+        }
+
+        children.push(Some(*segment));
+    }
+
+    children
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_shell::MockShell;
+
+    fn segment(id: &str, width: f64, ctx: &mut BuildContext) -> Entity {
+        Container::new().id(id).width(width).build(ctx)
+    }
+
+    #[test]
+    fn test_groups() {
+        let mut shell = MockShell::new(|ctx| {
+            Window::new()
+                .width(300.0)
+                .height(100.0)
+                .child(
+                    StatusBar::new()
+                        .end(segment("end_1", 30.0, ctx))
+                        .start(segment("start", 40.0, ctx))
+                        .center(segment("center", 20.0, ctx))
+                        .end(segment("end_2", 30.0, ctx))
+                        .build(ctx),
+                )
+                .build(ctx)
+        });
+
+        let position = |shell: &mut MockShell, id: &str| shell.get::<Point>(id, "position");
+        let width = |shell: &mut MockShell, id: &str| shell.get::<Rectangle>(id, "bounds").width();
+
+        // the bar is placed at the bottom of the window
+        assert!((position(&mut shell, "start").y() - 76.0).abs() < f64::EPSILON);
+
+        // the start group is aligned to the left, the center group to the center and the end group to the right
+        assert!((position(&mut shell, "start").x() - 4.0).abs() < f64::EPSILON);
+
+        let center = position(&mut shell, "center").x() + width(&mut shell, "center") / 2.0;
+        assert!((center - 150.0).abs() < f64::EPSILON);
+
+        let end = position(&mut shell, "end_2").x() + width(&mut shell, "end_2");
+        assert!((end - 296.0).abs() < f64::EPSILON);
+
+        // the segments of a group are divided by a separator with spacing on both sides
+        let gap = position(&mut shell, "end_2").x()
+            - position(&mut shell, "end_1").x()
+            - width(&mut shell, "end_1");
+        assert!((gap - 17.0).abs() < f64::EPSILON);
+    }
+}
@@ -1,6 +1,8 @@
+use std::time::Duration;
+
 use super::behaviors::MouseBehavior;
 
-use crate::{api::prelude::*, prelude::*, proc_macros::*};
+use crate::{api::prelude::*, prelude::*, proc_macros::*, shell::prelude::*};
 
 // --- KEYS --
 static ID_SWITCH_TRACK: &str = "switch_track";
@@ -14,12 +16,26 @@ pub enum SwitchAction {
 
     /// Adjust visual state after selection changed.
     SelectionChanged,
+
+    /// Requests the keyboard focus for the switch.
+    Focus,
+
+    /// Toggles the selection state if the switch is focused and the key is `Space` or `Enter`.
+    KeyDown(KeyEvent),
+
+    /// Moves the toggle to its next position of the running animation.
+    Animate,
 }
 
 /// State to handle the position of switch toggle.
 #[derive(Default, AsAny)]
 pub struct SwitchState {
     switch_toggle: Entity,
+    window: Entity,
+    // margin of the toggle without animation
+    toggle_margin: Thickness,
+    // animates the left margin of the toggle
+    animation: Option<Transition>,
 }
 
 impl SwitchState {
@@ -31,10 +47,112 @@ impl SwitchState {
         ctx.widget().update(false);
     }
 
+    // requests the keyboard focus from the window
+    fn request_focus(&self, ctx: &mut Context) {
+        if !*Switch::focused_ref(&ctx.widget()) {
+            ctx.event_adapter()
+                .push_event_direct(self.window, FocusEvent::RequestFocus(ctx.entity()));
+        }
+    }
+
+    // toggles the selection if the switch is focused and the toggle key is pressed
+    fn key_down(&self, event: KeyEvent, ctx: &mut Context) {
+        if *Switch::focused_ref(&ctx.widget()) && is_toggle_key(event.key) {
+            self.toggle_selection(ctx);
+        }
+    }
+
+    // slides the toggle to the position of the selection state if a transition duration is set, otherwise the
+    // visual state is updated immediately
+    fn start_animation(&mut self, ctx: &mut Context) {
+        let duration = motion::resolve_duration(Duration::from_millis(
+            Switch::transition_duration_ref(&ctx.widget()).max(0.0) as u64,
+        ));
+
+        if duration.as_millis() == 0 {
+            self.animation = None;
+            self.update_visual(ctx);
+            return;
+        }
+
+        let selected: bool = *Switch::selected_ref(&ctx.widget());
+        let available_width = ctx.widget().get::<Rectangle>("bounds").width();
+
+        // starts from the current position, so a running animation is reversed smoothly
+        let (from, to) = {
+            let mut switch_toggle = ctx.get_widget(self.switch_toggle);
+            let bounds = switch_toggle.clone::<Rectangle>("bounds");
+
+            if selected {
+                switch_toggle
+                    .get_mut::<Selector>("selector")
+                    .push_state("selected");
+            } else {
+                switch_toggle
+                    .get_mut::<Selector>("selector")
+                    .remove_state("selected");
+            }
+
+            switch_toggle.update(true);
+            switch_toggle.set("h_align", Alignment::from("start"));
+
+            (
+                bounds.x(),
+                toggle_offset(
+                    selected,
+                    available_width,
+                    bounds.width(),
+                    self.toggle_margin,
+                ),
+            )
+        };
+
+        self.set_toggle_offset(ctx, from);
+        self.animation = Some(Transition::new(
+            "margin",
+            TransitionValue::Number(from),
+            TransitionValue::Number(to),
+            duration.as_millis() as u64,
+        ));
+
+        // the animation is driven by `update_post_layout`, which is only called for dirty widgets
+        ctx.widget().set("dirty", true);
+    }
+
+    // moves the toggle to the current position of the animation, finished animations are replaced by the final
+    // visual state
+    fn animate(&mut self, ctx: &mut Context) {
+        let time = now();
+
+        let offset = match self.animation.as_mut().map(|a| a.value(time)) {
+            Some(TransitionValue::Number(offset)) => offset,
+            _ => return,
+        };
+
+        if self.animation.as_ref().unwrap().is_finished(time) {
+            self.animation = None;
+            self.update_visual(ctx);
+            return;
+        }
+
+        self.set_toggle_offset(ctx, offset);
+
+        // the update of the next frame is triggered by a message, the switch is set dirty by the message handler
+        ctx.send_message(SwitchAction::Animate, ctx.entity());
+        ctx.send_window_request(WindowRequest::Redraw);
+    }
+
+    fn set_toggle_offset(&self, ctx: &mut Context, offset: f64) {
+        let mut margin = self.toggle_margin;
+        margin.set_left(offset);
+        ctx.get_widget(self.switch_toggle).set("margin", margin);
+    }
+
     // update the visual state to the selection state.
     fn update_visual(&self, ctx: &mut Context) {
         let selected: bool = *Switch::selected_ref(&ctx.widget());
         let mut switch_toggle = ctx.get_widget(self.switch_toggle);
+        switch_toggle.set("margin", self.toggle_margin);
 
         if selected {
             switch_toggle.set("h_align", Alignment::from("end"));
@@ -55,6 +173,10 @@ impl SwitchState {
 impl State for SwitchState {
     fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
         self.switch_toggle = ctx.child(ID_SWITCH_TOGGLE).entity();
+        self.window = ctx.entity_of_window();
+        self.toggle_margin = ctx
+            .get_widget(self.switch_toggle)
+            .clone::<Thickness>("margin");
         self.update_visual(ctx);
     }
 
@@ -67,14 +189,27 @@ impl State for SwitchState {
         for message in messages.read::<SwitchAction>() {
             match message {
                 SwitchAction::ToggleSelection => self.toggle_selection(ctx),
-                SwitchAction::SelectionChanged => self.update_visual(ctx),
+                SwitchAction::SelectionChanged => self.start_animation(ctx),
+                SwitchAction::Focus => self.request_focus(ctx),
+                SwitchAction::KeyDown(event) => self.key_down(event, ctx),
+                SwitchAction::Animate => ctx.widget().set("dirty", true),
             }
         }
     }
+
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if self.animation.is_some() {
+            self.animate(ctx);
+        }
+    }
 }
 
 widget!(
-    /// The `Switch` widget can be switch between `selected` and not `selected`.
+    /// The `Switch` widget can be switch between `selected` and not `selected`. A click focuses the switch, a
+    /// focused switch is also toggled by the `Space` and the `Enter` key.
+    ///
+    /// If `transition_duration` is greater than zero, the toggle slides to its new position within the given
+    /// milliseconds. With reduced motion enabled the toggle always moves immediately.
     ///
     /// **style:** `switch`
     ///
@@ -83,7 +218,7 @@ widget!(
     /// ```rust
     /// Switch::new().selected(true).build(ctx)
     /// ```
-    Switch<SwitchState>: MouseHandler {
+    Switch<SwitchState>: KeyDownHandler, MouseHandler {
         /// Sets or shares the background property.
         background: Brush,
 
@@ -99,6 +234,9 @@ widget!(
         /// Sets or shares the padding property.
         padding: Thickness,
 
+        /// Sets or shares the focused property.
+        focused: bool,
+
         /// Sets or shares the pressed property.
         pressed: bool,
 
@@ -109,7 +247,11 @@ widget!(
         hover: bool,
 
         /// Defines the margin around the inner border.
-        container_margin: Thickness
+        container_margin: Thickness,
+
+        /// Sets or shares the duration in milliseconds the toggle needs to slide to its new position. `0.0`
+        /// moves the toggle immediately.
+        transition_duration: f64
     }
 );
 
@@ -117,6 +259,7 @@ impl Template for Switch {
     fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
         self.name("Switch")
             .style("switch")
+            .focused(false)
             .pressed(false)
             .selected(false)
             .width(36.0)
@@ -125,11 +268,16 @@ impl Template for Switch {
             .border_width(1.0)
             .padding(4.0)
             .container_margin((2, 8))
+            .transition_duration(0.0)
             .child(
                 MouseBehavior::new()
                     .pressed(id)
                     .enabled(id)
                     .target(id.0)
+                    .on_mouse_down(move |ctx, _| {
+                        ctx.send_message(SwitchAction::Focus, id);
+                        false
+                    })
                     .on_click(move |ctx, _| {
                         ctx.send_message(SwitchAction::ToggleSelection, id);
                         false
@@ -162,5 +310,95 @@ impl Template for Switch {
             .on_changed("selected", move |ctx, _| {
                 ctx.send_message(SwitchAction::SelectionChanged, id);
             })
+            .on_key_down(move |ctx, event| {
+                ctx.send_message(SwitchAction::KeyDown(event), id);
+                false
+            })
+    }
+}
+
+// --- Helpers --
+
+// returns the left margin of the toggle at the end of the track if the switch is selected, otherwise at the start
+fn toggle_offset(
+    selected: bool,
+    available_width: f64,
+    toggle_width: f64,
+    margin: Thickness,
+) -> f64 {
+    if selected {
+        return available_width - toggle_width - margin.right();
+    }
+
+    margin.left()
+}
+
+// returns `true` if the given key toggles a focused switch
+fn is_toggle_key(key: Key) -> bool {
+    key == Key::Space || key == Key::Enter
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_shell::MockShell;
+
+    #[test]
+    fn test_key_toggle() {
+        let mut shell = MockShell::new(|ctx| {
+            Window::new()
+                .width(200.0)
+                .height(200.0)
+                .child(
+                    Switch::new()
+                        .id("switch")
+                        .h_align("start")
+                        .v_align("start")
+                        .build(ctx),
+                )
+                .build(ctx)
+        });
+
+        // the switch is not focused yet
+        shell.key_press(Key::Space);
+        assert!(!shell.get::<bool>("switch", "selected"));
+
+        // the click focuses and toggles the switch
+        shell.click(18.0, 15.0);
+        assert!(shell.get::<bool>("switch", "focused"));
+        assert!(shell.get::<bool>("switch", "selected"));
+
+        shell.key_press(Key::Space);
+        assert!(!shell.get::<bool>("switch", "selected"));
+
+        shell.key_press(Key::Enter);
+        assert!(shell.get::<bool>("switch", "selected"));
+
+        shell.key_press(Key::Escape);
+        assert!(shell.get::<bool>("switch", "selected"));
+    }
+
+    #[test]
+    fn test_toggle_animation() {
+        let margin = Thickness::new(4.0, 0.0, 4.0, 0.0);
+
+        assert!((toggle_offset(false, 40.0, 10.0, margin) - 4.0).abs() < f64::EPSILON);
+        assert!((toggle_offset(true, 40.0, 10.0, margin) - 26.0).abs() < f64::EPSILON);
+
+        // the toggle slides from the start to the end of the track
+        let mut animation = Transition::new(
+            "margin",
+            TransitionValue::Number(toggle_offset(false, 40.0, 10.0, margin)),
+            TransitionValue::Number(toggle_offset(true, 40.0, 10.0, margin)),
+            150,
+        );
+
+        assert_eq!(animation.value(1000.0), TransitionValue::Number(4.0));
+        assert_eq!(animation.value(1075.0), TransitionValue::Number(15.0));
+        assert!(!animation.is_finished(1075.0));
+        assert_eq!(animation.value(1150.0), TransitionValue::Number(26.0));
+        assert!(animation.is_finished(1150.0));
     }
 }
@@ -0,0 +1,455 @@
+use super::behaviors::MouseBehavior;
+use crate::{
+    api::prelude::*, prelude::*, proc_macros::*, shell::prelude::*, theme_default::prelude::*,
+};
+
+// --- KEYS --
+
+static OVERFLOW_BUTTON: &str = "overflow_button";
+
+// --- KEYS --
+
+// width that is reserved for the overflow button if not all items fit into the toolbar
+const OVERFLOW_BUTTON_WIDTH: f64 = 32.0;
+
+/// The `ToolbarState` moves the items that do not fit into the width of the `Toolbar` into its overflow menu.
+#[derive(Default, AsAny)]
+pub struct ToolbarState {
+    // widths of the items including their margin, items of the overflow menu keep their last width
+    widths: HashMap<Entity, f64>,
+    overflow: Vec<Entity>,
+    overflow_button: Entity,
+    drop_down: Option<Entity>,
+    toggle_drop_down: bool,
+    click: Option<Entity>,
+    mouse_up: Option<Point>,
+}
+
+impl ToolbarState {
+    /// Returns the items that are moved into the overflow menu.
+    pub fn overflow(&self) -> &[Entity] {
+        &self.overflow
+    }
+
+    fn toggle_drop_down(&mut self) {
+        self.toggle_drop_down = true;
+    }
+
+    fn click_item(&mut self, item: Entity) {
+        self.click = Some(item);
+    }
+
+    fn mouse_up(&mut self, position: Point) {
+        if self.drop_down.is_some() {
+            self.mouse_up = Some(position);
+        }
+    }
+
+    // returns the items of the toolbar with their width, items that are collapsed by the user are skipped
+    fn items(&mut self, ctx: &mut Context) -> Vec<(Entity, f64)> {
+        let mut items = vec![];
+        let mut index = 0;
+
+        while let Some(child) = ctx.try_child_from_index(index) {
+            index += 1;
+
+            let entity = child.entity();
+
+            if entity == self.overflow_button {
+                continue;
+            }
+
+            if *child.get::<Visibility>("visibility") == Visibility::Collapsed {
+                if self.overflow.contains(&entity) {
+                    items.push((
+                        entity,
+                        self.widths.get(&entity).cloned().unwrap_or_default(),
+                    ));
+                }
+                continue;
+            }
+
+            let bounds = child.get::<Rectangle>("bounds");
+            let margin = child.get::<Thickness>("margin");
+            let width = bounds.width() + margin.left() + margin.right();
+
+            self.widths.insert(entity, width);
+            items.push((entity, width));
+        }
+
+        items
+    }
+
+    // returns the width that is available for the items, the stack layout sizes the toolbar to its items so the
+    // width is taken from the width constraint of the toolbar or from its parent
+    fn available_width(&self, ctx: &mut Context) -> f64 {
+        let constraint = Toolbar::constraint_clone(&ctx.widget());
+
+        if constraint.width() > 0.0 {
+            return constraint.width();
+        }
+
+        let margin = *ctx.widget().get::<Thickness>("margin");
+        let parent_width = ctx.parent().get::<Rectangle>("bounds").width();
+
+        (parent_width - margin.left() - margin.right()).min(constraint.max_width())
+    }
+
+    fn open_drop_down(&mut self, ctx: &mut Context) {
+        let entity = ctx.entity();
+        let window_width = ctx.window().get::<Rectangle>("bounds").width();
+
+        // the drop-down is aligned to the right edge of the overflow button
+        let (position, bounds) = {
+            let overflow_button = ctx.get_widget(self.overflow_button);
+            (
+                overflow_button.clone::<Point>("position"),
+                overflow_button.clone::<Rectangle>("bounds"),
+            )
+        };
+
+        let texts: Vec<String> = self
+            .overflow
+            .iter()
+            .map(|item| {
+                ctx.get_widget(*item)
+                    .try_clone::<String>("text")
+                    .unwrap_or_default()
+            })
+            .collect();
+
+        let (drop_down, items) = {
+            let build_context = &mut ctx.build_context();
+            let items_panel = Stack::new().build(build_context);
+            let mut items = vec![];
+
+            for (item, text) in self.overflow.iter().zip(texts) {
+                let item = *item;
+
+                let content = TextBlock::new()
+                    .v_align("center")
+                    .text(text)
+                    .build(build_context);
+
+                let menu_item = MenuBarItem::new()
+                    .on_click(move |states, _| {
+                        states.get_mut::<ToolbarState>(entity).click_item(item);
+                        false
+                    })
+                    .build(build_context);
+
+                let mouse_behavior = MouseBehavior::new()
+                    .target(menu_item.0)
+                    .build(build_context);
+                build_context.register_shared_property::<Selector>(
+                    "selector",
+                    mouse_behavior,
+                    menu_item,
+                );
+                build_context.register_shared_property::<bool>(
+                    "pressed",
+                    mouse_behavior,
+                    menu_item,
+                );
+                build_context.append_child(menu_item, mouse_behavior);
+                build_context.append_child(mouse_behavior, content);
+                build_context.append_child(items_panel, menu_item);
+                items.push(menu_item);
+            }
+
+            let drop_down = Container::new()
+                .style("popup")
+                .h_align("end")
+                .v_align("start")
+                .min_width(120.0)
+                .padding(2.0)
+                .margin((
+                    0.0,
+                    position.y() + bounds.height(),
+                    window_width - position.x() - bounds.width(),
+                    0.0,
+                ))
+                .child(items_panel)
+                .build(build_context);
+            let _ = build_context.append_child_to_overlay(drop_down);
+
+            (drop_down, items)
+        };
+
+        ctx.get_widget(drop_down)
+            .update_widget(entity, false, false);
+
+        for item in items {
+            ctx.get_widget(item).update_widget(entity, false, false);
+        }
+
+        self.drop_down = Some(drop_down);
+    }
+
+    fn close_drop_down(&mut self, ctx: &mut Context) {
+        if let Some(drop_down) = self.drop_down.take() {
+            let _ = ctx.remove_child_from_overlay(drop_down);
+        }
+    }
+}
+
+impl State for ToolbarState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.overflow_button = ctx
+            .entity_of_child(OVERFLOW_BUTTON)
+            .expect("ToolbarState.init: Overflow button child could not be found.");
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        // a click outside of the drop-down and the overflow button closes the drop-down
+        if let (Some(position), Some(drop_down)) = (self.mouse_up.take(), self.drop_down) {
+            let in_drop_down = check_mouse_condition(position, &ctx.get_widget(drop_down));
+            let in_overflow_button =
+                check_mouse_condition(position, &ctx.get_widget(self.overflow_button));
+
+            if !in_drop_down && !in_overflow_button {
+                self.close_drop_down(ctx);
+            }
+        }
+
+        // forwards the click of an item of the overflow menu to the original item
+        if let Some(item) = self.click.take() {
+            ctx.event_adapter().push_event_direct(
+                item,
+                ClickEvent {
+                    position: Point::default(),
+                },
+            );
+            self.close_drop_down(ctx);
+        }
+
+        if self.toggle_drop_down {
+            self.toggle_drop_down = false;
+
+            if self.drop_down.is_some() {
+                self.close_drop_down(ctx);
+            } else if !self.overflow.is_empty() {
+                self.open_drop_down(ctx);
+            }
+        }
+    }
+
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let items = self.items(ctx);
+        let widths: Vec<f64> = items.iter().map(|(_, width)| *width).collect();
+        let available = self.available_width(ctx);
+        let spacing = *ctx.widget().get::<f64>("spacing");
+
+        let count = visible_count(&widths, available, spacing, OVERFLOW_BUTTON_WIDTH);
+        let overflow: Vec<Entity> = items[count..].iter().map(|(item, _)| *item).collect();
+
+        if overflow == self.overflow {
+            return;
+        }
+
+        for (index, (item, _)) in items.iter().enumerate() {
+            let visibility = if index < count {
+                Visibility::Visible
+            } else {
+                Visibility::Collapsed
+            };
+
+            ctx.get_widget(*item).set("visibility", visibility);
+        }
+
+        ctx.get_widget(self.overflow_button).set(
+            "visibility",
+            if overflow.is_empty() {
+                Visibility::Collapsed
+            } else {
+                Visibility::Visible
+            },
+        );
+
+        self.overflow = overflow;
+        self.close_drop_down(ctx);
+        ctx.send_window_request(WindowRequest::Redraw);
+    }
+
+    fn cleanup(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.close_drop_down(ctx);
+    }
+}
+
+widget!(
+    /// The `Toolbar` lays out its items horizontally. If its width is not sufficient, the last items are moved
+    /// into an overflow menu that is opened by the `»` button at the end of the toolbar. The overflow menu shows
+    /// the text of the items, selecting an entry clicks the item.
+    ///
+    /// **style:** `toolbar`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// Toolbar::new()
+    ///     .child(Button::new().text("Cut").build(ctx))
+    ///     .child(Button::new().text("Copy").build(ctx))
+    ///     .child(Button::new().text("Paste").build(ctx))
+    ///     .build(ctx)
+    /// ```
+    Toolbar<ToolbarState>: MouseHandler {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: f64,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the orientation property. The items of a toolbar are always laid out horizontally.
+        orientation: Orientation,
+
+        /// Sets or shares the spacing between the items.
+        spacing: f64
+    }
+);
+
+impl Template for Toolbar {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let overflow_button = Button::new()
+            .id(OVERFLOW_BUTTON)
+            .style("button_single_content")
+            .text("»")
+            .width(OVERFLOW_BUTTON_WIDTH)
+            .visibility("collapsed")
+            .on_click(move |states, _| {
+                states.get_mut::<ToolbarState>(id).toggle_drop_down();
+                true
+            })
+            .build(ctx);
+
+        self.name("Toolbar")
+            .style("toolbar")
+            .orientation("horizontal")
+            .spacing(4.0)
+            .background(colors::BRIGHT_GRAY_COLOR)
+            .border_radius(0.0)
+            .border_width(0.0)
+            .border_brush("transparent")
+            .child(overflow_button)
+            .on_global_mouse_up(move |states, mouse| {
+                states.get_mut::<ToolbarState>(id).mouse_up(mouse.position)
+            })
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        RectangleRenderObject.into()
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        StackLayout::new().into()
+    }
+}
+
+// --- Helpers --
+
+// returns the number of items that fit into the available width, the width of the overflow button is reserved
+// if not all items fit
+fn visible_count(widths: &[f64], available: f64, spacing: f64, overflow_width: f64) -> usize {
+    let total: f64 = widths.iter().sum::<f64>() + spacing * widths.len().saturating_sub(1) as f64;
+
+    if total <= available {
+        return widths.len();
+    }
+
+    let available = available - overflow_width - spacing;
+    let mut used = 0.0;
+
+    for (index, width) in widths.iter().enumerate() {
+        used += width;
+
+        if index > 0 {
+            used += spacing;
+        }
+
+        if used > available {
+            return index;
+        }
+    }
+
+    widths.len()
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use super::*;
+    use crate::mock_shell::MockShell;
+
+    fn item(id: &str, width: f64, ctx: &mut BuildContext) -> Entity {
+        Button::new().id(id).text(id).width(width).build(ctx)
+    }
+
+    #[test]
+    fn test_overflow() {
+        let clicks = Rc::new(Cell::new(0));
+        let counter = clicks.clone();
+
+        // 40 + 60 + 40 + 50 and 3 * 4 spacing do not fit into 140
+        let mut shell = MockShell::new(move |ctx| {
+            let clicks = counter.clone();
+
+            Window::new()
+                .width(140.0)
+                .height(100.0)
+                .child(
+                    Toolbar::new()
+                        .v_align("start")
+                        .child(item("item_0", 40.0, ctx))
+                        .child(item("item_1", 60.0, ctx))
+                        .child(
+                            Button::new()
+                                .id("item_2")
+                                .text("item_2")
+                                .width(40.0)
+                                .on_click(move |_, _| {
+                                    clicks.set(clicks.get() + 1);
+                                    true
+                                })
+                                .build(ctx),
+                        )
+                        .child(item("item_3", 50.0, ctx))
+                        .build(ctx),
+                )
+                .build(ctx)
+        });
+
+        // the items are measured after the first layout
+        shell.run();
+
+        let visibility =
+            |shell: &mut MockShell, id: &str| shell.get::<Visibility>(id, "visibility");
+
+        // the overflow button takes 32 + 4, so the last two items are moved into the overflow menu
+        assert_eq!(visibility(&mut shell, "item_0"), Visibility::Visible);
+        assert_eq!(visibility(&mut shell, "item_1"), Visibility::Visible);
+        assert_eq!(visibility(&mut shell, "item_2"), Visibility::Collapsed);
+        assert_eq!(visibility(&mut shell, "item_3"), Visibility::Collapsed);
+        assert_eq!(visibility(&mut shell, OVERFLOW_BUTTON), Visibility::Visible);
+
+        // the overflow button opens the drop-down below it, its first entry clicks the first collapsed item
+        let position = shell.get::<Point>(OVERFLOW_BUTTON, "position");
+        let bounds = shell.get::<Rectangle>(OVERFLOW_BUTTON, "bounds");
+        shell.click(
+            position.x() + bounds.width() / 2.0,
+            position.y() + bounds.height() / 2.0,
+        );
+        shell.click(
+            position.x() + bounds.width() / 2.0,
+            position.y() + bounds.height() + 8.0,
+        );
+        assert_eq!(clicks.get(), 1);
+    }
+}
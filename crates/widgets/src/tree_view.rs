@@ -0,0 +1,504 @@
+use std::collections::HashSet;
+
+use super::behaviors::MouseBehavior;
+use crate::{api::prelude::*, prelude::*, proc_macros::*, theme_default::prelude::*};
+
+static ITEMS_PANEL: &str = "items_panel";
+
+type ChildCount = Option<Box<dyn Fn(&[usize]) -> usize>>;
+
+type TreeItemBuilder = Option<Box<dyn Fn(&mut BuildContext, &[usize]) -> Entity>>;
+
+// describes a visible node of the tree
+#[derive(Clone, Debug, PartialEq)]
+struct TreeRow {
+    path: Vec<usize>,
+    depth: usize,
+    expandable: bool,
+    expanded: bool,
+}
+
+/// The `TreeViewState` generates the visible nodes and handles expanding, collapsing and the selection of nodes.
+///
+/// A node is described by its path, the list of child indices from the top level to the node.
+#[derive(Default, AsAny)]
+pub struct TreeViewState {
+    child_count: ChildCount,
+    builder: TreeItemBuilder,
+    expanded: HashSet<Vec<usize>>,
+    selected: Option<Vec<usize>>,
+    toggle: Option<Vec<usize>>,
+    select: Option<Vec<usize>>,
+    // generated items with the path of their node
+    items: Vec<(Vec<usize>, Entity)>,
+    items_panel: Entity,
+}
+
+impl TreeViewState {
+    /// Expands the given node if it is collapsed, otherwise the node is collapsed.
+    pub fn toggle_expanded(&mut self, path: Vec<usize>) {
+        self.toggle = Some(path);
+    }
+
+    /// Selects the given node.
+    pub fn select(&mut self, path: Vec<usize>) {
+        self.select = Some(path);
+    }
+
+    /// Returns the path of the selected node.
+    pub fn selected(&self) -> Option<&Vec<usize>> {
+        self.selected.as_ref()
+    }
+
+    /// Returns `true` if the given node is expanded.
+    pub fn is_expanded(&self, path: &[usize]) -> bool {
+        self.expanded.contains(path)
+    }
+
+    fn generate_items(&mut self, ctx: &mut Context) {
+        let (child_count, builder) = match (&self.child_count, &self.builder) {
+            (Some(child_count), Some(builder)) => (child_count, builder),
+            _ => return,
+        };
+
+        let entity = ctx.entity();
+        let indent = *ctx.widget().get::<f64>("indent");
+
+        ctx.clear_children_of(self.items_panel);
+        self.items.clear();
+
+        for row in visible_rows(&self.expanded, child_count.as_ref()) {
+            let item = {
+                let build_context = &mut ctx.build_context();
+                let child = builder(build_context, &row.path);
+                let toggle_path = row.path.clone();
+                let select_path = row.path.clone();
+
+                let expander = Button::new()
+                    .style("button_small")
+                    .v_align("center")
+                    .min_width(14)
+                    .height(15)
+                    .margin((0, 0, 4, 0))
+                    .icon(if row.expanded {
+                        material_icons_font::MD_KEYBOARD_ARROW_DOWN
+                    } else {
+                        material_icons_font::MD_KEYBOARD_ARROW_RIGHT
+                    })
+                    .visibility(if row.expandable { "visible" } else { "hidden" })
+                    .on_click(move |states, _| {
+                        states
+                            .get_mut::<TreeViewState>(entity)
+                            .toggle_expanded(toggle_path.clone());
+                        true
+                    })
+                    .build(build_context);
+
+                let content = Stack::new()
+                    .orientation("horizontal")
+                    .margin(indentation(&row, indent))
+                    .child(expander)
+                    .child(child)
+                    .build(build_context);
+
+                let item = TreeViewItem::new()
+                    .expanded(row.expanded)
+                    .on_click(move |states, _| {
+                        states
+                            .get_mut::<TreeViewState>(entity)
+                            .select(select_path.clone());
+                        false
+                    })
+                    .build(build_context);
+
+                let mouse_behavior = MouseBehavior::new().target(item.0).build(build_context);
+                build_context.register_shared_property::<Selector>(
+                    "selector",
+                    mouse_behavior,
+                    item,
+                );
+                build_context.register_shared_property::<bool>("pressed", mouse_behavior, item);
+                build_context.append_child(item, mouse_behavior);
+
+                build_context.register_shared_property::<Brush>("foreground", child, item);
+                build_context.register_shared_property::<f32>("opacity", item, entity);
+                build_context.register_shared_property::<f32>("opacity", child, entity);
+                build_context.register_shared_property::<f64>("font_size", child, item);
+                build_context.register_shared_property::<f64>("font", child, item);
+                build_context.append_child(self.items_panel, item);
+                build_context.append_child(mouse_behavior, content);
+
+                item
+            };
+            ctx.get_widget(item).update_widget(entity, false, false);
+            self.items.push((row.path, item));
+        }
+
+        self.update_selection(ctx);
+    }
+
+    // updates the selected state of the generated items
+    fn update_selection(&self, ctx: &mut Context) {
+        for (path, item) in &self.items {
+            let selected = self.selected.as_ref() == Some(path);
+            let mut widget = ctx.get_widget(*item);
+
+            if *widget.get::<bool>("selected") == selected {
+                continue;
+            }
+
+            widget.set("selected", selected);
+
+            if selected {
+                widget
+                    .get_mut::<Selector>("selector")
+                    .push_state("selected");
+            } else {
+                widget
+                    .get_mut::<Selector>("selector")
+                    .remove_state("selected");
+            }
+
+            widget.update(false);
+        }
+    }
+}
+
+impl State for TreeViewState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.items_panel = ctx
+            .entity_of_child(ITEMS_PANEL)
+            .expect("TreeViewState.init: ItemsPanel child could not be found.");
+
+        self.generate_items(ctx);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let entity = ctx.entity();
+
+        if let Some(path) = self.toggle.take() {
+            let expanded = if self.expanded.remove(&path) {
+                false
+            } else {
+                self.expanded.insert(path.clone());
+                true
+            };
+
+            self.generate_items(ctx);
+            ctx.event_adapter()
+                .push_event_direct(entity, TreeNodeExpandedEvent(entity, path, expanded));
+        }
+
+        if let Some(path) = self.select.take() {
+            if self.selected.as_ref() != Some(&path) {
+                self.selected = Some(path.clone());
+                self.update_selection(ctx);
+                ctx.event_adapter()
+                    .push_event_direct(entity, TreeNodeSelectedEvent(entity, path));
+            }
+        }
+
+        if *ctx.widget().get::<bool>("request_update") {
+            ctx.widget().set("request_update", false);
+            self.generate_items(ctx);
+        }
+    }
+}
+
+widget!(
+    /// The `TreeViewItem` describes a node inside of a `TreeView`.
+    ///
+    /// **style:** `list_view_item`
+    TreeViewItem: MouseHandler {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: f64,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the foreground property.
+        foreground: Brush,
+
+        /// Sets or share the font size property.
+        font_size: f64,
+
+        /// Sets or shares the font property.
+        font: String,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the pressed property.
+        pressed: bool,
+
+        /// Sets or shares the selected property.
+        selected: bool,
+
+        /// Sets or shares the expanded property.
+        expanded: bool
+    }
+);
+
+impl Template for TreeViewItem {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("TreeViewItem")
+            .style("list_view_item")
+            .min_width(64.0)
+            .height(24.0)
+            .selected(false)
+            .expanded(false)
+            .pressed(false)
+            .padding(0.0)
+            .background("transparent")
+            .border_radius(0.0)
+            .border_width(0.0)
+            .border_brush("transparent")
+            .foreground(colors::LINK_WATER_COLOR)
+            .font_size(32.0)
+            .font("Roboto-Regular")
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        RectangleRenderObject.into()
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        PaddingLayout::new().into()
+    }
+}
+
+widget!(
+    /// The `TreeView` shows hierarchical items. Nodes with children could be expanded and collapsed, the children
+    /// are indented by their depth. The children of a node are only generated if the node is expanded.
+    ///
+    /// **style:** `list_view`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// TreeView::new()
+    ///     // top level with two nodes, the first node has three children
+    ///     .child_count(|path| match path {
+    ///         [] => 2,
+    ///         [0] => 3,
+    ///         _ => 0,
+    ///     })
+    ///     .items_builder(|bc, path| TextBlock::new().text(format!("{:?}", path)).build(bc))
+    ///     .on_node_selected(|_, _, path| println!("Selected: {:?}", path))
+    ///     .build(ctx)
+    /// ```
+    TreeView<TreeViewState>: TreeNodeExpandedHandler, TreeNodeSelectedHandler {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: f64,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the indentation per depth of a node.
+        indent: f64,
+
+        /// Use this flag to force the redrawing of the items.
+        request_update: bool
+    }
+);
+
+impl TreeView {
+    /// Defines the function that returns the number of children of the node with the given path. The top level
+    /// nodes are requested with an empty path.
+    pub fn child_count<F: Fn(&[usize]) -> usize + 'static>(mut self, child_count: F) -> Self {
+        self.state_mut().child_count = Some(Box::new(child_count));
+        self
+    }
+
+    /// Define the template build function for the content of the node with the given path.
+    pub fn items_builder<F: Fn(&mut BuildContext, &[usize]) -> Entity + 'static>(
+        mut self,
+        builder: F,
+    ) -> Self {
+        self.state_mut().builder = Some(Box::new(builder));
+        self
+    }
+}
+
+impl Template for TreeView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let items_panel = Stack::new().v_align("start").id(ITEMS_PANEL).build(ctx);
+
+        let scroll_viewer = ScrollViewer::new()
+            .mode(("auto", "auto"))
+            .child(items_panel)
+            .build(ctx);
+
+        self.name("TreeView")
+            .style("list_view")
+            .background(colors::LYNCH_COLOR)
+            .border_radius(2.0)
+            .border_width(1.0)
+            .border_brush(colors::BOMBAY_COLOR)
+            .padding(2.0)
+            .indent(16.0)
+            .child(
+                Container::new()
+                    .background(id)
+                    .border_radius(id)
+                    .border_width(id)
+                    .border_brush(id)
+                    .padding(id)
+                    .opacity(id)
+                    .child(scroll_viewer)
+                    .child(
+                        ScrollIndicator::new()
+                            .padding(2.0)
+                            .content_bounds(("bounds", items_panel))
+                            .view_port_bounds(("bounds", scroll_viewer))
+                            .scroll_padding(("padding", scroll_viewer))
+                            .mode(scroll_viewer)
+                            .scroll_bar_visibility(scroll_viewer)
+                            .opacity(id)
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+}
+
+// --- Helpers --
+
+// returns the visible nodes, the number of children is only requested for the top level and the children of
+// expanded nodes
+fn visible_rows(
+    expanded: &HashSet<Vec<usize>>,
+    child_count: &dyn Fn(&[usize]) -> usize,
+) -> Vec<TreeRow> {
+    let mut rows = vec![];
+    push_rows(&[], 0, expanded, child_count, &mut rows);
+    rows
+}
+
+fn push_rows(
+    parent: &[usize],
+    depth: usize,
+    expanded: &HashSet<Vec<usize>>,
+    child_count: &dyn Fn(&[usize]) -> usize,
+    rows: &mut Vec<TreeRow>,
+) {
+    for i in 0..child_count(parent) {
+        let mut path = parent.to_vec();
+        path.push(i);
+
+        let is_expanded = expanded.contains(&path);
+
+        rows.push(TreeRow {
+            path: path.clone(),
+            depth,
+            expandable: child_count(&path) > 0,
+            expanded: is_expanded,
+        });
+
+        if is_expanded {
+            push_rows(&path, depth + 1, expanded, child_count, rows);
+        }
+    }
+}
+
+// returns the margin of the content of a node indented by its depth
+fn indentation(row: &TreeRow, indent: f64) -> Thickness {
+    Thickness::new(row.depth as f64 * indent, 0.0, 0.0, 0.0)
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::*;
+    use crate::mock_shell::MockShell;
+
+    fn child_count(path: &[usize]) -> usize {
+        match path {
+            [] => 2,
+            [0] => 2,
+            [0, 1] => 1,
+            _ => 0,
+        }
+    }
+
+    #[test]
+    fn test_expand_collapse() {
+        let expanded = Rc::new(RefCell::new(vec![]));
+        let selected = Rc::new(RefCell::new(vec![]));
+        let (expanded_nodes, selected_nodes) = (expanded.clone(), selected.clone());
+
+        let mut shell = MockShell::new(move |ctx| {
+            let (expanded, selected) = (expanded_nodes.clone(), selected_nodes.clone());
+
+            Window::new()
+                .width(300.0)
+                .height(300.0)
+                .child(
+                    TreeView::new()
+                        .width(200.0)
+                        .height(200.0)
+                        .h_align("start")
+                        .v_align("start")
+                        .child_count(child_count)
+                        .items_builder(|ctx, path| {
+                            TextBlock::new()
+                                .id(format!("node_{:?}", path))
+                                .text(format!("{:?}", path))
+                                .build(ctx)
+                        })
+                        .on_node_expanded(move |_, _, path, is_expanded| {
+                            expanded.borrow_mut().push((path, is_expanded));
+                        })
+                        .on_node_selected(move |_, _, path| selected.borrow_mut().push(path))
+                        .build(ctx),
+                )
+                .build(ctx)
+        });
+
+        // only the top level nodes are generated
+        assert!(shell.contains("node_[0]"));
+        assert!(shell.contains("node_[1]"));
+        assert!(!shell.contains("node_[0, 0]"));
+
+        // the expander of the first node reveals its children, they are indented by their depth
+        shell.click(6.0, 14.0);
+        shell.run();
+        assert_eq!(*expanded.borrow(), vec![(vec![0], true)]);
+        assert!(shell.contains("node_[0, 0]"));
+        assert!(shell.contains("node_[0, 1]"));
+        assert!(!shell.contains("node_[0, 1, 0]"));
+
+        let indent = shell.get::<Point>("node_[0, 0]", "position").x()
+            - shell.get::<Point>("node_[0]", "position").x();
+        assert!((indent - 16.0).abs() < f64::EPSILON);
+
+        // a click on the row of a node selects it, the second top level node is the fourth row now
+        shell.click(150.0, 86.0);
+        assert_eq!(*selected.borrow(), vec![vec![1]]);
+
+        // collapsing hides the children
+        shell.click(6.0, 14.0);
+        shell.run();
+        assert_eq!(expanded.borrow().last(), Some(&(vec![0], false)));
+        assert!(!shell.contains("node_[0, 0]"));
+        assert!(shell.contains("node_[1]"));
+    }
+}
@@ -0,0 +1,44 @@
+use crate::{api::prelude::*, proc_macros::*};
+
+widget!(
+    /// The `UniformGrid` places its children row by row into cells of the same size. If `column_count` is `0`
+    /// the number of columns is calculated from the aspect ratio of the grid, so the cells get nearly square.
+    ///
+    /// **style:** `uniform_grid`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// UniformGrid::new()
+    ///     .column_count(3)
+    ///     .child(Button::new().text("1").build(ctx))
+    ///     .child(Button::new().text("2").build(ctx))
+    ///     .child(Button::new().text("3").build(ctx))
+    ///     .child(Button::new().text("4").build(ctx))
+    ///     .build(ctx)
+    /// ```
+    UniformGrid {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the number of columns, `0` calculates the columns from the aspect ratio.
+        column_count: usize
+    }
+);
+
+impl Template for UniformGrid {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("UniformGrid")
+            .style("uniform_grid")
+            .background("transparent")
+            .column_count(0)
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        RectangleRenderObject.into()
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        UniformGridLayout::new().into()
+    }
+}
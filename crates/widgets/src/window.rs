@@ -23,6 +23,8 @@ struct WindowState {
     actions: VecDeque<Action>,
     background: Brush,
     title: String,
+    always_on_top: bool,
+    fullscreen: bool,
 }
 
 impl WindowState {
@@ -75,6 +77,12 @@ impl State for WindowState {
     fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
         self.set_background(ctx);
         self.title = ctx.widget().clone("title");
+        self.always_on_top = *ctx.widget().get::<bool>("always_on_top");
+        self.fullscreen = *ctx.widget().get::<bool>("fullscreen");
+
+        if self.fullscreen {
+            ctx.send_window_request(WindowRequest::SetFullscreen(true));
+        }
     }
 
     fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
@@ -89,6 +97,20 @@ impl State for WindowState {
             ctx.send_window_request(WindowRequest::ChangeTitle(self.title.clone()));
         }
 
+        let window = ctx.widget();
+
+        if self.always_on_top != *Window::always_on_top_ref(&window) {
+            self.always_on_top = *Window::always_on_top_ref(&window);
+            ctx.send_window_request(WindowRequest::SetAlwaysOnTop(self.always_on_top));
+        }
+
+        let window = ctx.widget();
+
+        if self.fullscreen != *Window::fullscreen_ref(&window) {
+            self.fullscreen = *Window::fullscreen_ref(&window);
+            ctx.send_window_request(WindowRequest::SetFullscreen(self.fullscreen));
+        }
+
         if let Some(action) = self.actions.pop_front() {
             match action {
                 Action::WindowEvent(window_event) => match window_event {
@@ -98,6 +120,14 @@ impl State for WindowState {
                     WindowEvent::ActiveChanged(active) => {
                         self.active_changed(active, ctx);
                     }
+                    WindowEvent::ColorSchemeChanged(color_scheme) => {
+                        if let Some(theme) = ctx.color_scheme_theme(color_scheme) {
+                            ctx.switch_theme(theme);
+                        }
+                    }
+                    WindowEvent::CloseRequested => {
+                        ctx.send_window_request(WindowRequest::Close);
+                    }
                     _ => {}
                 },
                 Action::FocusEvent(focus_event) => match focus_event {
@@ -117,6 +147,9 @@ widget!(
     /// The `Window` widget provides access to the properties of an application window.
     /// It also contains global properties like keyboard modifier and focused widget.
     ///
+    /// The `min_width`, `min_height`, `max_width` and `max_height` of the window limit the size the user can
+    /// resize the window to.
+    ///
     /// **style:** `window`
     Window<WindowState>: ActivateHandler {
         /// Sets or shares the background property.
@@ -125,12 +158,22 @@ widget!(
         /// Sets or shares the title property.
         title: String,
 
+        /// Sets or shares the flow direction of the window content. Set it to `right_to_left` to mirror the
+        /// layout for right-to-left languages.
+        flow_direction: FlowDirection,
+
         /// Sets or shares the resizeable property.
         resizeable: bool,
 
-        /// Sets or shares the property if this window should always be on top.
+        /// Sets or shares the property if this window should always be on top. Changes after the window is
+        /// created are sent to the shell by `WindowRequest::SetAlwaysOnTop`, the orbclient and web shells do
+        /// not support them yet.
         always_on_top: bool,
 
+        /// Sets or shares the property if this window is shown fullscreen. Changes are sent to the shell by
+        /// `WindowRequest::SetFullscreen`.
+        fullscreen: bool,
+
         /// Sets or shares the flag if the window is borderless.
         borderless: bool,
 
@@ -152,6 +195,24 @@ widget!(
 );
 
 impl Window {
+    /// Registers a handler that is called if the user requests to close the window. If the handler returns
+    /// `false` the close request is canceled and the window stays open.
+    pub fn on_close_requested<H: Fn(&mut StatesContext) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(WindowEventHandler {
+            handler: Rc::new(move |ctx, event| {
+                if let WindowEvent::CloseRequested = event {
+                    // marks the event as handled to prevent the window from closing
+                    return !handler(ctx);
+                }
+
+                false
+            }),
+        })
+    }
+
     fn on_window_event<H: Fn(&mut StatesContext, WindowEvent) -> bool + 'static>(
         self,
         handler: H,
@@ -180,6 +241,7 @@ impl Template for Window {
             .title("Window")
             .resizeable(false)
             .always_on_top(false)
+            .fullscreen(false)
             .on_window_event(move |ctx, event| {
                 ctx.get_mut::<WindowState>(id)
                     .push_action(Action::WindowEvent(event));
@@ -200,3 +262,66 @@ impl Template for Window {
         GridLayout::new().into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{mock_shell::MockShell, shell::prelude::ColorScheme, CheckBox};
+
+    #[test]
+    fn test_always_on_top() {
+        // the check box pins the window on top of the other windows
+        let mut shell = MockShell::new(|ctx| {
+            let check_box = CheckBox::new().v_align("start").build(ctx);
+
+            Window::new()
+                .always_on_top(("selected", check_box))
+                .child(check_box)
+                .build(ctx)
+        });
+        assert!(!shell.is_always_on_top());
+
+        shell.click(12.0, 12.0);
+        assert_eq!(shell.requests(), &[WindowRequest::SetAlwaysOnTop(true)]);
+        assert!(shell.is_always_on_top());
+
+        shell.click(12.0, 12.0);
+        assert!(!shell.is_always_on_top());
+    }
+
+    #[test]
+    fn test_close_requested() {
+        // the window is closed if the handler allows it
+        let mut shell = MockShell::new(|ctx| Window::new().on_close_requested(|_| true).build(ctx));
+        shell.quit();
+        assert_eq!(shell.requests(), &[WindowRequest::Close]);
+        assert!(!shell.is_open());
+
+        // the window stays open if the handler cancels the close request
+        let mut shell =
+            MockShell::new(|ctx| Window::new().on_close_requested(|_| false).build(ctx));
+        shell.quit();
+        assert!(shell.requests().is_empty());
+        assert!(shell.is_open());
+    }
+
+    #[test]
+    fn test_color_scheme_changed() {
+        let mut shell = MockShell::with_color_scheme_themes(
+            theme_default_light(),
+            theme_default_dark(),
+            |ctx| Window::new().id("window").build(ctx),
+        );
+        assert_eq!(shell.get::<Theme>("window", "theme"), theme_default_light());
+
+        shell.color_scheme_changed(ColorScheme::Dark);
+        assert_eq!(shell.get::<Theme>("window", "theme"), theme_default_dark());
+
+        // an unknown color scheme keeps the current theme
+        shell.color_scheme_changed(ColorScheme::Unknown);
+        assert_eq!(shell.get::<Theme>("window", "theme"), theme_default_dark());
+
+        shell.color_scheme_changed(ColorScheme::Light);
+        assert_eq!(shell.get::<Theme>("window", "theme"), theme_default_light());
+    }
+}
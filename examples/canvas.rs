@@ -149,10 +149,10 @@ impl RenderPipeline for Graphic2DPipeline {
         // render_context.set_fill_style(utils::Brush::SolidColor(Color::from("#000000")));
 
         render_context.set_fill_style(utils::Brush::Gradient(Gradient {
-            kind: GradientKind::Linear(LinearGradientCoords::Ends {
-                start: Point::new(0.0, 0.0),
-                end: Point::new(rect_width, rect_height),
-            }),
+            kind: GradientKind::Linear(LinearGradientCoords::from_ends(
+                Point::new(0.0, 0.0),
+                Point::new(rect_width, rect_height),
+            )),
             stops: vec![
                 GradientStop {
                     color: Color::from("#0021EB"),
@@ -170,6 +170,20 @@ impl RenderPipeline for Graphic2DPipeline {
             repeat: false,
         }));
         render_context.fill_rect(rect_x, rect_y, rect_width, rect_height);
+
+        // dashed border around the gradient
+        render_context.set_stroke_style("#ffffff");
+        render_context.set_line_width(2.0);
+        render_context.set_line_dash(&[8.0, 4.0]);
+        render_context.begin_path();
+        render_context.stroke_rect(
+            rect_x - 4.0,
+            rect_y - 4.0,
+            rect_width + 8.0,
+            rect_height + 8.0,
+        );
+        render_context.set_line_dash(&[]);
+
         render_context.register_font(
             "Roboto-Regular",
             include_bytes!("../crates/theme_default/assets/fonts/Roboto-Regular.ttf"),
@@ -177,12 +191,11 @@ impl RenderPipeline for Graphic2DPipeline {
         render_context.set_font_size(60.0);
         render_context.set_font_family("Roboto-Regular");
         render_context.set_fill_style("lynch");
-        let orb_metrics = render_context.measure_text("Orb");
-        render_context.fill_text(
-            "Orb",
-            rect_x + rect_width - orb_metrics.width,
-            rect_y - orb_metrics.height,
-        );
+        render_context.set_text_align(TextAlignment::End);
+        render_context.set_text_baseline(TextBaseline::Bottom);
+        render_context.fill_text("Orb", rect_x + rect_width, rect_y);
+        render_context.set_text_align(TextAlignment::Start);
+        render_context.set_text_baseline(TextBaseline::Top);
         render_context.set_fill_style("goldendream");
         render_context.save();
         let rotation = (270.0f64).to_radians();
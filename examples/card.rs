@@ -0,0 +1,101 @@
+use orbtk::prelude::*;
+
+// Draws the background of a card with a drop shadow that grows with the elevation.
+#[derive(IntoRenderObject)]
+pub struct CardRenderObject;
+
+impl RenderObject for CardRenderObject {
+    fn render_self(&self, ctx: &mut Context, global_position: &Point) {
+        let (bounds, background, elevation) = {
+            let widget = ctx.widget();
+            (
+                widget.clone::<Rectangle>("bounds"),
+                widget.clone::<Brush>("background"),
+                widget.clone_or_default::<f64>("elevation"),
+            )
+        };
+
+        let render_context = ctx.render_context_2_d();
+
+        if elevation > 0.0 {
+            render_context.set_shadow(0.0, elevation / 2.0, elevation, Color::rgba(0, 0, 0, 100));
+        }
+
+        render_context.set_fill_style(background);
+        render_context.fill_rect(
+            global_position.x() + bounds.x(),
+            global_position.y() + bounds.y(),
+            bounds.width(),
+            bounds.height(),
+        );
+        render_context.clear_shadow();
+    }
+}
+
+widget!(
+    /// The `Card` widget is a surface with a drop shadow.
+    Card {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the elevation of the card. The shadow grows with the elevation.
+        elevation: f64,
+
+        /// Sets or shares the padding property.
+        padding: Thickness
+    }
+);
+
+impl Template for Card {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("Card")
+            .background("#ffffff")
+            .elevation(4.0)
+            .padding(16.0)
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        CardRenderObject.into()
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        PaddingLayout::new().into()
+    }
+}
+
+fn card(title: &str, elevation: f64, ctx: &mut BuildContext) -> Entity {
+    Card::new()
+        .elevation(elevation)
+        .margin(16.0)
+        .child(
+            TextBlock::new()
+                .text(title)
+                .foreground("#000000")
+                .build(ctx),
+        )
+        .build(ctx)
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::new()
+                .title("OrbTk - card example")
+                .position((100.0, 100.0))
+                .size(420.0, 400.0)
+                .background("#eeeeee")
+                .child(
+                    Stack::new()
+                        .margin(16.0)
+                        .child(card("Elevation 2", 2.0, ctx))
+                        .child(card("Elevation 4", 4.0, ctx))
+                        .child(card("Elevation 8", 8.0, ctx))
+                        .build(ctx),
+                )
+                .build(ctx)
+        })
+        .run();
+}
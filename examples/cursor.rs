@@ -0,0 +1,85 @@
+use orbtk::{
+    prelude::*,
+    shell::{CursorIcon, WindowRequest},
+};
+
+#[derive(Default, AsAny)]
+struct MainViewState;
+
+impl State for MainViewState {
+    fn messages(
+        &mut self,
+        mut messages: MessageReader,
+        _registry: &mut Registry,
+        ctx: &mut Context,
+    ) {
+        for cursor_icon in messages.read::<CursorIcon>() {
+            ctx.send_window_request(WindowRequest::SetCursor(cursor_icon));
+        }
+    }
+}
+
+widget!(MainView<MainViewState>);
+
+impl MainView {
+    // creates a button that shows the given cursor while it is hovered
+    fn cursor_button(
+        id: Entity,
+        text: &str,
+        cursor_icon: CursorIcon,
+        ctx: &mut BuildContext,
+    ) -> Entity {
+        Button::new()
+            .margin(4.0)
+            .text(text)
+            .on_enter(move |states, _| states.send_message(cursor_icon, id))
+            .on_leave(move |states, _| states.send_message(CursorIcon::Default, id))
+            .build(ctx)
+    }
+}
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView").margin(16.0).child(
+            Stack::new()
+                .h_align("center")
+                .child(MainView::cursor_button(
+                    id,
+                    "Pointer",
+                    CursorIcon::Pointer,
+                    ctx,
+                ))
+                .child(MainView::cursor_button(id, "Text", CursorIcon::Text, ctx))
+                .child(MainView::cursor_button(
+                    id,
+                    "East west resize",
+                    CursorIcon::EwResize,
+                    ctx,
+                ))
+                .child(MainView::cursor_button(
+                    id,
+                    "North south resize",
+                    CursorIcon::NsResize,
+                    ctx,
+                ))
+                .child(MainView::cursor_button(id, "Grab", CursorIcon::Grab, ctx))
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::new()
+                .title("OrbTk - cursor example")
+                .position((100.0, 100.0))
+                .size(420.0, 300.0)
+                .child(MainView::new().build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
@@ -0,0 +1,58 @@
+use orbtk::prelude::*;
+
+#[derive(Default, AsAny)]
+struct MainViewState {
+    toggle_fullscreen: bool,
+}
+
+impl MainViewState {
+    fn toggle_fullscreen(&mut self) {
+        self.toggle_fullscreen = true;
+    }
+}
+
+impl State for MainViewState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if self.toggle_fullscreen {
+            // the window state sends the changed property as `WindowRequest::SetFullscreen` to the shell
+            let fullscreen = *Window::fullscreen_ref(&ctx.window());
+            Window::fullscreen_set(&mut ctx.window(), !fullscreen);
+            self.toggle_fullscreen = false;
+        }
+    }
+}
+
+widget!(MainView<MainViewState>);
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView").margin(16.0).child(
+            Button::new()
+                .v_align("top")
+                .h_align("center")
+                .text("Toggle fullscreen")
+                .on_click(move |states, _| -> bool {
+                    states.get_mut::<MainViewState>(id).toggle_fullscreen();
+                    true
+                })
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::new()
+                .title("OrbTk - fullscreen example")
+                .position((100.0, 100.0))
+                .size(420.0, 200.0)
+                .resizeable(true)
+                .child(MainView::new().build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
@@ -0,0 +1,61 @@
+use orbtk::prelude::*;
+
+#[derive(Default, AsAny)]
+struct MainViewState {
+    clicks: usize,
+    change_title: bool,
+}
+
+impl MainViewState {
+    fn change_title(&mut self) {
+        self.clicks += 1;
+        self.change_title = true;
+    }
+}
+
+impl State for MainViewState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if self.change_title {
+            // the window state sends the changed title as `WindowRequest::ChangeTitle` to the shell
+            Window::title_set(
+                &mut ctx.window(),
+                format!("OrbTk - window title example ({} clicks)", self.clicks),
+            );
+            self.change_title = false;
+        }
+    }
+}
+
+widget!(MainView<MainViewState>);
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView").margin(16.0).child(
+            Button::new()
+                .v_align("top")
+                .h_align("center")
+                .text("Change title")
+                .on_click(move |states, _| -> bool {
+                    states.get_mut::<MainViewState>(id).change_title();
+                    true
+                })
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::new()
+                .title("OrbTk - window title example")
+                .position((100.0, 100.0))
+                .size(420.0, 200.0)
+                .child(MainView::new().build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
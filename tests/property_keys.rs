@@ -0,0 +1,13 @@
+use orbtk::prelude::*;
+
+#[test]
+fn test_property_keys() {
+    assert_eq!(Grid::ROWS.name(), "rows");
+    assert_eq!(Grid::SELECTOR.name(), "selector");
+}
+
+#[test]
+fn test_unknown_property_key() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/unknown_property_key.rs");
+}
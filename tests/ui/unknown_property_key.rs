@@ -0,0 +1,5 @@
+use orbtk::prelude::*;
+
+fn main() {
+    let _ = Grid::TROWS;
+}